@@ -520,6 +520,10 @@ fn parse_filter(list: &MetaList) -> Result<proc_macro2::TokenStream> {
 /// Generates an implementation of the `FromEndian` trait for a struct. This
 /// allows converting values from a given endianness to the host's endianness.
 ///
+/// Fields that shouldn't be converted, such as padding or single byte fields
+/// of types that don't implement `FromEndian`, can be marked with the
+/// `#[endian(skip)]` attribute. They are copied as they are.
+///
 /// # Example
 ///
 /// ```no_run
@@ -527,6 +531,8 @@ fn parse_filter(list: &MetaList) -> Result<proc_macro2::TokenStream> {
 /// struct MyStruct {
 ///     a: u32,
 ///     b: u16,
+///     #[endian(skip)]
+///     _padding: [u8; 2],
 /// }
 /// ```
 ///
@@ -538,17 +544,19 @@ fn parse_filter(list: &MetaList) -> Result<proc_macro2::TokenStream> {
 ///         Self {
 ///             a: self.a.from_be(),
 ///             b: self.b.from_be(),
+///             _padding: self._padding,
 ///         }
 ///     }
 ///     fn from_le(&self) -> Self {
 ///         Self {
 ///             a: self.a.from_le(),
 ///             b: self.b.from_le(),
+///             _padding: self._padding,
 ///         }
 ///     }
 /// }
 /// ```
-#[proc_macro_derive(FromEndian)]
+#[proc_macro_derive(FromEndian, attributes(endian))]
 pub fn from_endian_macro(input: TokenStream) -> TokenStream {
     let ast: DeriveInput = syn::parse(input).unwrap();
 
@@ -559,25 +567,50 @@ pub fn from_endian_macro(input: TokenStream) -> TokenStream {
 
     let struct_name = ast.ident;
 
-    let mut field_names = Vec::new();
+    let mut be_fields = Vec::new();
+    let mut le_fields = Vec::new();
     for field in struct_data.fields {
-        field_names.push(field.ident);
+        let mut skip = false;
+        for attr in &field.attrs {
+            if !attr.path().is_ident("endian") {
+                continue;
+            }
+            if let Err(e) = attr.parse_nested_meta(|meta| {
+                if meta.path.is_ident("skip") {
+                    skip = true;
+                    Ok(())
+                } else {
+                    Err(meta.error("Expected `skip`."))
+                }
+            }) {
+                return e.into_compile_error().into();
+            }
+        }
+
+        let field_name = field.ident;
+        if skip {
+            be_fields.push(quote! { #field_name: self.#field_name });
+            le_fields.push(quote! { #field_name: self.#field_name });
+        } else {
+            be_fields.push(quote! {
+                #field_name: asr::FromEndian::from_be(&self.#field_name)
+            });
+            le_fields.push(quote! {
+                #field_name: asr::FromEndian::from_le(&self.#field_name)
+            });
+        }
     }
 
     quote! {
-        impl asr::primitives::dynamic_endian::FromEndian for #struct_name {
+        impl asr::FromEndian for #struct_name {
             fn from_be(&self) -> Self {
                 Self {
-                    #(#field_names: asr::primitives::dynamic_endian::FromEndian::from_be(
-                        &self.#field_names,
-                    ),)*
+                    #(#be_fields,)*
                 }
             }
             fn from_le(&self) -> Self {
                 Self {
-                    #(#field_names: asr::primitives::dynamic_endian::FromEndian::from_le(
-                        &self.#field_names,
-                    ),)*
+                    #(#le_fields,)*
                 }
             }
         }
@@ -595,6 +628,13 @@ mod unity;
 /// to be of a type that can be read from a process. Fields can be marked as
 /// static with the `#[static_field]` attribute.
 ///
+/// If the offset of a field is known ahead of time, it can be specified with
+/// the `#[offset = 0x20]` attribute, which skips looking up the field by its
+/// name. This can't be combined with `#[rename = "..."]`. Fields that the game
+/// stores in a different byte order can be marked with `#[endian = "be"]` or
+/// `#[endian = "le"]`, which converts them with `asr::FromEndian` after reading
+/// them.
+///
 /// # Example
 ///
 /// ```no_run
@@ -604,6 +644,9 @@ mod unity;
 ///     level_time: f32,
 ///     #[static_field]
 ///     foo: bool,
+///     #[offset = 0x20]
+///     #[endian = "be"]
+///     score: u32,
 /// }
 /// ```
 ///
@@ -614,6 +657,8 @@ mod unity;
 /// {
 ///     float currentLevelTime;
 ///     static bool foo;
+///     // Stored in big endian at offset 0x20.
+///     uint score;
 ///     // ...
 /// }
 /// ```
@@ -636,7 +681,7 @@ mod unity;
 /// If only static fields are present, the `read` method does not take an
 /// instance argument.
 #[cfg(feature = "unity")]
-#[proc_macro_derive(Il2cppClass, attributes(static_field, rename))]
+#[proc_macro_derive(Il2cppClass, attributes(static_field, rename, offset, endian))]
 pub fn il2cpp_class_binding(input: TokenStream) -> TokenStream {
     unity::process(input, quote! { asr::game_engine::unity::il2cpp })
 }
@@ -648,6 +693,13 @@ pub fn il2cpp_class_binding(input: TokenStream) -> TokenStream {
 /// to be of a type that can be read from a process. Fields can be marked as
 /// static with the `#[static_field]` attribute.
 ///
+/// If the offset of a field is known ahead of time, it can be specified with
+/// the `#[offset = 0x20]` attribute, which skips looking up the field by its
+/// name. This can't be combined with `#[rename = "..."]`. Fields that the game
+/// stores in a different byte order can be marked with `#[endian = "be"]` or
+/// `#[endian = "le"]`, which converts them with `asr::FromEndian` after reading
+/// them.
+///
 /// # Example
 ///
 /// ```no_run
@@ -657,6 +709,9 @@ pub fn il2cpp_class_binding(input: TokenStream) -> TokenStream {
 ///     level_time: f32,
 ///     #[static_field]
 ///     foo: bool,
+///     #[offset = 0x20]
+///     #[endian = "be"]
+///     score: u32,
 /// }
 /// ```
 ///
@@ -667,6 +722,8 @@ pub fn il2cpp_class_binding(input: TokenStream) -> TokenStream {
 /// {
 ///     float currentLevelTime;
 ///     static bool foo;
+///     // Stored in big endian at offset 0x20.
+///     uint score;
 ///     // ...
 /// }
 /// ```
@@ -689,7 +746,7 @@ pub fn il2cpp_class_binding(input: TokenStream) -> TokenStream {
 /// If only static fields are present, the `read` method does not take an
 /// instance argument.
 #[cfg(feature = "unity")]
-#[proc_macro_derive(MonoClass, attributes(static_field, rename))]
+#[proc_macro_derive(MonoClass, attributes(static_field, rename, offset, endian))]
 pub fn mono_class_binding(input: TokenStream) -> TokenStream {
     unity::process(input, quote! { asr::game_engine::unity::mono })
 }
@@ -1,6 +1,6 @@
 use proc_macro::TokenStream;
 use quote::{quote, quote_spanned, ToTokens};
-use syn::{Data, DeriveInput, Expr, ExprLit, Ident, Lit, Meta};
+use syn::{spanned::Spanned, Attribute, Data, DeriveInput, Expr, ExprLit, Ident, Lit, LitInt};
 
 pub fn process(input: TokenStream, mono_module: impl ToTokens) -> TokenStream {
     let ast: DeriveInput = syn::parse(input).unwrap();
@@ -23,49 +23,70 @@ pub fn process(input: TokenStream, mono_module: impl ToTokens) -> TokenStream {
     let mut field_names = Vec::new();
     let mut lookup_names = Vec::new();
     let mut field_types = Vec::new();
+    let mut field_offsets = Vec::new();
     let mut field_reads = Vec::new();
+    let mut field_type_checks = Vec::new();
     for field in struct_data.fields {
         let field_name = field.ident.clone().unwrap();
         let span = field_name.span();
-        let is_static = field.attrs.iter().any(|x| {
-            let Meta::Path(path) = &x.meta else {
-                return false;
-            };
-            path.is_ident("static_field")
+        let attrs = match FieldAttrs::parse(&field.attrs) {
+            Ok(attrs) => attrs,
+            Err(e) => return e.into_compile_error().into(),
+        };
+        let is_static = attrs.is_static;
+        let base = if is_static {
+            quote! { self.static_table }
+        } else {
+            quote! { instance }
+        };
+        field_reads.push(match attrs.endian {
+            Some(Endian::Big) => quote_spanned! { span =>
+                asr::FromEndian::from_be(&process.read(#base + self.#field_name).map_err(drop)?)
+            },
+            Some(Endian::Little) => quote_spanned! { span =>
+                asr::FromEndian::from_le(&process.read(#base + self.#field_name).map_err(drop)?)
+            },
+            None => quote_spanned! { span =>
+                process.read(#base + self.#field_name).map_err(drop)?
+            },
         });
-        field_reads.push(if is_static {
-            quote_spanned! { span =>
-                process.read(self.static_table + self.#field_name).map_err(drop)?
-            }
+        let ty = &field.ty;
+        field_offsets.push(if let Some(offset) = &attrs.offset {
+            quote_spanned! { offset.span() => #offset }
         } else {
-            quote_spanned! { span =>
-                process.read(instance + self.#field_name).map_err(drop)?
+            let lookup_name = attrs
+                .rename
+                .unwrap_or_else(|| field.ident.clone().unwrap().to_string());
+            if let Some(index) = lookup_names.iter().position(|(name, _)| *name == lookup_name) {
+                let other: &Ident = &lookup_names[index].1;
+                return syn::Error::new(
+                    span,
+                    format!(
+                        "the field `{field_name}` resolves to the name `{lookup_name}`, \
+                        which is already used by the field `{other}`. \
+                        Use `#[rename = \"...\"]` to map them to different fields",
+                    ),
+                )
+                .into_compile_error()
+                .into();
             }
+            let offset = quote! {
+                class.wait_get_field_offset(process, module, #lookup_name).await
+            };
+            lookup_names.push((lookup_name, field_name.clone()));
+            offset
         });
-        let lookup_name = field
-            .attrs
-            .iter()
-            .find_map(|x| {
-                let Meta::NameValue(name_value) = &x.meta else {
-                    return None;
-                };
-                if !name_value.path.is_ident("rename") {
-                    return None;
-                }
-                let Expr::Lit(ExprLit {
-                    lit: Lit::Str(name),
-                    ..
-                }) = &name_value.value
-                else {
-                    return None;
-                };
-                Some(name.value())
-            })
-            .unwrap_or_else(|| field.ident.clone().unwrap().to_string());
+        field_type_checks.push(quote_spanned! { ty.span() =>
+            __field_type_must_be_readable_from_memory::<#ty>();
+        });
+        if attrs.endian.is_some() {
+            field_type_checks.push(quote_spanned! { ty.span() =>
+                __field_type_must_support_byte_swapping::<#ty>();
+            });
+        }
         has_static |= is_static;
         is_fully_static &= is_static;
         field_names.push(field_name);
-        lookup_names.push(lookup_name);
         field_types.push(field.ty);
     }
 
@@ -92,6 +113,18 @@ pub fn process(input: TokenStream, mono_module: impl ToTokens) -> TokenStream {
     };
 
     quote! {
+        // Every field needs to be readable from the memory of the process.
+        // This produces an error pointing at the type of the field if it's
+        // not, rather than at the generated code that reads it.
+        const _: () = {
+            fn __field_type_must_be_readable_from_memory<T: asr::__bytemuck::CheckedBitPattern>() {}
+            #[allow(dead_code)]
+            fn __field_type_must_support_byte_swapping<T: asr::FromEndian>() {}
+            fn __check_field_types() {
+                #(#field_type_checks)*
+            }
+        };
+
         #vis struct #binding_name {
             class: #mono_module::Class,
             #static_table_field
@@ -107,7 +140,7 @@ pub fn process(input: TokenStream, mono_module: impl ToTokens) -> TokenStream {
                 let class = image.wait_get_class(process, module, #stuct_name_string).await;
 
                 #(
-                    let #field_names = class.wait_get_field_offset(process, module, #lookup_names).await;
+                    let #field_names = #field_offsets;
                 )*
 
                 #binding_name {
@@ -132,3 +165,113 @@ pub fn process(input: TokenStream, mono_module: impl ToTokens) -> TokenStream {
     }
     .into()
 }
+
+enum Endian {
+    Big,
+    Little,
+}
+
+/// The attributes that can be placed on the fields of the struct.
+#[derive(Default)]
+struct FieldAttrs {
+    is_static: bool,
+    rename: Option<String>,
+    offset: Option<LitInt>,
+    endian: Option<Endian>,
+}
+
+impl FieldAttrs {
+    fn parse(attrs: &[Attribute]) -> syn::Result<Self> {
+        let mut this = Self::default();
+        let mut rename_span = None;
+        for attr in attrs {
+            let path = attr.path();
+            let is_known = ["static_field", "rename", "offset", "endian"]
+                .iter()
+                .any(|name| path.is_ident(name));
+            if !is_known {
+                continue;
+            }
+            if path.is_ident("static_field") {
+                attr.meta.require_path_only()?;
+                this.is_static = true;
+                continue;
+            }
+
+            let value = &attr.meta.require_name_value()?.value;
+            let duplicate = || {
+                syn::Error::new(
+                    attr.span(),
+                    format!(
+                        "the `{}` attribute is specified more than once",
+                        path.get_ident().unwrap(),
+                    ),
+                )
+            };
+            if path.is_ident("rename") {
+                let Expr::Lit(ExprLit {
+                    lit: Lit::Str(name),
+                    ..
+                }) = value
+                else {
+                    return Err(syn::Error::new(
+                        value.span(),
+                        "expected the name of the field, e.g. `#[rename = \"name\"]`",
+                    ));
+                };
+                if this.rename.is_some() {
+                    return Err(duplicate());
+                }
+                this.rename = Some(name.value());
+                rename_span = Some(attr.span());
+            } else if path.is_ident("offset") {
+                let Expr::Lit(ExprLit {
+                    lit: Lit::Int(offset),
+                    ..
+                }) = value
+                else {
+                    return Err(syn::Error::new(
+                        value.span(),
+                        "expected the offset of the field, e.g. `#[offset = 0x20]`",
+                    ));
+                };
+                offset.base10_parse::<u32>()?;
+                if this.offset.is_some() {
+                    return Err(duplicate());
+                }
+                this.offset = Some(LitInt::new(&format!("{}u32", offset.base10_digits()), offset.span()));
+            } else {
+                let endian = match value {
+                    Expr::Lit(ExprLit {
+                        lit: Lit::Str(endian),
+                        ..
+                    }) if endian.value() == "be" => Endian::Big,
+                    Expr::Lit(ExprLit {
+                        lit: Lit::Str(endian),
+                        ..
+                    }) if endian.value() == "le" => Endian::Little,
+                    _ => {
+                        return Err(syn::Error::new(
+                            value.span(),
+                            "expected the endianness of the field, either `\"be\"` or `\"le\"`",
+                        ))
+                    }
+                };
+                if this.endian.is_some() {
+                    return Err(duplicate());
+                }
+                this.endian = Some(endian);
+            }
+        }
+
+        if let (Some(span), Some(_)) = (rename_span, &this.offset) {
+            return Err(syn::Error::new(
+                span,
+                "the `rename` and `offset` attributes can't be combined, \
+                as a field with a known offset is not looked up by its name",
+            ));
+        }
+
+        Ok(this)
+    }
+}
@@ -0,0 +1,133 @@
+//! Fixed-capacity collections that don't need an allocator. These are useful
+//! for keeping small amounts of state around, such as the last few events
+//! that happened in the game, even when the `alloc` feature is disabled.
+//!
+//! For keeping track of the most recent values of a watched value, see
+//! [`History`](crate::watcher::History).
+
+use core::{fmt, iter::FusedIterator};
+
+pub use arrayvec::{ArrayString, ArrayVec};
+
+/// A first-in, first-out queue that can hold up to `N` values. Once the queue
+/// is full, [`push`](Self::push) makes room by dropping the oldest value,
+/// which makes it a good fit for a bounded log of events. If values must not
+/// be lost, [`try_push`](Self::try_push) can be used instead.
+///
+/// # Example
+///
+/// ```
+/// use asr::collections::Queue;
+///
+/// let mut events = Queue::<&str, 2>::new();
+/// events.push("Boss 1");
+/// events.push("Boss 2");
+/// events.push("Boss 3");
+///
+/// assert!(events.iter().eq(&["Boss 2", "Boss 3"]));
+/// assert_eq!(events.pop(), Some("Boss 2"));
+/// ```
+#[derive(Clone)]
+pub struct Queue<T, const N: usize> {
+    values: [Option<T>; N],
+    start: usize,
+    len: usize,
+}
+
+impl<T, const N: usize> Default for Queue<T, N> {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: fmt::Debug, const N: usize> fmt::Debug for Queue<T, N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+impl<T, const N: usize> Queue<T, N> {
+    /// Creates a new empty queue.
+    #[inline]
+    pub fn new() -> Self {
+        Self {
+            values: [(); N].map(|_| None),
+            start: 0,
+            len: 0,
+        }
+    }
+
+    /// Returns the number of values in the queue.
+    #[inline]
+    pub const fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns [`true`] if the queue doesn't contain any values.
+    #[inline]
+    pub const fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Returns [`true`] if the queue can't hold any more values without
+    /// dropping the oldest one.
+    #[inline]
+    pub const fn is_full(&self) -> bool {
+        self.len == N
+    }
+
+    /// Pushes a value to the back of the queue. If the queue is full, the
+    /// oldest value is removed and returned.
+    pub fn push(&mut self, value: T) -> Option<T> {
+        if N == 0 {
+            return Some(value);
+        }
+        let evicted = if self.is_full() { self.pop() } else { None };
+        self.values[(self.start + self.len) % N] = Some(value);
+        self.len += 1;
+        evicted
+    }
+
+    /// Pushes a value to the back of the queue, unless the queue is full, in
+    /// which case the value is handed back.
+    pub fn try_push(&mut self, value: T) -> Result<(), T> {
+        if self.is_full() {
+            return Err(value);
+        }
+        self.push(value);
+        Ok(())
+    }
+
+    /// Removes the oldest value from the queue and returns it.
+    pub const fn pop(&mut self) -> Option<T> {
+        if self.is_empty() {
+            return None;
+        }
+        let value = self.values[self.start].take();
+        self.start = (self.start + 1) % N;
+        self.len -= 1;
+        value
+    }
+
+    /// Returns the oldest value in the queue without removing it.
+    pub fn front(&self) -> Option<&T> {
+        self.iter().next()
+    }
+
+    /// Returns the most recently pushed value in the queue.
+    pub fn back(&self) -> Option<&T> {
+        self.iter().next_back()
+    }
+
+    /// Removes all the values from the queue.
+    pub fn clear(&mut self) {
+        while self.pop().is_some() {}
+        self.start = 0;
+    }
+
+    /// Iterates over the values from the oldest to the most recent one.
+    pub fn iter(&self) -> impl DoubleEndedIterator<Item = &T> + FusedIterator {
+        (0..self.len).filter_map(move |i| self.values[(self.start + i) % N].as_ref())
+    }
+}
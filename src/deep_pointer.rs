@@ -1,6 +1,6 @@
 //! Support for storing pointer paths for easy dereferencing inside the autosplitter logic.
 
-use core::array;
+use core::{array, cell::Cell};
 
 use bytemuck::CheckedBitPattern;
 
@@ -80,3 +80,131 @@ impl<const CAP: usize> DeepPointer<CAP> {
         )
     }
 }
+
+/// A [`DeepPointer`] that remembers the address each of its pointers
+/// resolved to.
+///
+/// Every dereference reads the pointers along the path again, one pointer
+/// sized read per offset, and compares them with the remembered addresses.
+/// As soon as one of them differs, the path is followed again from that
+/// pointer on, so a pointer that moved in the middle of the path is noticed
+/// even if the old address at the end of the path is still readable. If a
+/// pointer can't be read, the addresses before it are kept, and the path is
+/// followed again from there on the next dereference.
+pub struct CachedPath<const CAP: usize> {
+    pointer: DeepPointer<CAP>,
+    nodes: Cell<[Address; CAP]>,
+    valid: Cell<usize>,
+}
+
+impl<const CAP: usize> CachedPath<CAP> {
+    /// Creates a new cached pointer path. Just like with
+    /// [`DeepPointer::new`], the path is truncated if it has more offsets
+    /// than `CAP`.
+    #[inline]
+    pub fn new(base_address: impl Into<Address>, pointer_size: PointerSize, path: &[u64]) -> Self {
+        Self::from(DeepPointer::new(base_address, pointer_size, path))
+    }
+
+    /// Forgets the remembered addresses, so the whole pointer path is
+    /// followed again on the next dereference.
+    #[inline]
+    pub fn invalidate(&self) {
+        self.valid.set(0);
+    }
+
+    /// Dereferences the pointer path, returning the memory address of the
+    /// value of interest.
+    pub fn deref_offsets(&self, process: &Process) -> Result<Address, Error> {
+        let pointer_size = self.pointer.pointer_size;
+        self.resolve(|address| process.read_pointer(address, pointer_size))
+    }
+
+    /// Dereferences the pointer path, returning the value stored at the final
+    /// memory address.
+    pub fn deref<T: CheckedBitPattern>(&self, process: &Process) -> Result<T, Error> {
+        process.read(self.deref_offsets(process)?)
+    }
+
+    /// Checks the remembered addresses with the function provided, which
+    /// reads a pointer, and follows the path again from the first pointer
+    /// that changed.
+    fn resolve(
+        &self,
+        mut read_pointer: impl FnMut(Address) -> Result<Address, Error>,
+    ) -> Result<Address, Error> {
+        let (&last, path) = self.pointer.path[..self.pointer.depth]
+            .split_last()
+            .ok_or(Error {})?;
+        let mut nodes = self.nodes.get();
+        let mut valid = self.valid.get();
+        let mut address = self.pointer.base_address;
+
+        let result = path.iter().enumerate().try_for_each(|(index, &offset)| {
+            let Ok(pointer) = read_pointer(address + offset) else {
+                valid = valid.min(index);
+                return Err(Error {});
+            };
+            if index >= valid || nodes[index] != pointer {
+                // Everything after this pointer needs to be followed again.
+                nodes[index] = pointer;
+                valid = index + 1;
+            }
+            address = pointer;
+            Ok(())
+        });
+
+        self.nodes.set(nodes);
+        self.valid.set(valid);
+        result.map(|()| address + last)
+    }
+}
+
+impl<const CAP: usize> From<DeepPointer<CAP>> for CachedPath<CAP> {
+    #[inline]
+    fn from(pointer: DeepPointer<CAP>) -> Self {
+        Self {
+            pointer,
+            nodes: Cell::new([Address::NULL; CAP]),
+            valid: Cell::new(0),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn cached_path_follows_a_middle_pointer_that_moved() {
+        let middle = Cell::new(0x3000);
+        let readable = Cell::new(true);
+        let read_pointer = |address: Address| match address.value() {
+            0x1010 => Ok(Address::new(0x2000)),
+            0x2020 => Ok(Address::new(middle.get())),
+            0x3030 | 0x4030 if readable.get() => Ok(Address::new(address.value() * 2)),
+            _ => Err(Error {}),
+        };
+        let path = CachedPath::<4>::new(0x1000u64, PointerSize::Bit64, &[0x10, 0x20, 0x30, 0x8]);
+
+        assert_eq!(path.resolve(read_pointer).ok(), Some(Address::new(0x6068)));
+        assert_eq!(path.valid.get(), 3);
+
+        // The old address at the end of the path is still readable.
+        middle.set(0x4000);
+        assert_eq!(path.resolve(read_pointer).ok(), Some(Address::new(0x8068)));
+        assert_eq!(path.valid.get(), 3);
+        assert_eq!(
+            path.nodes.get()[..3],
+            [0x2000, 0x4000, 0x8060].map(Address::new)
+        );
+
+        // The pointers before the one that can't be read stay remembered.
+        readable.set(false);
+        assert!(path.resolve(read_pointer).is_err());
+        assert_eq!(path.valid.get(), 2);
+
+        path.invalidate();
+        assert_eq!(path.valid.get(), 0);
+    }
+}
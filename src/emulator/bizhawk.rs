@@ -0,0 +1,147 @@
+//! Shared support for locating the memory domains exposed by BizHawk (EmuHawk)
+//! cores.
+//!
+//! BizHawk cores allocate the memory of the emulated system in a single block,
+//! which is split into the memory domains shown in BizHawk's memory tools
+//! (e.g. "EWRAM" and "IWRAM" for the Gameboy Advance). This module locates that
+//! block and maps each domain to its position inside of it, so that supporting
+//! BizHawk for a new system only requires describing the layout of its
+//! domains.
+//!
+//! # Example
+//!
+//! ```no_run
+//! # use asr::{emulator::bizhawk::{Domain, MemoryDomains}, Process};
+//! # fn example(process: &Process) {
+//! const DOMAINS: &[Domain] = &[
+//!     Domain::new("EWRAM", 0x0, 0x40000),
+//!     Domain::new("IWRAM", 0x40000, 0x8000),
+//! ];
+//!
+//! let mut domains = MemoryDomains::new("mgba.dll", 0x48000, DOMAINS);
+//!
+//! if domains.find(process).is_some() {
+//!     let mut buf = [0; 4];
+//!     let _ = domains.read_domain(process, "IWRAM", 0x100, &mut buf);
+//! }
+//! # }
+//! ```
+
+use crate::{Address, Error, MemoryRangeFlags, Process};
+
+/// A memory domain exposed by a BizHawk core.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct Domain {
+    name: &'static str,
+    offset: u64,
+    size: u64,
+}
+
+impl Domain {
+    /// Describes a memory domain with the name shown by BizHawk, its offset
+    /// from the start of the memory block allocated by the core and its size.
+    pub const fn new(name: &'static str, offset: u64, size: u64) -> Self {
+        Self { name, offset, size }
+    }
+
+    /// The name of the memory domain, as shown by BizHawk.
+    pub const fn name(&self) -> &'static str {
+        self.name
+    }
+
+    /// The size of the memory domain in bytes.
+    pub const fn size(&self) -> u64 {
+        self.size
+    }
+}
+
+/// The memory domains of a BizHawk core.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct MemoryDomains {
+    core: &'static str,
+    block_size: u64,
+    domains: &'static [Domain],
+    core_base: Address,
+    block: Address,
+}
+
+impl MemoryDomains {
+    /// Describes the memory domains of a BizHawk core. The core is identified
+    /// by the name of its module, while the block size is the size of the
+    /// memory allocation that contains all the domains.
+    pub const fn new(core: &'static str, block_size: u64, domains: &'static [Domain]) -> Self {
+        Self {
+            core,
+            block_size,
+            domains,
+            core_base: Address::NULL,
+            block: Address::NULL,
+        }
+    }
+
+    /// Tries to locate the memory block allocated by the core. Returns the
+    /// address of the block if successful.
+    pub fn find(&mut self, process: &Process) -> Option<Address> {
+        self.core_base = process.get_module_address(self.core).ok()?;
+
+        self.block = process
+            .memory_ranges()
+            .find(|range| {
+                range.size().is_ok_and(|size| size == self.block_size)
+                    && range.flags().is_ok_and(|flag| {
+                        flag.contains(MemoryRangeFlags::WRITE | MemoryRangeFlags::READ)
+                    })
+            })?
+            .address()
+            .ok()?;
+
+        Some(self.block)
+    }
+
+    /// Checks whether the core and its memory block are still accessible.
+    pub fn keep_alive(&self, process: &Process) -> bool {
+        !self.block.is_null()
+            && process.read::<u8>(self.block).is_ok()
+            && process.read::<u8>(self.core_base).is_ok()
+    }
+
+    /// Returns the memory domain with the name specified, if the core exposes
+    /// it.
+    pub fn get_domain(&self, name: &str) -> Option<&'static Domain> {
+        self.domains.iter().find(|domain| domain.name == name)
+    }
+
+    /// Converts an offset into the memory domain specified to a real memory
+    /// address in the emulator process' virtual memory space.
+    ///
+    /// Returns `Err()` if the memory block has not been found yet, the domain
+    /// doesn't exist or the offset is outside of the domain.
+    pub fn get_domain_address(&self, name: &str, offset: u64) -> Result<Address, Error> {
+        let domain = self.get_domain(name).ok_or(Error {})?;
+
+        if self.block.is_null() || offset >= domain.size {
+            return Err(Error {});
+        }
+
+        Ok(self.block + domain.offset + offset)
+    }
+
+    /// Reads a range of bytes from the memory domain specified into the buffer
+    /// provided. The whole range needs to be inside of the domain, otherwise
+    /// this method immediately returns `Err()`.
+    pub fn read_domain(
+        &self,
+        process: &Process,
+        name: &str,
+        offset: u64,
+        buf: &mut [u8],
+    ) -> Result<(), Error> {
+        let domain = self.get_domain(name).ok_or(Error {})?;
+
+        if offset.saturating_add(buf.len() as u64) > domain.size {
+            return Err(Error {});
+        }
+
+        process.read_into_buf(self.get_domain_address(name, offset)?, buf)
+    }
+}
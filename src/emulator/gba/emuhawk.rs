@@ -1,36 +1,35 @@
-use crate::{Address, MemoryRangeFlags, Process};
+use crate::{
+    emulator::bizhawk::{Domain, MemoryDomains},
+    Address, Process,
+};
+
+const DOMAINS: &[Domain] = &[
+    Domain::new("EWRAM", 0x0, 0x40000),
+    Domain::new("IWRAM", 0x40000, 0x8000),
+];
 
 #[derive(Copy, Clone, Debug, PartialEq, Eq)]
 pub struct State {
-    base_addr: Address,
+    domains: MemoryDomains,
 }
 
 impl State {
     pub fn find_ram(&mut self, game: &Process) -> Option<[Address; 2]> {
-        self.base_addr = game.get_module_address("mgba.dll").ok()?;
-
-        let addr = game
-            .memory_ranges()
-            .find(|range| {
-                range.size().is_ok_and(|size| size == 0x48000)
-                    && range.flags().is_ok_and(|flag| {
-                        flag.contains(MemoryRangeFlags::WRITE | MemoryRangeFlags::READ)
-                    })
-            })?
-            .address()
-            .ok()?;
+        self.domains.find(game)?;
 
-        Some([addr, addr + 0x40000])
+        Some([
+            self.domains.get_domain_address("EWRAM", 0).ok()?,
+            self.domains.get_domain_address("IWRAM", 0).ok()?,
+        ])
     }
 
     pub fn keep_alive(&self, game: &Process, ram_base: &Option<[Address; 2]>) -> bool {
-        ram_base.is_some_and(|[ewram, _]| game.read::<u8>(ewram).is_ok())
-            && game.read::<u8>(self.base_addr).is_ok()
+        ram_base.is_some() && self.domains.keep_alive(game)
     }
 
     pub const fn new() -> Self {
         Self {
-            base_addr: Address::NULL,
+            domains: MemoryDomains::new("mgba.dll", 0x48000, DOMAINS),
         }
     }
 }
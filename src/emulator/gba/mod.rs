@@ -9,7 +9,12 @@ use core::{
     task::{Context, Poll},
 };
 
-use crate::{future::retry, Address, Error, Process};
+use crate::{
+    emulator::{memory, retroarch::ContentPath},
+    future::retry,
+    string::ArrayCString,
+    Address, Error, Process,
+};
 use bytemuck::CheckedBitPattern;
 
 mod emuhawk;
@@ -27,6 +32,8 @@ pub struct Emulator {
     state: Cell<State>,
     /// The memory address of the emulated RAM
     ram_base: Cell<Option<[Address; 2]>>, // [ewram, iwram]
+    /// The location of the path of the loaded content, if running in Retroarch
+    content_path: ContentPath,
 }
 
 impl Emulator {
@@ -51,6 +58,7 @@ impl Emulator {
             process,
             state: Cell::new(state),
             ram_base: Cell::new(None),
+            content_path: ContentPath::new(),
         })
     }
 
@@ -75,6 +83,22 @@ impl Emulator {
         self.process.is_open()
     }
 
+    /// Reads the path of the content (the ROM or disc image) that is currently
+    /// loaded. This allows a single auto splitter to support multiple games,
+    /// or multiple regions of the same game, by branching on the file name.
+    ///
+    /// This is only supported by Retroarch on Windows, regardless of the core
+    /// that is used. Returns `Err()` for every other emulator, while no
+    /// content is loaded or if the path can't be found.
+    pub fn content_path<const N: usize>(&self) -> Result<ArrayCString<N>, Error> {
+        match self.state.get() {
+            State::Retroarch(_) => self
+                .content_path
+                .read(&self.process, &["gba", "agb", "bin", "zip", "7z"]),
+            _ => Err(Error {}),
+        }
+    }
+
     /// Executes a future until the emulator process closes.
     pub const fn until_closes<F>(&self, future: F) -> UntilEmulatorCloses<'_, F> {
         UntilEmulatorCloses {
@@ -160,6 +184,12 @@ impl Emulator {
     /// - from `0x03000000` to `0x03007FFF` for IWRAM
     ///
     /// Values outside these ranges are invalid, and will make this method immediately return `Err()`.
+    ///
+    /// The I/O registers starting at `0x04000000`, such as `VCOUNT`, are not
+    /// mapped. None of the supported emulators keep them in a location that
+    /// can be found reliably, so frame timing needs to be derived from values
+    /// the game itself keeps in RAM, like a frame counter updated by its
+    /// VBlank handler.
     pub fn read<T: CheckedBitPattern>(&self, offset: u32) -> Result<T, Error> {
         match self.check_bounds::<T>(offset) {
             true => self.process.read(self.get_address(offset)?),
@@ -169,6 +199,10 @@ impl Emulator {
 
     /// Follows a path of pointers from the address given and reads a value of the type specified from
     /// the process at the end of the pointer path.
+    ///
+    /// The pointers stored in memory are interpreted as 32-bit emulated
+    /// addresses and translated the same way as the ones passed to
+    /// [`read`](Self::read).
     pub fn read_pointer_path<T: CheckedBitPattern>(
         &self,
         base_address: u32,
@@ -180,12 +214,7 @@ impl Emulator {
     /// Follows a path of pointers from the address given and returns the address at the end
     /// of the pointer path
     fn deref_offsets(&self, base_address: u32, path: &[u32]) -> Result<u32, Error> {
-        let mut address = base_address;
-        let (&last, path) = path.split_last().ok_or(Error {})?;
-        for &offset in path {
-            address = self.read::<u32>(address + offset)?;
-        }
-        Ok(address + last)
+        memory::deref_offsets(base_address, path, |address| self.read::<u32>(address))
     }
 }
 
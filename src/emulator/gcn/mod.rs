@@ -3,14 +3,18 @@
 use core::{
     cell::Cell,
     future::Future,
-    mem::size_of,
-    ops::Sub,
+    mem::{size_of, size_of_val},
     pin::Pin,
     task::{Context, Poll},
 };
 
-use crate::{future::retry, Address, Endian, Error, FromEndian, Process};
-use bytemuck::CheckedBitPattern;
+use crate::{
+    emulator::{memory, retroarch::ContentPath, symbol_map::SymbolMap},
+    future::retry,
+    string::ArrayCString,
+    Address, Endian, Error, FromEndian, Process,
+};
+use bytemuck::{AnyBitPattern, CheckedBitPattern};
 
 mod dolphin;
 mod retroarch;
@@ -25,6 +29,10 @@ pub struct Emulator {
     mem1_base: Cell<Option<Address>>,
     /// The endianness used by the emulator process
     endian: Cell<Endian>,
+    /// The location of the path of the loaded content, if running in Retroarch
+    content_path: ContentPath,
+    /// The symbol map of the game, if one was provided
+    symbol_map: Cell<Option<SymbolMap<'static>>>,
 }
 
 impl Emulator {
@@ -45,6 +53,8 @@ impl Emulator {
             state: Cell::new(state),
             mem1_base: Cell::new(None),
             endian: Cell::new(Endian::Big), // Endianness is usually Big across all GCN emulators
+            content_path: ContentPath::new(),
+            symbol_map: Cell::new(None),
         })
     }
 
@@ -64,6 +74,39 @@ impl Emulator {
         self.process.is_open()
     }
 
+    /// Reads the path of the content (the ROM or disc image) that is currently
+    /// loaded. This allows a single auto splitter to support multiple games,
+    /// or multiple regions of the same game, by branching on the file name.
+    ///
+    /// This is only supported by Retroarch on Windows, regardless of the core
+    /// that is used. Returns `Err()` for every other emulator, while no
+    /// content is loaded or if the path can't be found.
+    pub fn content_path<const N: usize>(&self) -> Result<ArrayCString<N>, Error> {
+        match self.state.get() {
+            State::Retroarch(_) => self.content_path.read(
+                &self.process,
+                &["iso", "gcm", "gcz", "rvz", "ciso", "wia", "dol", "elf"],
+            ),
+            _ => Err(Error {}),
+        }
+    }
+
+    /// Sets the [symbol map](SymbolMap) of the game, which allows looking up
+    /// the addresses of its functions and globals by name with
+    /// [`resolve_symbol`](Self::resolve_symbol). As the map is not part of
+    /// the game's memory, it needs to be embedded into the auto splitter.
+    pub fn set_symbol_map(&self, symbol_map: SymbolMap<'static>) {
+        self.symbol_map.set(Some(symbol_map));
+    }
+
+    /// Looks up the address of the symbol with the name provided in the
+    /// [symbol map](SymbolMap) of the game. The address can be passed to
+    /// [`read`](Self::read) directly. Returns [`None`] if no symbol map was
+    /// set or the symbol is not part of it.
+    pub fn resolve_symbol(&self, name: &str) -> Option<u32> {
+        self.symbol_map.get()?.resolve(name)
+    }
+
     /// Executes a future until the emulator process closes.
     pub const fn until_closes<F>(&self, future: F) -> UntilEmulatorCloses<'_, F> {
         UntilEmulatorCloses {
@@ -112,14 +155,25 @@ impl Emulator {
     ///
     /// Valid addresses range from `0x80000000` to `0x817FFFFF`.
     pub fn get_address(&self, offset: u32) -> Result<Address, Error> {
+        let ram_offset = Self::ram_offset(offset).ok_or(Error {})?;
+        Ok(self.mem1_base.get().ok_or(Error {})? + ram_offset)
+    }
+
+    /// Converts a GameCube memory address to an offset into MEM1, which is
+    /// mapped to `0x80000000`.
+    const fn ram_offset(offset: u32) -> Option<u32> {
         match offset {
-            (0x80000000..=0x817FFFFF) => {
-                Ok(self.mem1_base.get().ok_or(Error {})? + offset.sub(0x80000000))
-            }
-            _ => Err(Error {}),
+            (0x80000000..=0x817FFFFF) => Some(offset - 0x80000000),
+            _ => None,
         }
     }
 
+    /// Converts the bytes of a pointer, as stored by the emulator, into an
+    /// address of the emulated system.
+    fn decode_pointer(bytes: [u8; 4], endian: Endian) -> u32 {
+        u32::from_ne_bytes(bytes).from_endian(endian)
+    }
+
     /// Checks if a memory reading operation would exceed the memory bounds of the emulated system.
     ///
     /// Returns `true` if the read operation can be performed safely, `false` otherwise.
@@ -130,6 +184,17 @@ impl Emulator {
         }
     }
 
+    /// Checks if a memory reading operation of the given amount of bytes would
+    /// exceed the memory bounds of the emulated system.
+    ///
+    /// Returns `true` if the read operation can be performed safely, `false` otherwise.
+    const fn check_bounds_len(offset: u32, len: usize) -> bool {
+        match offset {
+            (0x80000000..=0x817FFFFF) => offset as u64 + len as u64 <= 0x81800000,
+            _ => false,
+        }
+    }
+
     /// Reads raw data from the emulated RAM ignoring all endianness settings.
     /// The same call, performed on two different emulators, might return different
     /// results due to the endianness used by the emulator.
@@ -165,8 +230,53 @@ impl Emulator {
             .from_endian(self.endian.get()))
     }
 
+    /// Reads a contiguous block of values from the emulated RAM into the slice
+    /// provided, converting each of them from the endianness used by the
+    /// emulator.
+    ///
+    /// The offset provided is meant to be the mapped address used on the original,
+    /// big-endian system.
+    ///
+    /// Valid addresses for the Nintendo Gamecube range from `0x80000000` to `0x817FFFFF`.
+    /// The whole block needs to be inside of this range.
+    ///
+    /// Any other invalid value will make this method immediately return `Err()`.
+    pub fn read_into_slice<T: AnyBitPattern + FromEndian>(
+        &self,
+        offset: u32,
+        slice: &mut [T],
+    ) -> Result<(), Error> {
+        if !Self::check_bounds_len(offset, size_of_val(slice)) {
+            return Err(Error {});
+        }
+
+        self.process
+            .read_into_slice(self.get_address(offset)?, slice)?;
+
+        let endian = self.endian.get();
+        for value in slice.iter_mut() {
+            *value = value.from_endian(endian);
+        }
+
+        Ok(())
+    }
+
+    /// Reads the 6-character game ID of the currently loaded game (e.g.
+    /// `GALE01`) from the disc header, which gets copied to the start of MEM1
+    /// at `0x80000000`. This can be used to make sure the auto splitter is
+    /// running against the expected game and region.
+    ///
+    /// Returns `Err()` if the emulated RAM has not been found yet.
+    pub fn game_id(&self) -> Result<ArrayCString<6>, Error> {
+        self.read_ignoring_endianness(0x80000000)
+    }
+
     /// Follows a path of pointers from the address given and reads a value of the type specified from
     /// the process at the end of the pointer path.
+    ///
+    /// The pointers stored in memory are interpreted as 32-bit big endian
+    /// emulated addresses and translated the same way as the ones passed to
+    /// [`read`](Self::read).
     pub fn read_pointer_path<T: CheckedBitPattern + FromEndian>(
         &self,
         base_address: u32,
@@ -178,12 +288,10 @@ impl Emulator {
     /// Follows a path of pointers from the address given and returns the address at the end
     /// of the pointer path
     fn deref_offsets(&self, base_address: u32, path: &[u32]) -> Result<u32, Error> {
-        let mut address = base_address;
-        let (&last, path) = path.split_last().ok_or(Error {})?;
-        for &offset in path {
-            address = self.read::<u32>(address + offset)?;
-        }
-        Ok(address + last)
+        memory::deref_offsets(base_address, path, |address| {
+            let bytes = self.read_ignoring_endianness(address)?;
+            Ok(Self::decode_pointer(bytes, self.endian.get()))
+        })
     }
 }
 
@@ -222,3 +330,38 @@ static PROCESS_NAMES: [(&str, State); 2] = [
     ("Dolphin.exe", State::Dolphin(dolphin::State)),
     ("retroarch.exe", State::Retroarch(retroarch::State::new())),
 ];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn slice_bounds_of_mem1() {
+        assert!(Emulator::check_bounds_len(0x80000000, 0x1800000));
+        assert!(Emulator::check_bounds_len(0x817FFFF0, 16));
+        assert!(!Emulator::check_bounds_len(0x817FFFF0, 17));
+        assert!(Emulator::check_bounds_len(0x817FFFFF, 1));
+        assert!(!Emulator::check_bounds_len(0x817FFFFF, 2));
+        assert!(!Emulator::check_bounds_len(0x81800000, 1));
+        assert!(!Emulator::check_bounds_len(0x7FFFFFFF, 1));
+    }
+
+    #[test]
+    fn big_endian_pointers_in_mem1() {
+        let bytes = 0x8012_3456u32.to_be_bytes();
+        let pointer = Emulator::decode_pointer(bytes, Endian::Big);
+        assert_eq!(pointer, 0x8012_3456);
+        assert_eq!(Emulator::ram_offset(pointer), Some(0x12_3456));
+
+        // Some emulators byte swap the memory of the emulated system.
+        let bytes = 0x8012_3456u32.to_le_bytes();
+        assert_eq!(Emulator::decode_pointer(bytes, Endian::Little), 0x8012_3456);
+
+        assert_eq!(Emulator::ram_offset(0x8000_0000), Some(0));
+        assert_eq!(Emulator::ram_offset(0x817F_FFFF), Some(0x17F_FFFF));
+        assert_eq!(Emulator::ram_offset(0x8180_0000), None);
+        assert_eq!(Emulator::ram_offset(0x7FFF_FFFF), None);
+        // A null pointer doesn't point into MEM1.
+        assert_eq!(Emulator::ram_offset(0), None);
+    }
+}
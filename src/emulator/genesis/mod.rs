@@ -9,7 +9,12 @@ use core::{
     task::{Context, Poll},
 };
 
-use crate::{future::retry, Address, Endian, Error, FromEndian, Process};
+use crate::{
+    emulator::{memory, retroarch::ContentPath},
+    future::retry,
+    string::ArrayCString,
+    Address, Endian, Error, FromEndian, Process,
+};
 use bytemuck::CheckedBitPattern;
 
 mod blastem;
@@ -19,6 +24,10 @@ mod retroarch;
 mod segaclassics;
 
 /// A SEGA Genesis emulator that the auto splitter is attached to.
+///
+/// Only the 68k work RAM and, for some cores, the cartridge's SRAM are mapped.
+/// The memory of the 32X and the Sega CD add-ons is not accessible, as none of
+/// the supported emulators expose it in a way that can be located reliably.
 pub struct Emulator {
     /// The attached emulator process
     process: Process,
@@ -26,8 +35,12 @@ pub struct Emulator {
     state: Cell<State>,
     /// The memory address of the emulated RAM
     wram_base: Cell<Option<Address>>,
+    /// The memory address of the cartridge's battery-backed SRAM, if exposed
+    sram_base: Cell<Option<Address>>,
     /// The endianness used by the emulator process
     endian: Cell<Endian>,
+    /// The location of the path of the loaded content, if running in Retroarch
+    content_path: ContentPath,
 }
 
 impl Emulator {
@@ -50,7 +63,9 @@ impl Emulator {
             process,
             state: Cell::new(state),
             wram_base: Cell::new(None),
+            sram_base: Cell::new(None),
             endian: Cell::new(Endian::Little), // Endianness is supposed to be Little, until stated otherwise in the code
+            content_path: ContentPath::new(),
         })
     }
 
@@ -73,6 +88,25 @@ impl Emulator {
         self.process.is_open()
     }
 
+    /// Reads the path of the content (the ROM or disc image) that is currently
+    /// loaded. This allows a single auto splitter to support multiple games,
+    /// or multiple regions of the same game, by branching on the file name.
+    ///
+    /// This is only supported by Retroarch on Windows, regardless of the core
+    /// that is used. Returns `Err()` for every other emulator, while no
+    /// content is loaded or if the path can't be found.
+    pub fn content_path<const N: usize>(&self) -> Result<ArrayCString<N>, Error> {
+        match self.state.get() {
+            State::Retroarch(_) => self.content_path.read(
+                &self.process,
+                &[
+                    "md", "gen", "smd", "bin", "32x", "cue", "chd", "iso", "zip", "7z",
+                ],
+            ),
+            _ => Err(Error {}),
+        }
+    }
+
     /// Executes a future until the emulator process closes.
     pub const fn until_closes<F>(&self, future: F) -> UntilEmulatorCloses<'_, F> {
         UntilEmulatorCloses {
@@ -111,8 +145,16 @@ impl Emulator {
             State::BlastEm(x) => x.keep_alive(),
         };
 
+        // Not every game has SRAM and not every emulator exposes it, so not
+        // finding it doesn't count as a failure.
+        let sram_base = match &mut state {
+            State::Retroarch(x) if success => x.sram_base(&self.process),
+            _ => None,
+        };
+
         self.endian.set(endian);
         self.state.set(state);
+        self.sram_base.set(sram_base);
 
         if success {
             self.wram_base.set(wram_base);
@@ -143,6 +185,17 @@ impl Emulator {
         }
     }
 
+    /// Converts an offset into the cartridge's SRAM to a real memory address in
+    /// the emulator process' virtual memory space
+    ///
+    /// The offset provided must not be higher than `0xFFFF`
+    pub fn get_sram_address(&self, offset: u32) -> Result<Address, Error> {
+        match offset {
+            (0..=0xFFFF) => Ok(self.sram_base.get().ok_or(Error {})? + offset),
+            _ => Err(Error {}),
+        }
+    }
+
     /// Reads any value from the emulated RAM.
     ///
     /// The offset provided is meant to be the same used on the original,
@@ -205,6 +258,68 @@ impl Emulator {
             Ok(value.assume_init().from_be())
         }
     }
+
+    /// Reads any value from the cartridge's battery-backed SRAM.
+    ///
+    /// The offset provided is relative to the start of the SRAM, which on the
+    /// original hardware is usually mapped at `0x200000`. Values stored in SRAM
+    /// are treated as big-endian and converted accordingly.
+    ///
+    /// SRAM is currently only exposed by Retroarch, when using one of the
+    /// Genesis Plus GX cores (`genesis_plus_gx_libretro.dll`,
+    /// `genesis_plus_gx_wide_libretro.dll`). It is also only available if the
+    /// game actually uses SRAM. In every other case this method will return
+    /// `Err()`.
+    ///
+    /// The offset provided must not be higher than `0xFFFF`, otherwise this
+    /// method will immediately return `Err()`.
+    pub fn read_sram<T: CheckedBitPattern + FromEndian>(&self, offset: u32) -> Result<T, Error> {
+        if !self.check_bounds::<T>(offset) {
+            return Err(Error {});
+        }
+
+        Ok(self
+            .process
+            .read::<T>(self.get_sram_address(offset)?)?
+            .from_be())
+    }
+
+    /// Reads a pointer from the emulated RAM and converts it into an offset
+    /// that can be passed to [`read`](Self::read). Pointers are stored as 32-bit
+    /// big endian values and are converted regardless of the endianness used
+    /// by the emulator. Only the lower 24 bits are used by the Motorola 68000,
+    /// so the work RAM can be reached through any address from `0xE00000` to
+    /// `0xFFFFFF`, as it's mirrored across that whole range.
+    ///
+    /// Returns `Err()` if the pointer doesn't point into the work RAM.
+    pub fn read_pointer(&self, offset: u32) -> Result<u32, Error> {
+        Self::pointer_offset(self.read(offset)?)
+    }
+
+    /// Converts a pointer of the Motorola 68000 into an offset into the work
+    /// RAM. Returns `Err()` if the pointer doesn't point into the work RAM.
+    const fn pointer_offset(pointer: u32) -> Result<u32, Error> {
+        match pointer & 0xFFFFFF {
+            pointer @ 0xE00000..=0xFFFFFF => Ok(pointer & 0xFFFF),
+            _ => Err(Error {}),
+        }
+    }
+
+    /// Follows a path of pointers from the offset given and reads a value of
+    /// the type specified from the emulated RAM at the end of the pointer path.
+    ///
+    /// The pointers stored in memory are interpreted as 32-bit big endian
+    /// addresses and translated the same way as the ones read by
+    /// [`read_pointer`](Self::read_pointer).
+    pub fn read_pointer_path<T: CheckedBitPattern + FromEndian>(
+        &self,
+        base_offset: u32,
+        path: &[u32],
+    ) -> Result<T, Error> {
+        self.read(memory::deref_offsets(base_offset, path, |offset| {
+            self.read_pointer(offset)
+        })?)
+    }
 }
 
 /// A future that executes a future until the emulator closes.
@@ -255,3 +370,35 @@ static PROCESS_NAMES: [(&str, State); 6] = [
     ("gens.exe", State::Gens(gens::State)),
     ("blastem.exe", State::BlastEm(blastem::State)),
 ];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn two_level_big_endian_pointer_path() {
+        let mut ram = [0u8; 0x10000];
+        // The first pointer uses the upper byte, which the 68000 ignores.
+        ram[0x0010..0x0014].copy_from_slice(&0xABFF_8000u32.to_be_bytes());
+        // The second pointer uses a mirror of the work RAM.
+        ram[0x8004..0x8008].copy_from_slice(&0x00E0_1230u32.to_be_bytes());
+        // This one points into the cartridge ROM instead.
+        ram[0x8008..0x800C].copy_from_slice(&0x0001_2340u32.to_be_bytes());
+        let read_pointer = |offset: u32| {
+            let bytes = ram
+                .get(offset as usize..offset as usize + 4)
+                .ok_or(Error {})?;
+            Emulator::pointer_offset(u32::from_be_bytes(bytes.try_into().unwrap()))
+        };
+
+        assert_eq!(
+            memory::deref_offsets(0x0000, &[0x10, 0x4, 0x8], read_pointer).ok(),
+            Some(0x1238),
+        );
+        assert!(memory::deref_offsets(0x0000, &[0x10, 0x8, 0x0], read_pointer).is_err());
+
+        assert_eq!(Emulator::pointer_offset(0x00FF_FFFF).ok(), Some(0xFFFF));
+        assert_eq!(Emulator::pointer_offset(0xFFE0_0000).ok(), Some(0));
+        assert!(Emulator::pointer_offset(0x00DF_FFFF).is_err());
+    }
+}
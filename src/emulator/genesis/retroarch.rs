@@ -1,10 +1,14 @@
 use crate::{
-    file_format::pe, signature::Signature, Address, Address32, Endian, MemoryRangeFlags, Process,
+    file_format::pe, signature::Signature, Address, Address32, Endian, MemoryRangeFlags,
+    PointerSize, Process,
 };
 
 #[derive(Copy, Clone, Debug, PartialEq, Eq)]
 pub struct State {
     core_base: Address,
+    /// The address of Genesis Plus GX's `sram` global, if the core is used.
+    sram: Option<Address>,
+    pointer_size: PointerSize,
 }
 
 impl State {
@@ -29,6 +33,12 @@ impl State {
             .find_map(|&m| Some((m, game.get_module_address(m).ok()?)))?;
 
         self.core_base = core_address;
+        self.sram = None;
+        self.pointer_size = if is_x86_64 {
+            PointerSize::Bit64
+        } else {
+            PointerSize::Bit32
+        };
 
         if core_name == SUPPORTED_CORES[0] {
             *endian = Endian::Little;
@@ -55,6 +65,8 @@ impl State {
             *endian = Endian::Little;
 
             // Genesis plus GX
+            self.sram = self.find_sram(game);
+
             if is_x86_64 {
                 const SIG_64: Signature<10> = Signature::new("48 8D 0D ?? ?? ?? ?? 4C 8B 2D");
 
@@ -110,6 +122,62 @@ impl State {
         }
     }
 
+    /// Looks for the `sram` global of Genesis Plus GX through the
+    /// `retro_get_memory_data` export, which returns `sram.sram` for
+    /// `RETRO_MEMORY_SAVE_RAM`. The function also loads other pointers, so
+    /// every candidate load is checked for pointing into a plausible `T_SRAM`
+    /// struct.
+    fn find_sram(&self, game: &Process) -> Option<Address> {
+        let function_address = pe::symbols(game, self.core_base)
+            .find(|symbol| {
+                symbol
+                    .get_name::<22>(game)
+                    .is_ok_and(|name| name.matches("retro_get_memory_data"))
+            })?
+            .address;
+
+        let mut function = [0; 0x100];
+        game.read_into_buf(function_address, &mut function).ok()?;
+
+        let candidates = (0..function.len()).filter_map(|i| {
+            let operand = |at: usize| {
+                Some(u32::from_le_bytes(
+                    function.get(at..at + 4)?.try_into().ok()?,
+                ))
+            };
+            match (self.pointer_size, &function[i..]) {
+                // mov rax, [rip + disp32]
+                (PointerSize::Bit64, [0x48, 0x8B, 0x05, ..]) => {
+                    Some(function_address + (i + 7) as u64 + operand(i + 3)? as i32)
+                }
+                // mov eax, [disp32]
+                (PointerSize::Bit32, [0xA1, ..]) => Some(Address::new(operand(i + 1)? as u64)),
+                _ => None,
+            }
+        });
+
+        candidates
+            .map(|pointer: Address| pointer.add_signed(-(sram_offsets::SRAM as i64)))
+            .find(|&sram| is_sram(game, sram))
+    }
+
+    /// Returns the address of the SRAM, if the game loaded in Genesis Plus GX
+    /// uses it.
+    pub fn sram_base(&self, game: &Process) -> Option<Address> {
+        let sram = self.sram?;
+        if game.read::<u8>(sram + sram_offsets::ON).ok()? == 0 {
+            return None;
+        }
+        let sram = game
+            .read_pointer(sram + sram_offsets::SRAM, self.pointer_size)
+            .ok()?;
+        // The SRAM is 64 KiB large.
+        if sram.is_null() || game.read::<u8>(sram + 0xFFFF_u32).is_err() {
+            return None;
+        }
+        Some(sram)
+    }
+
     pub fn keep_alive(&self, game: &Process) -> bool {
         game.read::<u8>(self.core_base).is_ok()
     }
@@ -117,6 +185,44 @@ impl State {
     pub const fn new() -> Self {
         Self {
             core_base: Address::NULL,
+            sram: None,
+            pointer_size: PointerSize::Bit64,
         }
     }
 }
+
+/// The layout of `T_SRAM`, which is the same for 32-bit and 64-bit builds.
+///
+/// <https://github.com/libretro/Genesis-Plus-GX/blob/master/core/cart_hw/sram.h>
+mod sram_offsets {
+    pub const DETECT: u64 = 0x0;
+    pub const ON: u64 = 0x1;
+    pub const CUSTOM: u64 = 0x2;
+    pub const START: u64 = 0x4;
+    pub const END: u64 = 0x8;
+    pub const SRAM: u64 = 0x10;
+}
+
+/// Checks that the flags are booleans and that the SRAM is mapped to a range
+/// within the 24-bit address space of the Genesis.
+fn is_sram(game: &Process, sram: Address) -> bool {
+    let Ok(header) = game.read::<[u8; 12]>(sram) else {
+        return false;
+    };
+    let flag = |offset: u64| header[offset as usize] <= 1;
+    let word = |offset: u64| {
+        let offset = offset as usize;
+        u32::from_le_bytes([
+            header[offset],
+            header[offset + 1],
+            header[offset + 2],
+            header[offset + 3],
+        ])
+    };
+    let (start, end) = (word(sram_offsets::START), word(sram_offsets::END));
+    flag(sram_offsets::DETECT)
+        && flag(sram_offsets::ON)
+        && flag(sram_offsets::CUSTOM)
+        && start <= end
+        && end <= 0xFF_FFFF
+}
@@ -0,0 +1,112 @@
+use bytemuck::CheckedBitPattern;
+
+use crate::{Error, FromEndian};
+
+/// The memory of an emulated system, which allows code to be written once
+/// for all the emulators that implement this trait. This is useful for
+/// games that got released on multiple systems, or for code that is shared
+/// between several auto splitters.
+///
+/// Addresses are always passed as they are used by the emulated system. The
+/// values are converted from the endianness of the emulated system, which is
+/// why they need to implement [`FromEndian`]. The inherent methods of the
+/// emulators are the same, but may have fewer requirements, such as not
+/// requiring [`FromEndian`] on systems that are little endian.
+///
+/// # Example
+///
+/// ```no_run
+/// # use asr::{emulator::EmulatedMemory, Error};
+/// fn read_lives(memory: &impl EmulatedMemory, lives_address: u32) -> Result<u8, Error> {
+///     memory.read(lives_address)
+/// }
+/// ```
+pub trait EmulatedMemory {
+    /// Reads a value of the type specified from the emulated memory at the
+    /// address given.
+    fn read<T: CheckedBitPattern + FromEndian>(&self, address: u32) -> Result<T, Error>;
+
+    /// Follows a path of pointers from the address given and reads a value of
+    /// the type specified from the emulated memory at the end of the pointer
+    /// path. The pointers are interpreted as addresses of the emulated
+    /// system.
+    fn read_pointer_path<T: CheckedBitPattern + FromEndian>(
+        &self,
+        base_address: u32,
+        path: &[u32],
+    ) -> Result<T, Error>;
+}
+
+macro_rules! impl_emulated_memory {
+    ($($feature:literal => $emulator:ty),* $(,)?) => {
+        $(
+            #[cfg(feature = $feature)]
+            impl EmulatedMemory for $emulator {
+                #[inline]
+                fn read<T: CheckedBitPattern + FromEndian>(&self, address: u32) -> Result<T, Error> {
+                    <$emulator>::read(self, address)
+                }
+
+                #[inline]
+                fn read_pointer_path<T: CheckedBitPattern + FromEndian>(
+                    &self,
+                    base_address: u32,
+                    path: &[u32],
+                ) -> Result<T, Error> {
+                    <$emulator>::read_pointer_path(self, base_address, path)
+                }
+            }
+        )*
+    };
+}
+
+/// Follows a path of pointers from the address given and returns the address
+/// at the end of the pointer path. Each pointer is read with the function
+/// provided, which also translates it into an address of the emulated system.
+pub(super) fn deref_offsets(
+    base_address: u32,
+    path: &[u32],
+    mut read_pointer: impl FnMut(u32) -> Result<u32, Error>,
+) -> Result<u32, Error> {
+    let mut address = base_address;
+    let (&last, path) = path.split_last().ok_or(Error {})?;
+    for &offset in path {
+        address = read_pointer(address.wrapping_add(offset))?;
+    }
+    Ok(address.wrapping_add(last))
+}
+
+impl_emulated_memory!(
+    "gba" => super::gba::Emulator,
+    "gcn" => super::gcn::Emulator,
+    "genesis" => super::genesis::Emulator,
+    "ps1" => super::ps1::Emulator,
+    "ps2" => super::ps2::Emulator,
+    "sms" => super::sms::Emulator,
+    "wii" => super::wii::Emulator,
+);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn deref_offsets_reads_every_offset_but_the_last() {
+        let mut reads = [0; 2];
+        let mut count = 0;
+        let address = deref_offsets(0x100, &[0x10, 0x20, 0x4], |address| {
+            reads[count] = address;
+            count += 1;
+            Ok(address * 2)
+        });
+        assert_eq!(address.ok(), Some(0x484));
+        assert_eq!(reads, [0x110, 0x240]);
+
+        assert_eq!(
+            deref_offsets(0x100, &[0x4], |_| Err(Error {})).ok(),
+            Some(0x104)
+        );
+        assert!(deref_offsets(0x100, &[], |_| Ok(0)).is_err());
+        assert!(deref_offsets(0x100, &[0x4, 0x4], |_| Err(Error {})).is_err());
+    }
+}
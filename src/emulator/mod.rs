@@ -1,16 +1,51 @@
 //! Support for attaching to various emulators.
 
+#[cfg(feature = "flags")]
+pub mod bizhawk;
 #[cfg(feature = "gba")]
 pub mod gba;
 #[cfg(feature = "gcn")]
 pub mod gcn;
 #[cfg(feature = "genesis")]
 pub mod genesis;
+#[cfg(any(
+    feature = "gba",
+    feature = "gcn",
+    feature = "genesis",
+    feature = "ps1",
+    feature = "ps2",
+    feature = "sms",
+    feature = "wii"
+))]
+mod memory;
 #[cfg(feature = "ps1")]
 pub mod ps1;
 #[cfg(feature = "ps2")]
 pub mod ps2;
+#[cfg(any(
+    feature = "gba",
+    feature = "gcn",
+    feature = "genesis",
+    feature = "ps1",
+    feature = "ps2",
+    feature = "sms",
+    feature = "wii"
+))]
+mod retroarch;
 #[cfg(feature = "sms")]
 pub mod sms;
+#[cfg(any(feature = "gcn", feature = "wii"))]
+pub mod symbol_map;
 #[cfg(feature = "wii")]
 pub mod wii;
+
+#[cfg(any(
+    feature = "gba",
+    feature = "gcn",
+    feature = "genesis",
+    feature = "ps1",
+    feature = "ps2",
+    feature = "sms",
+    feature = "wii"
+))]
+pub use self::memory::EmulatedMemory;
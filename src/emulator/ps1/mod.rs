@@ -4,12 +4,16 @@ use core::{
     cell::Cell,
     future::Future,
     mem::size_of,
-    ops::Sub,
     pin::Pin,
     task::{Context, Poll},
 };
 
-use crate::{future::retry, Address, Error, Process};
+use crate::{
+    emulator::{memory, retroarch::ContentPath},
+    future::retry,
+    string::ArrayCString,
+    Address, Error, Process,
+};
 use bytemuck::CheckedBitPattern;
 
 mod duckstation;
@@ -21,6 +25,11 @@ mod retroarch;
 mod xebra;
 
 /// A Playstation 1 emulator that the auto splitter is attached to.
+///
+/// Only the 2MB of main RAM are mapped, as that's the only memory region the
+/// supported emulators can be located reliably for. The scratchpad and the GTE
+/// registers are not accessible. Access is read-only, as the auto splitting
+/// runtime provides no way to write to the memory of a process.
 pub struct Emulator {
     /// The attached emulator process
     process: Process,
@@ -28,6 +37,8 @@ pub struct Emulator {
     state: Cell<State>,
     /// The memory address of the emulated RAM
     ram_base: Cell<Option<Address>>,
+    /// The location of the path of the loaded content, if running in Retroarch
+    content_path: ContentPath,
 }
 
 impl Emulator {
@@ -52,6 +63,7 @@ impl Emulator {
             process,
             state: Cell::new(state),
             ram_base: Cell::new(None),
+            content_path: ContentPath::new(),
         })
     }
 
@@ -76,6 +88,25 @@ impl Emulator {
         self.process.is_open()
     }
 
+    /// Reads the path of the content (the ROM or disc image) that is currently
+    /// loaded. This allows a single auto splitter to support multiple games,
+    /// or multiple regions of the same game, by branching on the file name.
+    ///
+    /// This is only supported by Retroarch on Windows, regardless of the core
+    /// that is used. Returns `Err()` for every other emulator, while no
+    /// content is loaded or if the path can't be found.
+    pub fn content_path<const N: usize>(&self) -> Result<ArrayCString<N>, Error> {
+        match self.state.get() {
+            State::Retroarch(_) => self.content_path.read(
+                &self.process,
+                &[
+                    "cue", "bin", "chd", "iso", "img", "pbp", "m3u", "ccd", "exe",
+                ],
+            ),
+            _ => Err(Error {}),
+        }
+    }
+
     /// Executes a future until the emulator process closes.
     pub const fn until_closes<F>(&self, future: F) -> UntilEmulatorCloses<'_, F> {
         UntilEmulatorCloses {
@@ -132,11 +163,28 @@ impl Emulator {
     ///
     /// Valid addresses for the PS1 range from `0x80000000` to `0x817FFFFF`.
     pub fn get_address(&self, offset: u32) -> Result<Address, Error> {
+        let ram_offset = Self::ram_offset(offset).ok_or(Error {})?;
+        Ok(self.ram_base.get().ok_or(Error {})? + ram_offset)
+    }
+
+    /// Converts a PS1 memory address to an offset into the RAM, which is
+    /// mapped to `0x80000000`.
+    const fn ram_offset(offset: u32) -> Option<u32> {
         match offset {
-            (0x80000000..=0x817FFFFF) => {
-                Ok(self.ram_base.get().ok_or(Error {})? + offset.sub(0x80000000))
-            }
-            _ => Err(Error {}),
+            (0x80000000..=0x817FFFFF) => Some(offset - 0x80000000),
+            _ => None,
+        }
+    }
+
+    /// Converts the bytes of a 32-bit little endian pointer into an address
+    /// that can be passed to [`read`](Self::read). Pointers into the `KUSEG`
+    /// and `KSEG1` mirrors of the RAM are mapped to `KSEG0`.
+    ///
+    /// Returns `Err()` for null pointers.
+    const fn decode_pointer(bytes: [u8; 4]) -> Result<u32, Error> {
+        match u32::from_le_bytes(bytes) {
+            0 => Err(Error {}),
+            pointer => Ok(pointer & 0x1FFFFFFF | 0x80000000),
         }
     }
 
@@ -171,6 +219,12 @@ impl Emulator {
 
     /// Follows a path of pointers from the address given and reads a value of the type specified from
     /// the process at the end of the pointer path.
+    ///
+    /// The pointers stored in memory are interpreted as emulated addresses and
+    /// translated the same way as the ones passed to [`read`](Self::read).
+    /// Pointers into any of the MIPS segments that mirror the RAM (`KUSEG` at
+    /// `0x00000000`, `KSEG0` at `0x80000000` and `KSEG1` at `0xA0000000`) are
+    /// accepted, as games use all of them.
     pub fn read_pointer_path<T: CheckedBitPattern>(
         &self,
         base_address: u32,
@@ -182,12 +236,9 @@ impl Emulator {
     /// Follows a path of pointers from the address given and returns the address at the end
     /// of the pointer path
     fn deref_offsets(&self, base_address: u32, path: &[u32]) -> Result<u32, Error> {
-        let mut address = base_address;
-        let (&last, path) = path.split_last().ok_or(Error {})?;
-        for &offset in path {
-            address = self.read::<u32>(address + offset)?;
-        }
-        Ok(address + last)
+        memory::deref_offsets(base_address, path, |address| {
+            Self::decode_pointer(self.read(address)?)
+        })
     }
 }
 
@@ -246,3 +297,22 @@ const PROCESS_NAMES: [(&str, State); 8] = [
     ("XEBRA.EXE", State::Xebra(xebra::State)),
     ("mednafen.exe", State::Mednafen(mednafen::State)),
 ];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn pointers_into_the_mirrors_of_the_ram_are_mapped_to_kseg0() {
+        for pointer in [0x0000_1234u32, 0x8000_1234, 0xA000_1234] {
+            let address = Emulator::decode_pointer(pointer.to_le_bytes());
+            assert_eq!(address.ok(), Some(0x8000_1234));
+            assert_eq!(Emulator::ram_offset(0x8000_1234), Some(0x1234));
+        }
+        assert!(Emulator::decode_pointer([0; 4]).is_err());
+
+        assert_eq!(Emulator::ram_offset(0x817F_FFFF), Some(0x17F_FFFF));
+        assert_eq!(Emulator::ram_offset(0x8180_0000), None);
+        assert_eq!(Emulator::ram_offset(0x0000_1234), None);
+    }
+}
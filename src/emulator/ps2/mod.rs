@@ -4,12 +4,16 @@ use core::{
     cell::Cell,
     future::Future,
     mem::size_of,
-    ops::Sub,
     pin::Pin,
     task::{Context, Poll},
 };
 
-use crate::{future::retry, Address, Error, Process};
+use crate::{
+    emulator::{memory, retroarch::ContentPath},
+    future::retry,
+    string::ArrayCString,
+    Address, Error, Process,
+};
 use bytemuck::CheckedBitPattern;
 
 mod pcsx2;
@@ -23,6 +27,8 @@ pub struct Emulator {
     state: Cell<State>,
     /// The memory address of the emulated RAM
     ram_base: Cell<Option<Address>>,
+    /// The location of the path of the loaded content, if running in Retroarch
+    content_path: ContentPath,
 }
 
 impl Emulator {
@@ -42,6 +48,7 @@ impl Emulator {
             process,
             state: Cell::new(state),
             ram_base: Cell::new(None),
+            content_path: ContentPath::new(),
         })
     }
 
@@ -61,6 +68,23 @@ impl Emulator {
         self.process.is_open()
     }
 
+    /// Reads the path of the content (the ROM or disc image) that is currently
+    /// loaded. This allows a single auto splitter to support multiple games,
+    /// or multiple regions of the same game, by branching on the file name.
+    ///
+    /// This is only supported by Retroarch on Windows, regardless of the core
+    /// that is used. Returns `Err()` for every other emulator, while no
+    /// content is loaded or if the path can't be found.
+    pub fn content_path<const N: usize>(&self) -> Result<ArrayCString<N>, Error> {
+        match self.state.get() {
+            State::Retroarch(_) => self.content_path.read(
+                &self.process,
+                &["iso", "chd", "cso", "bin", "cue", "gz", "elf"],
+            ),
+            _ => Err(Error {}),
+        }
+    }
+
     /// Executes a future until the emulator process closes.
     pub const fn until_closes<F>(&self, future: F) -> UntilEmulatorCloses<'_, F> {
         UntilEmulatorCloses {
@@ -107,14 +131,26 @@ impl Emulator {
     ///
     /// Valid addresses for the PS2 range from `0x00100000` to `0x01FFFFFF`.
     pub fn get_address(&self, offset: u32) -> Result<Address, Error> {
+        let ram_offset = Self::ram_offset(offset).ok_or(Error {})?;
+        Ok(self.ram_base.get().ok_or(Error {})? + ram_offset)
+    }
+
+    /// Converts a PS2 memory address to an offset into the part of the RAM
+    /// that is mapped from `0x00100000` on.
+    const fn ram_offset(offset: u32) -> Option<u32> {
         match offset {
-            (0x00100000..=0x01FFFFFF) => {
-                Ok(self.ram_base.get().ok_or(Error {})? + offset.sub(0x00100000))
-            }
-            _ => Err(Error {}),
+            (0x00100000..=0x01FFFFFF) => Some(offset - 0x00100000),
+            _ => None,
         }
     }
 
+    /// Converts the bytes of a 32-bit little endian pointer into an address
+    /// that can be passed to [`read`](Self::read). Pointers into the uncached
+    /// mirrors of the RAM are mapped to the cached addresses.
+    const fn decode_pointer(bytes: [u8; 4]) -> u32 {
+        u32::from_le_bytes(bytes) & 0x0FFFFFFF
+    }
+
     /// Checks if a memory reading operation would exceed the memory bounds of the emulated system.
     ///
     /// Returns `true` if the read operation can be performed safely, `false` otherwise.
@@ -134,7 +170,9 @@ impl Emulator {
     /// Valid addresses for the PS2's memory range from `0x00100000` to `0x01FFFFFF`
     ///
     /// Providing any offset outside the range of the PS2's RAM will return
-    /// `Err()`.
+    /// `Err()`. This includes the kernel area below `0x00100000` and the EE
+    /// scratchpad at `0x70000000`, which are not mapped by any of the
+    /// supported emulators.
     pub fn read<T: CheckedBitPattern>(&self, offset: u32) -> Result<T, Error> {
         match self.check_bounds::<T>(offset) {
             true => self.process.read(self.get_address(offset)?),
@@ -144,6 +182,11 @@ impl Emulator {
 
     /// Follows a path of pointers from the address given and reads a value of the type specified from
     /// the process at the end of the pointer path.
+    ///
+    /// The pointers stored in memory are interpreted as emulated addresses and
+    /// translated the same way as the ones passed to [`read`](Self::read).
+    /// Pointers into the uncached (`0x20000000`) and uncached accelerated
+    /// (`0x30000000`) mirrors of the RAM are accepted as well.
     pub fn read_pointer_path<T: CheckedBitPattern>(
         &self,
         base_address: u32,
@@ -155,12 +198,9 @@ impl Emulator {
     /// Follows a path of pointers from the address given and returns the address at the end
     /// of the pointer path
     fn deref_offsets(&self, base_address: u32, path: &[u32]) -> Result<u32, Error> {
-        let mut address = base_address;
-        let (&last, path) = path.split_last().ok_or(Error {})?;
-        for &offset in path {
-            address = self.read::<u32>(address + offset)?;
-        }
-        Ok(address + last)
+        memory::deref_offsets(base_address, path, |address| {
+            Ok(Self::decode_pointer(self.read(address)?))
+        })
     }
 }
 
@@ -203,3 +243,25 @@ const PROCESS_NAMES: [(&str, State); 6] = [
     ("pcsx2.exe", State::Pcsx2(pcsx2::State::new())),
     ("retroarch.exe", State::Retroarch(retroarch::State::new())),
 ];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn pointers_into_the_uncached_mirrors_of_the_ram_are_mapped() {
+        // Cached, uncached and uncached accelerated addresses of the same byte.
+        for pointer in [0x0012_3456u32, 0x2012_3456, 0x3012_3456] {
+            let address = Emulator::decode_pointer(pointer.to_le_bytes());
+            assert_eq!(address, 0x0012_3456);
+            assert_eq!(Emulator::ram_offset(address), Some(0x2_3456));
+        }
+
+        assert_eq!(Emulator::ram_offset(0x0010_0000), Some(0));
+        assert_eq!(Emulator::ram_offset(0x01FF_FFFF), Some(0x1EF_FFFF));
+        assert_eq!(Emulator::ram_offset(0x0200_0000), None);
+        // The kernel area and null pointers are outside of the RAM.
+        assert_eq!(Emulator::ram_offset(0x000F_FFFF), None);
+        assert_eq!(Emulator::ram_offset(Emulator::decode_pointer([0; 4])), None);
+    }
+}
@@ -0,0 +1,147 @@
+//! Shared support for RetroArch, independent of the core that is used.
+
+use core::cell::Cell;
+
+use crate::{string::ArrayCString, Address, Error, MemoryRangeFlags, Process};
+
+const PAGE_SIZE: u64 = 4 << 10;
+
+/// The size of the path buffers of RetroArch on desktop platforms.
+const PATH_MAX_LENGTH: usize = 4 << 10;
+
+type Path = ArrayCString<PATH_MAX_LENGTH>;
+
+/// Locates the path of the content that is currently loaded by RetroArch.
+///
+/// RetroArch stores the path in the `path_content` buffer of its global
+/// state, which is directly followed by the `path_libretro` buffer holding
+/// the path of the loaded core. Other paths with the same file extensions,
+/// such as the entries of the history, are not followed by the path of a
+/// core, so the pair of buffers is what identifies the content path. The
+/// buffers never move, so their address is cached, but the path of the core
+/// is checked again on every read, so an address that turns out to be wrong
+/// doesn't stick.
+///
+/// Only the Windows version of RetroArch is supported, as `retroarch.exe` is
+/// the only RetroArch process the emulators attach to.
+pub(crate) struct ContentPath {
+    address: Cell<Option<Address>>,
+}
+
+impl ContentPath {
+    pub(crate) const fn new() -> Self {
+        Self {
+            address: Cell::new(None),
+        }
+    }
+
+    pub(crate) fn read<const N: usize>(
+        &self,
+        process: &Process,
+        extensions: &[&str],
+    ) -> Result<ArrayCString<N>, Error> {
+        let address = match self.address.get() {
+            Some(address) if is_core_path(process, address + PATH_MAX_LENGTH as u64) => address,
+            _ => {
+                self.address.set(None);
+                let address = find(process, extensions).ok_or(Error {})?;
+                self.address.set(Some(address));
+                address
+            }
+        };
+
+        // The buffer is empty while no content is loaded.
+        let path = process.read::<ArrayCString<N>>(address)?;
+        if path.is_empty() {
+            return Err(Error {});
+        }
+        Ok(path)
+    }
+}
+
+fn find(process: &Process, extensions: &[&str]) -> Option<Address> {
+    let (module_start, module_size) = process.get_module_range("retroarch.exe").ok()?;
+    let module_end = module_start.value() + module_size;
+
+    let mut buf = [0; PAGE_SIZE as usize];
+
+    process
+        .memory_ranges()
+        .filter(|m| {
+            m.flags()
+                .unwrap_or_default()
+                .contains(MemoryRangeFlags::WRITE)
+        })
+        .filter_map(|m| m.range().ok())
+        .filter(|&(address, size)| {
+            address.value() < module_end && address.value() + size > module_start.value()
+        })
+        .find_map(|(address, size)| {
+            let end = (address.value() + size).min(module_end);
+            let mut page = address.value().max(module_start.value()) & !(PAGE_SIZE - 1);
+            // Strings start right after a nul byte, which may be the last
+            // byte of the previous page.
+            let mut previous = 0;
+
+            while page < end {
+                if process.read_into_buf_quietly(page, &mut buf).is_err() {
+                    previous = 1;
+                    page += PAGE_SIZE;
+                    continue;
+                }
+
+                for i in 0..buf.len() {
+                    let starts_string = match i {
+                        0 => previous == 0,
+                        _ => buf[i - 1] == 0,
+                    };
+                    // The prefix of the path is only checked within the page,
+                    // while the whole path is read from the process, so paths
+                    // that continue on the next page are found as well.
+                    let rest = &buf[i..];
+                    if starts_string && rest[0] != 0 && (rest.len() < 3 || is_absolute_path(rest)) {
+                        let candidate = Address::new(page + i as u64);
+                        if is_content_path(process, candidate, extensions) {
+                            return Some(candidate);
+                        }
+                    }
+                }
+
+                previous = buf[buf.len() - 1];
+                page += PAGE_SIZE;
+            }
+
+            None
+        })
+}
+
+fn is_content_path(process: &Process, address: Address, extensions: &[&str]) -> bool {
+    process
+        .read::<Path>(address)
+        .is_ok_and(|path| is_absolute_path(&path) && has_extension(&path, extensions))
+        && is_core_path(process, address + PATH_MAX_LENGTH as u64)
+}
+
+fn is_core_path(process: &Process, address: Address) -> bool {
+    process
+        .read::<Path>(address)
+        .is_ok_and(|path| is_absolute_path(&path) && path.ends_with(b"_libretro.dll"))
+}
+
+fn is_absolute_path(string: &[u8]) -> bool {
+    match string {
+        [b'/', ..] | [b'\\', b'\\', ..] => true,
+        [drive, b':', b'\\' | b'/', ..] => drive.is_ascii_alphabetic(),
+        _ => false,
+    }
+}
+
+fn has_extension(string: &[u8], extensions: &[&str]) -> bool {
+    let Some(dot) = string.iter().rposition(|&b| b == b'.') else {
+        return false;
+    };
+    let extension = &string[dot + 1..];
+    extensions
+        .iter()
+        .any(|ext| ext.as_bytes().eq_ignore_ascii_case(extension))
+}
@@ -4,12 +4,16 @@ use core::{
     cell::Cell,
     future::Future,
     mem::size_of,
-    ops::Sub,
     pin::Pin,
     task::{Context, Poll},
 };
 
-use crate::{future::retry, Address, Error, Process};
+use crate::{
+    emulator::{memory, retroarch::ContentPath},
+    future::retry,
+    string::ArrayCString,
+    Address, Error, Process,
+};
 use bytemuck::CheckedBitPattern;
 
 mod blastem;
@@ -25,6 +29,8 @@ pub struct Emulator {
     state: Cell<State>,
     /// The memory address of the emulated RAM
     ram_base: Cell<Option<Address>>,
+    /// The location of the path of the loaded content, if running in Retroarch
+    content_path: ContentPath,
 }
 
 impl Emulator {
@@ -46,6 +52,7 @@ impl Emulator {
             process,
             state: Cell::new(state),
             ram_base: Cell::new(None),
+            content_path: ContentPath::new(),
         })
     }
 
@@ -67,6 +74,22 @@ impl Emulator {
         self.process.is_open()
     }
 
+    /// Reads the path of the content (the ROM or disc image) that is currently
+    /// loaded. This allows a single auto splitter to support multiple games,
+    /// or multiple regions of the same game, by branching on the file name.
+    ///
+    /// This is only supported by Retroarch on Windows, regardless of the core
+    /// that is used. Returns `Err()` for every other emulator, while no
+    /// content is loaded or if the path can't be found.
+    pub fn content_path<const N: usize>(&self) -> Result<ArrayCString<N>, Error> {
+        match self.state.get() {
+            State::Retroarch(_) => self
+                .content_path
+                .read(&self.process, &["sms", "gg", "sg", "bin", "zip", "7z"]),
+            _ => Err(Error {}),
+        }
+    }
+
     /// Executes a future until the emulator process closes.
     pub const fn until_closes<F>(&self, future: F) -> UntilEmulatorCloses<'_, F> {
         UntilEmulatorCloses {
@@ -115,16 +138,23 @@ impl Emulator {
     ///
     /// Valid addresses for the SMS range from `0xC000` to `0xDFFF`.
     pub fn get_address(&self, offset: u32) -> Result<Address, Error> {
+        let ram_offset = Self::ram_offset(offset).ok_or(Error {})?;
+        Ok(self.ram_base.get().ok_or(Error {})? + ram_offset)
+    }
+
+    /// Converts a SEGA Master System memory address to an offset into the
+    /// RAM, which is mapped to `0xC000`.
+    const fn ram_offset(offset: u32) -> Option<u32> {
         match offset {
-            (0xC000..=0xDFFF) => Ok(self.ram_base.get().ok_or(Error {})? + offset.sub(0xC000)),
-            _ => Err(Error {}),
+            (0xC000..=0xDFFF) => Some(offset - 0xC000),
+            _ => None,
         }
     }
 
     /// Checks if a memory reading operation would exceed the memory bounds of the emulated system.
     ///
     /// Returns `true` if the read operation can be performed safely, `false` otherwise.
-    const fn check_bounds<T>(&self, offset: u32) -> bool {
+    const fn check_bounds<T>(offset: u32) -> bool {
         match offset {
             (0xC000..=0xDFFF) => offset + size_of::<T>() as u32 <= 0xE000,
             _ => false,
@@ -140,11 +170,33 @@ impl Emulator {
     ///
     /// Providing any offset outside this range will return `Err()`.
     pub fn read<T: CheckedBitPattern>(&self, offset: u32) -> Result<T, Error> {
-        match self.check_bounds::<T>(offset) {
+        match Self::check_bounds::<T>(offset) {
             true => self.process.read(self.get_address(offset)?),
             false => Err(Error {}),
         }
     }
+
+    /// Follows a path of pointers from the address given and reads a value of the type specified from
+    /// the process at the end of the pointer path.
+    ///
+    /// As the SEGA Master System uses a Z80 CPU, the pointers are 16-bit wide
+    /// and little endian.
+    pub fn read_pointer_path<T: CheckedBitPattern>(
+        &self,
+        base_address: u32,
+        path: &[u32],
+    ) -> Result<T, Error> {
+        self.read(self.deref_offsets(base_address, path)?)
+    }
+
+    /// Follows a path of pointers from the address given and returns the address at the end
+    /// of the pointer path
+    fn deref_offsets(&self, base_address: u32, path: &[u32]) -> Result<u32, Error> {
+        memory::deref_offsets(base_address, path, |address| {
+            let bytes: [u8; 2] = self.read(address)?;
+            Ok(u16::from_le_bytes(bytes) as u32)
+        })
+    }
 }
 
 /// A future that executes a future until the emulator closes.
@@ -186,3 +238,24 @@ static PROCESS_NAMES: &[(&str, State)] = &[
     ("blastem.exe", State::BlastEm(blastem::State)),
     ("mednafen.exe", State::Mednafen(mednafen::State)),
 ];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn bounds_of_16_bit_reads() {
+        assert!(Emulator::check_bounds::<u16>(0xC000));
+        assert!(Emulator::check_bounds::<u16>(0xDFFE));
+        assert!(!Emulator::check_bounds::<u16>(0xDFFF));
+        assert!(!Emulator::check_bounds::<u16>(0xBFFF));
+    }
+
+    #[test]
+    fn ram_is_mapped_to_0xc000() {
+        assert_eq!(Emulator::ram_offset(0xC000), Some(0));
+        assert_eq!(Emulator::ram_offset(0xDFFF), Some(0x1FFF));
+        assert_eq!(Emulator::ram_offset(0xE000), None);
+        assert_eq!(Emulator::ram_offset(0xBFFF), None);
+    }
+}
@@ -0,0 +1,96 @@
+//! Support for looking up the addresses of the functions and globals of a
+//! game by their names.
+//!
+//! The symbol map of a game is not part of its memory, so it needs to be
+//! embedded into the auto splitter, either as a table of names and addresses
+//! or as the text of a map file that is included with [`include_str!`].
+//!
+//! # Example
+//!
+//! ```
+//! # use asr::emulator::symbol_map::SymbolMap;
+//! // Usually this would be `include_str!("game.map")`.
+//! static SYMBOLS: SymbolMap<'static> = SymbolMap::from_map(
+//!     ".bss section layout
+//!       00000000 000004 80421A40  4 gameState   main.o
+//!       UNUSED   000004 ........ unusedState main.o",
+//! );
+//!
+//! assert_eq!(SYMBOLS.resolve("gameState"), Some(0x80421A40));
+//! assert_eq!(SYMBOLS.resolve("unusedState"), None);
+//!
+//! let table = SymbolMap::from_table(&[("gameState", 0x80421A40)]);
+//! assert_eq!(table.resolve("gameState"), Some(0x80421A40));
+//! ```
+
+/// A map from the names of the symbols of a game to their addresses in the
+/// memory of the emulated system.
+#[derive(Copy, Clone, Debug)]
+pub struct SymbolMap<'a> {
+    source: Source<'a>,
+}
+
+#[derive(Copy, Clone, Debug)]
+enum Source<'a> {
+    Table(&'a [(&'a str, u32)]),
+    Map(&'a str),
+}
+
+impl<'a> SymbolMap<'a> {
+    /// Creates a symbol map from a table of names and the addresses of the
+    /// symbols.
+    pub const fn from_table(table: &'a [(&'a str, u32)]) -> Self {
+        Self {
+            source: Source::Table(table),
+        }
+    }
+
+    /// Creates a symbol map from the text of a map file, as it is produced by
+    /// the CodeWarrior linker or exported by Dolphin. Each symbol is on a
+    /// line of its own, consisting of at least five fields separated by
+    /// whitespace: the starting address, the size, the virtual address and
+    /// the alignment, all but the last one in hexadecimal, followed by the
+    /// name of the symbol. Any further fields, such as the object file, are
+    /// ignored. The virtual address is used as the address of the symbol.
+    /// Lines that don't follow this format, such as section headers or
+    /// symbols that were stripped by the linker, are skipped.
+    ///
+    /// ```text
+    /// .text section layout
+    ///   00000000 000038 80003100  4 __start   os.a __start.c
+    /// ```
+    ///
+    /// The map is parsed whenever a symbol is looked up, so the addresses
+    /// should be resolved once and then be reused.
+    pub const fn from_map(map: &'a str) -> Self {
+        Self {
+            source: Source::Map(map),
+        }
+    }
+
+    /// Iterates over the names and addresses of all the symbols in the map.
+    pub fn symbols(&self) -> impl Iterator<Item = (&'a str, u32)> + 'a {
+        let (table, map) = match self.source {
+            Source::Table(table) => (table, ""),
+            Source::Map(map) => (&[][..], map),
+        };
+        table.iter().copied().chain(map.lines().filter_map(|line| {
+            let mut fields = line.split_whitespace();
+            let (_, _, address, _, name) = (
+                fields.next()?,
+                fields.next()?,
+                fields.next()?,
+                fields.next()?,
+                fields.next()?,
+            );
+            Some((name, u32::from_str_radix(address, 16).ok()?))
+        }))
+    }
+
+    /// Looks up the address of the symbol with the name provided.
+    pub fn resolve(&self, name: &str) -> Option<u32> {
+        self.symbols()
+            .find(|&(symbol, _)| symbol == name)
+            .map(|(_, address)| address)
+    }
+}
@@ -3,14 +3,18 @@
 use core::{
     cell::Cell,
     future::Future,
-    mem::size_of,
-    ops::Sub,
+    mem::{size_of, size_of_val},
     pin::Pin,
     task::{Context, Poll},
 };
 
-use crate::{future::retry, Address, Endian, Error, FromEndian, Process};
-use bytemuck::CheckedBitPattern;
+use crate::{
+    emulator::{memory, retroarch::ContentPath, symbol_map::SymbolMap},
+    future::retry,
+    string::ArrayCString,
+    Address, Endian, Error, FromEndian, Process,
+};
+use bytemuck::{AnyBitPattern, CheckedBitPattern};
 
 mod dolphin;
 mod retroarch;
@@ -26,6 +30,10 @@ pub struct Emulator {
     ram_base: Cell<Option<[Address; 2]>>, // [MEM1, MEM2]
     /// The endianness used by the emulator process
     endian: Cell<Endian>,
+    /// The location of the path of the loaded content, if running in Retroarch
+    content_path: ContentPath,
+    /// The symbol map of the game, if one was provided
+    symbol_map: Cell<Option<SymbolMap<'static>>>,
 }
 
 impl Emulator {
@@ -46,6 +54,8 @@ impl Emulator {
             state: Cell::new(state),
             ram_base: Cell::new(None),      // [MEM1, MEM2]
             endian: Cell::new(Endian::Big), // Endianness is usually Big in Wii emulators
+            content_path: ContentPath::new(),
+            symbol_map: Cell::new(None),
         })
     }
 
@@ -65,6 +75,41 @@ impl Emulator {
         self.process.is_open()
     }
 
+    /// Reads the path of the content (the ROM or disc image) that is currently
+    /// loaded. This allows a single auto splitter to support multiple games,
+    /// or multiple regions of the same game, by branching on the file name.
+    ///
+    /// This is only supported by Retroarch on Windows, regardless of the core
+    /// that is used. Returns `Err()` for every other emulator, while no
+    /// content is loaded or if the path can't be found.
+    pub fn content_path<const N: usize>(&self) -> Result<ArrayCString<N>, Error> {
+        match self.state.get() {
+            State::Retroarch(_) => self.content_path.read(
+                &self.process,
+                &[
+                    "iso", "wbfs", "rvz", "gcz", "ciso", "wia", "wad", "dol", "elf",
+                ],
+            ),
+            _ => Err(Error {}),
+        }
+    }
+
+    /// Sets the [symbol map](SymbolMap) of the game, which allows looking up
+    /// the addresses of its functions and globals by name with
+    /// [`resolve_symbol`](Self::resolve_symbol). As the map is not part of
+    /// the game's memory, it needs to be embedded into the auto splitter.
+    pub fn set_symbol_map(&self, symbol_map: SymbolMap<'static>) {
+        self.symbol_map.set(Some(symbol_map));
+    }
+
+    /// Looks up the address of the symbol with the name provided in the
+    /// [symbol map](SymbolMap) of the game. The address can be passed to
+    /// [`read`](Self::read) directly. Returns [`None`] if no symbol map was
+    /// set or the symbol is not part of it.
+    pub fn resolve_symbol(&self, name: &str) -> Option<u32> {
+        self.symbol_map.get()?.resolve(name)
+    }
+
     /// Executes a future until the emulator process closes.
     pub const fn until_closes<F>(&self, future: F) -> UntilEmulatorCloses<'_, F> {
         UntilEmulatorCloses {
@@ -114,19 +159,26 @@ impl Emulator {
     /// - Valid addresses for `MEM1` range from `0x80000000` to `0x817FFFFF`
     /// - Valid addresses for `MEM2` range from `0x90000000` to `0x93FFFFFF`
     pub fn get_address(&self, offset: u32) -> Result<Address, Error> {
+        let (region, ram_offset) = Self::ram_offset(offset).ok_or(Error {})?;
+        Ok(self.ram_base.get().ok_or(Error {})?[region] + ram_offset)
+    }
+
+    /// Converts a Wii memory address to the index of the memory region it
+    /// belongs to (`0` for `MEM1` and `1` for `MEM2`) and the offset into it.
+    const fn ram_offset(offset: u32) -> Option<(usize, u32)> {
         match offset {
-            (0x80000000..=0x817FFFFF) => {
-                let [mem1, _] = self.ram_base.get().ok_or(Error {})?;
-                Ok(mem1 + offset.sub(0x80000000))
-            }
-            (0x90000000..=0x93FFFFFF) => {
-                let [_, mem2] = self.ram_base.get().ok_or(Error {})?;
-                Ok(mem2 + offset.sub(0x90000000))
-            }
-            _ => Err(Error {}),
+            (0x80000000..=0x817FFFFF) => Some((0, offset - 0x80000000)),
+            (0x90000000..=0x93FFFFFF) => Some((1, offset - 0x90000000)),
+            _ => None,
         }
     }
 
+    /// Converts the bytes of a pointer, as stored by the emulator, into an
+    /// address of the emulated system.
+    fn decode_pointer(bytes: [u8; 4], endian: Endian) -> u32 {
+        u32::from_ne_bytes(bytes).from_endian(endian)
+    }
+
     /// Checks if a memory reading operation would exceed the memory bounds of the emulated system.
     ///
     /// Returns `true` if the read operation can be performed safely, `false` otherwise.
@@ -138,6 +190,18 @@ impl Emulator {
         }
     }
 
+    /// Checks if a memory reading operation of the given amount of bytes would
+    /// exceed the memory bounds of the emulated system.
+    ///
+    /// Returns `true` if the read operation can be performed safely, `false` otherwise.
+    const fn check_bounds_len(offset: u32, len: usize) -> bool {
+        match offset {
+            (0x80000000..=0x817FFFFF) => offset as u64 + len as u64 <= 0x81800000,
+            (0x90000000..=0x93FFFFFF) => offset as u64 + len as u64 <= 0x94000000,
+            _ => false,
+        }
+    }
+
     /// Reads raw data from the emulated RAM ignoring all endianness settings.
     /// The same call, performed on two different emulators, might return different
     /// results due to the endianness used by the emulator.
@@ -177,8 +241,56 @@ impl Emulator {
             .from_endian(self.endian.get()))
     }
 
+    /// Reads a contiguous block of values from the emulated RAM into the slice
+    /// provided, converting each of them from the endianness used by the
+    /// emulator.
+    ///
+    /// The address provided is meant to be the mapped address used on the original,
+    /// big-endian system.
+    ///
+    /// The provided memory address has to match a mapped memory address on the original Wii:
+    /// - Valid addresses for `MEM1` range from `0x80000000` to `0x817FFFFF`
+    /// - Valid addresses for `MEM2` range from `0x90000000` to `0x93FFFFFF`
+    ///
+    /// The whole block needs to be inside of the same memory region.
+    ///
+    /// Any other invalid value will make this method immediately return `Err()`.
+    pub fn read_into_slice<T: AnyBitPattern + FromEndian>(
+        &self,
+        address: u32,
+        slice: &mut [T],
+    ) -> Result<(), Error> {
+        if !Self::check_bounds_len(address, size_of_val(slice)) {
+            return Err(Error {});
+        }
+
+        self.process
+            .read_into_slice(self.get_address(address)?, slice)?;
+
+        let endian = self.endian.get();
+        for value in slice.iter_mut() {
+            *value = value.from_endian(endian);
+        }
+
+        Ok(())
+    }
+
+    /// Reads the 6-character game ID of the currently loaded game (e.g.
+    /// `RMGE01`) from the disc header, which gets copied to the start of MEM1
+    /// at `0x80000000`. This can be used to make sure the auto splitter is
+    /// running against the expected game and region.
+    ///
+    /// Returns `Err()` if the emulated RAM has not been found yet.
+    pub fn game_id(&self) -> Result<ArrayCString<6>, Error> {
+        self.read_ignoring_endianness(0x80000000)
+    }
+
     /// Follows a path of pointers from the address given and reads a value of the type specified from
     /// the process at the end of the pointer path.
+    ///
+    /// The pointers stored in memory are interpreted as 32-bit big endian
+    /// emulated addresses and translated the same way as the ones passed to
+    /// [`read`](Self::read).
     pub fn read_pointer_path<T: CheckedBitPattern + FromEndian>(
         &self,
         base_address: u32,
@@ -190,12 +302,10 @@ impl Emulator {
     /// Follows a path of pointers from the address given and returns the address at the end
     /// of the pointer path
     fn deref_offsets(&self, base_address: u32, path: &[u32]) -> Result<u32, Error> {
-        let mut address = base_address;
-        let (&last, path) = path.split_last().ok_or(Error {})?;
-        for &offset in path {
-            address = self.read::<u32>(address + offset)?;
-        }
-        Ok(address + last)
+        memory::deref_offsets(base_address, path, |address| {
+            let bytes = self.read_ignoring_endianness(address)?;
+            Ok(Self::decode_pointer(bytes, self.endian.get()))
+        })
     }
 }
 
@@ -234,3 +344,50 @@ static PROCESS_NAMES: [(&str, State); 2] = [
     ("Dolphin.exe", State::Dolphin(dolphin::State)),
     ("retroarch.exe", State::Retroarch(retroarch::State::new())),
 ];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn slice_bounds_of_mem1() {
+        assert!(Emulator::check_bounds_len(0x80000000, 0x1800000));
+        assert!(Emulator::check_bounds_len(0x817FFFF0, 16));
+        assert!(!Emulator::check_bounds_len(0x817FFFF0, 17));
+        assert!(Emulator::check_bounds_len(0x817FFFFF, 1));
+        assert!(!Emulator::check_bounds_len(0x817FFFFF, 2));
+        assert!(!Emulator::check_bounds_len(0x81800000, 1));
+        assert!(!Emulator::check_bounds_len(0x7FFFFFFF, 1));
+    }
+
+    #[test]
+    fn slice_bounds_of_mem2() {
+        assert!(Emulator::check_bounds_len(0x90000000, 0x4000000));
+        assert!(Emulator::check_bounds_len(0x93FFFFFF, 1));
+        assert!(!Emulator::check_bounds_len(0x93FFFFFF, 2));
+        assert!(!Emulator::check_bounds_len(0x94000000, 1));
+        assert!(!Emulator::check_bounds_len(0x8FFFFFFF, 1));
+    }
+
+    #[test]
+    fn big_endian_pointers_in_mem1_and_mem2() {
+        let bytes = 0x8012_3456u32.to_be_bytes();
+        let pointer = Emulator::decode_pointer(bytes, Endian::Big);
+        assert_eq!(pointer, 0x8012_3456);
+        assert_eq!(Emulator::ram_offset(pointer), Some((0, 0x12_3456)));
+
+        // Some emulators byte swap the memory of the emulated system.
+        let bytes = 0x9012_3456u32.to_le_bytes();
+        let pointer = Emulator::decode_pointer(bytes, Endian::Little);
+        assert_eq!(pointer, 0x9012_3456);
+        assert_eq!(Emulator::ram_offset(pointer), Some((1, 0x12_3456)));
+
+        assert_eq!(Emulator::ram_offset(0x817F_FFFF), Some((0, 0x17F_FFFF)));
+        assert_eq!(Emulator::ram_offset(0x8180_0000), None);
+        assert_eq!(Emulator::ram_offset(0x9000_0000), Some((1, 0)));
+        assert_eq!(Emulator::ram_offset(0x93FF_FFFF), Some((1, 0x3FF_FFFF)));
+        assert_eq!(Emulator::ram_offset(0x9400_0000), None);
+        // A null pointer doesn't point into either of the memory regions.
+        assert_eq!(Emulator::ram_offset(0), None);
+    }
+}
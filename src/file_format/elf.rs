@@ -46,6 +46,12 @@ pub struct Info {
 }
 
 impl Info {
+    /// Reads the ELF file information of a module from the given process.
+    pub fn read(process: &Process, module_address: impl Into<Address>) -> Option<Self> {
+        let header = process.read::<Header>(module_address.into()).ok()?;
+        Self::parse(bytemuck::bytes_of(&header))
+    }
+
     /// Parses the ELF file information from the given data.
     pub fn parse(data: &[u8]) -> Option<Self> {
         let header: &Header = bytemuck::from_bytes(data.get(..mem::size_of::<Header>())?);
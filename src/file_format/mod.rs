@@ -1,4 +1,177 @@
 //! Support for parsing various file formats.
 
+use crate::{Address, Endian, FromEndian, PointerSize, Process};
+
 pub mod elf;
 pub mod pe;
+
+/// The binary format of a module loaded into a process.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum BinaryFormat {
+    /// A Windows Portable Executable (`exe` or `dll`).
+    PE,
+    /// An Executable and Linkable Format module, as used on Linux.
+    ELF,
+    /// A Mach-O module, as used on macOS. This includes fat binaries that
+    /// contain modules for multiple architectures.
+    MachO,
+    /// A WebAssembly module, such as the ones produced by Emscripten for
+    /// Unity's WebGL builds. The module itself only contains the code. The
+    /// game's data lives in a separate linear memory that the host allocates,
+    /// and pointers stored in it are 32-bit offsets from the start of that
+    /// memory rather than addresses in the host process. Finding the linear
+    /// memory depends on the host, such as the browser, so none of the game
+    /// engine modules support attaching to such games.
+    Wasm,
+}
+
+/// Detects the binary format of the module at the given address by looking at
+/// its magic bytes. Returns [`None`] if the format is not recognized.
+pub fn detect(process: &Process, module_address: impl Into<Address>) -> Option<BinaryFormat> {
+    let module_address = module_address.into();
+
+    if pe::MachineType::read(process, module_address).is_some() {
+        return Some(BinaryFormat::PE);
+    }
+
+    if elf::is_64_bit(process, module_address).is_some() {
+        return Some(BinaryFormat::ELF);
+    }
+
+    match process.read::<[u8; 4]>(module_address).ok()? {
+        // The thin headers are stored in the native endianness of the
+        // architecture, while the fat header is always big endian.
+        [0xFE, 0xED, 0xFA, 0xCE | 0xCF]
+        | [0xCE | 0xCF, 0xFA, 0xED, 0xFE]
+        | [0xCA, 0xFE, 0xBA, 0xBE] => Some(BinaryFormat::MachO),
+        [0x00, b'a', b's', b'm'] => Some(BinaryFormat::Wasm),
+        _ => None,
+    }
+}
+
+/// The architecture (instruction set) a module is compiled for.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum Architecture {
+    /// 32-bit x86.
+    X86,
+    /// 64-bit x86, also known as x64 or AMD64.
+    X86_64,
+    /// 32-bit ARM.
+    Arm,
+    /// 64-bit ARM, also known as ARM64.
+    AArch64,
+    /// 32-bit PowerPC.
+    PowerPC,
+    /// 64-bit PowerPC.
+    PowerPC64,
+    /// MIPS.
+    Mips,
+    /// WebAssembly.
+    Wasm,
+    /// An architecture that is not known to this crate.
+    Unknown,
+}
+
+/// Information about a module that is useful for figuring out how to read
+/// its memory, regardless of its binary format.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub struct BinaryInfo {
+    /// The binary format of the module.
+    pub format: BinaryFormat,
+    /// The size of the pointers used by the module. This is [`None`] if the
+    /// architecture is not known to this crate.
+    pub pointer_size: Option<PointerSize>,
+    /// The byte order of the values stored by the module.
+    pub endian: Endian,
+    /// The architecture the module is compiled for.
+    pub arch: Architecture,
+}
+
+/// Reads the information about the module at the given address from its
+/// headers. PE, ELF, Mach-O and WebAssembly modules are supported. Returns
+/// [`None`] if the format is not recognized.
+pub fn read_binary_info(
+    process: &Process,
+    module_address: impl Into<Address>,
+) -> Option<BinaryInfo> {
+    let module_address = module_address.into();
+
+    if let Some(machine_type) = pe::MachineType::read(process, module_address) {
+        return Some(BinaryInfo {
+            format: BinaryFormat::PE,
+            pointer_size: machine_type.pointer_size(),
+            endian: Endian::Little,
+            arch: match machine_type {
+                pe::MachineType::I386 => Architecture::X86,
+                pe::MachineType::AMD64 => Architecture::X86_64,
+                pe::MachineType::ARM | pe::MachineType::ARMNT => Architecture::Arm,
+                pe::MachineType::ARM64 => Architecture::AArch64,
+                pe::MachineType::POWERPC | pe::MachineType::POWERPCFP => Architecture::PowerPC,
+                pe::MachineType::R4000
+                | pe::MachineType::MIPS16
+                | pe::MachineType::MIPSFPU
+                | pe::MachineType::MIPSFPU16 => Architecture::Mips,
+                _ => Architecture::Unknown,
+            },
+        });
+    }
+
+    if let Some(info) = elf::Info::read(process, module_address) {
+        return Some(BinaryInfo {
+            format: BinaryFormat::ELF,
+            pointer_size: info.bitness.pointer_size(),
+            endian: info.endian,
+            arch: match info.arch {
+                elf::Architecture::EM_386 => Architecture::X86,
+                elf::Architecture::EM_X86_64 => Architecture::X86_64,
+                elf::Architecture::EM_ARM => Architecture::Arm,
+                elf::Architecture::EM_AARCH64 => Architecture::AArch64,
+                elf::Architecture::EM_PPC => Architecture::PowerPC,
+                elf::Architecture::EM_PPC64 => Architecture::PowerPC64,
+                elf::Architecture::EM_MIPS => Architecture::Mips,
+                _ => Architecture::Unknown,
+            },
+        });
+    }
+
+    // WebAssembly modules don't declare their pointer size in the header. It
+    // only shows up in the memory section, so we assume the common 32-bit
+    // linear memory.
+    if process.read::<[u8; 8]>(module_address).ok()? == *b"\0asm\x01\0\0\0" {
+        return Some(BinaryInfo {
+            format: BinaryFormat::Wasm,
+            pointer_size: Some(PointerSize::Bit32),
+            endian: Endian::Little,
+            arch: Architecture::Wasm,
+        });
+    }
+
+    // Only thin Mach-O headers are considered, as a module loaded into a
+    // process is always a single architecture slice.
+    let [magic, cpu_type] = process.read::<[u32; 2]>(module_address).ok()?;
+    let (endian, pointer_size) = match magic.to_ne_bytes() {
+        [0xCE, 0xFA, 0xED, 0xFE] => (Endian::Little, PointerSize::Bit32),
+        [0xCF, 0xFA, 0xED, 0xFE] => (Endian::Little, PointerSize::Bit64),
+        [0xFE, 0xED, 0xFA, 0xCE] => (Endian::Big, PointerSize::Bit32),
+        [0xFE, 0xED, 0xFA, 0xCF] => (Endian::Big, PointerSize::Bit64),
+        _ => return None,
+    };
+
+    Some(BinaryInfo {
+        format: BinaryFormat::MachO,
+        pointer_size: Some(pointer_size),
+        endian,
+        arch: match cpu_type.from_endian(endian) {
+            7 => Architecture::X86,
+            0x0100_0007 => Architecture::X86_64,
+            12 => Architecture::Arm,
+            0x0100_000C => Architecture::AArch64,
+            18 => Architecture::PowerPC,
+            0x0100_0012 => Architecture::PowerPC64,
+            _ => Architecture::Unknown,
+        },
+    })
+}
@@ -95,6 +95,21 @@ struct OptionalCOFFHeader {
     // There's more but those vary depending on whether it's PE or PE+.
 }
 
+#[derive(Debug, Copy, Clone, Zeroable, Pod)]
+#[repr(C)]
+struct SectionHeader {
+    name: [u8; 8],
+    virtual_size: u32,
+    virtual_address: u32,
+    size_of_raw_data: u32,
+    pointer_to_raw_data: u32,
+    pointer_to_relocations: u32,
+    pointer_to_linenumbers: u32,
+    number_of_relocations: u16,
+    number_of_linenumbers: u16,
+    characteristics: u32,
+}
+
 #[derive(Debug, Copy, Clone, Zeroable, Pod, Default)]
 #[repr(C)]
 struct ExportedSymbolsTableDef {
@@ -267,6 +282,51 @@ pub fn read_size_of_image(process: &Process, module_address: impl Into<Address>)
     Some(optional_header.size_of_image)
 }
 
+/// Checks whether the module at the given address is a dynamic-link library
+/// (`dll`) rather than an executable (`exe`). Returns [`None`] if the module
+/// is not a Portable Executable.
+pub fn is_dll(process: &Process, module_address: impl Into<Address>) -> Option<bool> {
+    const IMAGE_FILE_DLL: u16 = 0x2000;
+
+    let (coff_header, _) = read_coff_header(process, module_address.into())?;
+
+    Some(coff_header.characteristics & IMAGE_FILE_DLL != 0)
+}
+
+/// Looks up the section with the name provided, such as `.text` or `.rdata`,
+/// in a module (`exe` or `dll`) of the given process. Returns the address and
+/// the size of the section once it is loaded. This allows limiting signature
+/// scans to the part of the module that contains what is looked for.
+pub fn find_section(
+    process: &Process,
+    module_address: impl Into<Address>,
+    name: &[u8],
+) -> Option<(Address, u64)> {
+    let module_address: Address = module_address.into();
+    let (coff_header, coff_header_address) = read_coff_header(process, module_address)?;
+
+    let section_table = coff_header_address
+        + mem::size_of::<COFFHeader>() as u64
+        + coff_header.size_of_optional_header.from_le() as u64;
+
+    (0..coff_header.number_of_sections.from_le() as u64)
+        .filter_map(|i| {
+            process
+                .read::<SectionHeader>(section_table + i * mem::size_of::<SectionHeader>() as u64)
+                .ok()
+        })
+        .find(|section| {
+            let len = section.name.iter().position(|&b| b == 0).unwrap_or(8);
+            section.name[..len] == *name
+        })
+        .map(|section| {
+            (
+                module_address + section.virtual_address.from_le(),
+                section.virtual_size.from_le() as u64,
+            )
+        })
+}
+
 fn read_coff_header(process: &Process, module_address: Address) -> Option<(COFFHeader, Address)> {
     let dos_header = process.read::<DOSHeader>(module_address).ok()?;
 
@@ -356,3 +416,134 @@ pub fn symbols(
         })
     })
 }
+
+#[derive(Debug, Copy, Clone, Zeroable, Pod)]
+#[repr(C)]
+struct ImportDescriptor {
+    original_first_thunk: u32,
+    time_date_stamp: u32,
+    forwarder_chain: u32,
+    name: u32,
+    first_thunk: u32,
+}
+
+/// A function imported by a module from another module.
+pub struct Import {
+    /// The address of the entry in the import address table of the module.
+    /// Once the module is loaded, the address of the imported function is
+    /// stored there.
+    pub address: Address,
+    /// The address storing the name of the module the function is imported
+    /// from.
+    dll_name_addr: Address,
+    /// Either the address storing the name of the function or its ordinal.
+    name_or_ordinal: Result<Address, u16>,
+}
+
+impl Import {
+    /// Tries to retrieve the name of the module (`dll`) that the function is
+    /// imported from.
+    pub fn get_dll_name<const CAP: usize>(
+        &self,
+        process: &Process,
+    ) -> Result<ArrayCString<CAP>, Error> {
+        process.read(self.dll_name_addr)
+    }
+
+    /// Tries to retrieve the name of the imported function. Functions that
+    /// are imported by their [ordinal](Self::ordinal) only don't have a name,
+    /// so an error is returned for them.
+    pub fn get_name<const CAP: usize>(
+        &self,
+        process: &Process,
+    ) -> Result<ArrayCString<CAP>, Error> {
+        // The name is preceded by a 16-bit hint.
+        process.read(self.name_or_ordinal.map_err(|_| Error {})? + 2)
+    }
+
+    /// Returns the ordinal of the imported function if it is imported by its
+    /// ordinal rather than its name.
+    pub const fn ordinal(&self) -> Option<u16> {
+        match self.name_or_ordinal {
+            Ok(_) => None,
+            Err(ordinal) => Some(ordinal),
+        }
+    }
+}
+
+/// Iterates over the functions that a given module imports from other
+/// modules. This can be used to detect which engine or runtime a game is
+/// linked against, without having to scan for signatures. Returns an empty
+/// iterator if the module doesn't import anything. Modules that are bound
+/// without an import lookup table are skipped, as only the addresses of the
+/// functions remain once they are loaded.
+pub fn imports(
+    process: &Process,
+    module_address: impl Into<Address>,
+) -> impl Iterator<Item = Import> + '_ {
+    let address: Address = module_address.into();
+
+    let import_directory = read_coff_header(process, address).and_then(|(_, coff_header)| {
+        let optional_header = coff_header + mem::size_of::<COFFHeader>() as u64;
+        let is_64_bit = process.read::<u16>(optional_header).ok()?.from_le() == 0x20B;
+        // The import table is the second data directory.
+        let rva = process
+            .read::<u32>(optional_header + if is_64_bit { 0x78 } else { 0x68 })
+            .ok()?
+            .from_le();
+        Some((rva, is_64_bit)).filter(|&(rva, _)| rva != 0)
+    });
+
+    import_directory
+        .into_iter()
+        .flat_map(move |(rva, is_64_bit)| {
+            (0u64..)
+                .map_while(move |i| {
+                    process
+                        .read::<ImportDescriptor>(
+                            address + rva + i * mem::size_of::<ImportDescriptor>() as u64,
+                        )
+                        .ok()
+                        .map(|descriptor| ImportDescriptor {
+                            original_first_thunk: descriptor.original_first_thunk.from_le(),
+                            time_date_stamp: descriptor.time_date_stamp.from_le(),
+                            forwarder_chain: descriptor.forwarder_chain.from_le(),
+                            name: descriptor.name.from_le(),
+                            first_thunk: descriptor.first_thunk.from_le(),
+                        })
+                        .filter(|descriptor| descriptor.name != 0)
+                })
+                .filter(|descriptor| descriptor.original_first_thunk != 0)
+                .flat_map(move |descriptor| {
+                    let thunk_size = if is_64_bit { 8 } else { 4 };
+                    (0u64..).map_while(move |i| {
+                        let offset = i * thunk_size;
+                        let (thunk, is_ordinal) = if is_64_bit {
+                            let thunk = process
+                                .read::<u64>(address + descriptor.original_first_thunk + offset)
+                                .ok()?
+                                .from_le();
+                            (thunk, thunk >> 63 != 0)
+                        } else {
+                            let thunk = process
+                                .read::<u32>(address + descriptor.original_first_thunk + offset)
+                                .ok()?
+                                .from_le();
+                            (thunk as u64, thunk >> 31 != 0)
+                        };
+                        if thunk == 0 {
+                            return None;
+                        }
+                        Some(Import {
+                            address: address + descriptor.first_thunk + offset,
+                            dll_name_addr: address + descriptor.name,
+                            name_or_ordinal: if is_ordinal {
+                                Err(thunk as u16)
+                            } else {
+                                Ok(address + (thunk as u32))
+                            },
+                        })
+                    })
+                })
+        })
+}
@@ -0,0 +1,98 @@
+use core::{
+    future::Future,
+    pin::Pin,
+    ptr,
+    task::{Context, RawWaker, RawWakerVTable, Waker},
+};
+
+/// A minimal executor that drives a future by polling it once on every call to
+/// [`update`](Self::update). This is the same executor that the
+/// [`async_main`](crate::async_main) macro uses, but without defining the
+/// `update` function that the runtime calls. This allows embedding the
+/// executor into your own `update` function, for example to run some
+/// synchronous code on every tick alongside the future.
+///
+/// The runtime only calls the `update` function exported by the auto splitter,
+/// so the executor needs to be stored somewhere that outlives a single tick,
+/// such as a `static`. As auto splitters are single-threaded WebAssembly
+/// modules, a [`RacyCell`](crate::sync::RacyCell) can be used for this. The
+/// future never gets woken up by the runtime, it is simply polled again on
+/// the next tick.
+///
+/// # Example
+///
+/// ```no_run
+/// # use core::{future::Future, pin::Pin};
+/// # use asr::{future::{next_tick, Executor}, sync::RacyCell, timer::{self, TimerState}};
+/// static EXECUTOR: RacyCell<Option<Executor<Pin<Box<dyn Future<Output = ()>>>>>> =
+///     RacyCell::new(None);
+///
+/// async fn main_loop() {
+///     loop {
+///         // Attach to the game and split based on its memory here.
+///         next_tick().await;
+///     }
+/// }
+///
+/// #[no_mangle]
+/// pub extern "C" fn update() {
+///     // SAFETY: The runtime never calls `update` concurrently.
+///     let executor = unsafe { &mut *EXECUTOR.get_mut() }
+///         .get_or_insert_with(|| Executor::new(Box::pin(main_loop())));
+///
+///     // This runs on every tick, no matter what the future is waiting for.
+///     if timer::state() == TimerState::NotRunning {
+///         timer::set_variable("Status", "Waiting for the run to start");
+///     }
+///
+///     Pin::new(executor).update();
+/// }
+/// ```
+pub struct Executor<F> {
+    future: F,
+    finished: bool,
+}
+
+impl<F: Future<Output = ()>> Executor<F> {
+    /// Creates a new executor that drives the future provided.
+    pub const fn new(future: F) -> Self {
+        Self {
+            future,
+            finished: false,
+        }
+    }
+
+    /// Returns [`true`] if the future has finished executing.
+    pub const fn is_finished(&self) -> bool {
+        self.finished
+    }
+
+    /// Polls the future once, unless it has already finished. This is meant to
+    /// be called once per tick. Returns [`true`] if the future has finished
+    /// executing.
+    pub fn update(self: Pin<&mut Self>) -> bool {
+        // SAFETY: We are simply projecting the Pin to the inner future. The
+        // future is never moved out of the executor.
+        let this = unsafe { self.get_unchecked_mut() };
+        if !this.finished {
+            let waker = noop_waker();
+            let mut cx = Context::from_waker(&waker);
+            // SAFETY: The executor is pinned, so the future is pinned as well.
+            let future = unsafe { Pin::new_unchecked(&mut this.future) };
+            this.finished = future.poll(&mut cx).is_ready();
+        }
+        this.finished
+    }
+}
+
+fn noop_waker() -> Waker {
+    static VTABLE: RawWakerVTable = RawWakerVTable::new(
+        |_| RawWaker::new(ptr::null(), &VTABLE),
+        |_| {},
+        |_| {},
+        |_| {},
+    );
+    // SAFETY: The vtable doesn't do anything, so the contract of the waker is
+    // trivially upheld.
+    unsafe { Waker::from_raw(RawWaker::new(ptr::null(), &VTABLE)) }
+}
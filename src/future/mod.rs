@@ -113,6 +113,9 @@ use core::{
 use crate::signature::Signature;
 use crate::{Address, Process};
 
+mod executor;
+pub use self::executor::*;
+
 #[cfg(target_os = "wasi")]
 mod time;
 #[cfg(target_os = "wasi")]
@@ -141,6 +144,24 @@ impl Future for NextTick {
     }
 }
 
+/// A future that yields back to the runtime a certain amount of times and
+/// continues on the tick after that.
+#[must_use = "You need to await this future."]
+pub struct NextTicks(u32);
+
+impl Future for NextTicks {
+    type Output = ();
+
+    fn poll(mut self: Pin<&mut Self>, _: &mut Context<'_>) -> Poll<Self::Output> {
+        if self.0 != 0 {
+            self.0 -= 1;
+            Poll::Pending
+        } else {
+            Poll::Ready(())
+        }
+    }
+}
+
 /// A future that retries the given function until it returns [`Some`], yielding
 /// back to the runtime between each call.
 #[must_use = "You need to await this future."]
@@ -159,6 +180,174 @@ impl<O: IntoOption, F: FnMut() -> O + Unpin> Future for Retry<F> {
     }
 }
 
+/// A future that retries the given function until it returns [`Some`], passing
+/// it the number of the attempt and yielding back to the runtime between each
+/// call.
+#[must_use = "You need to await this future."]
+pub struct RetryWith<F> {
+    f: F,
+    attempt: u32,
+}
+
+impl<O: IntoOption, F: FnMut(u32) -> O + Unpin> Future for RetryWith<F> {
+    type Output = O::T;
+
+    fn poll(mut self: Pin<&mut Self>, _: &mut Context<'_>) -> Poll<Self::Output> {
+        let this = &mut *self;
+        let attempt = this.attempt;
+        this.attempt = attempt.saturating_add(1);
+        match (this.f)(attempt).into_option() {
+            Some(t) => Poll::Ready(t),
+            None => Poll::Pending,
+        }
+    }
+}
+
+/// A future that retries all the given functions until one of them returns
+/// [`Some`], yielding back to the runtime between each try.
+#[must_use = "You need to await this future."]
+pub struct FirstOf<'a, 'f, T> {
+    fs: &'a mut [&'f mut dyn FnMut() -> Option<T>],
+}
+
+impl<T> Future for FirstOf<'_, '_, T> {
+    type Output = (usize, T);
+
+    fn poll(mut self: Pin<&mut Self>, _: &mut Context<'_>) -> Poll<Self::Output> {
+        match self
+            .fs
+            .iter_mut()
+            .enumerate()
+            .find_map(|(index, f)| Some((index, f()?)))
+        {
+            Some(found) => Poll::Ready(found),
+            None => Poll::Pending,
+        }
+    }
+}
+
+/// A future that calls the given function on every tick until it returned the
+/// same value for a certain amount of consecutive ticks.
+#[must_use = "You need to await this future."]
+pub struct WaitStable<T, F> {
+    f: F,
+    ticks: u32,
+    last: Option<T>,
+    count: u32,
+}
+
+impl<O: IntoOption, F: FnMut() -> O + Unpin> Future for WaitStable<O::T, F>
+where
+    O::T: PartialEq + Unpin,
+{
+    type Output = O::T;
+
+    fn poll(mut self: Pin<&mut Self>, _: &mut Context<'_>) -> Poll<Self::Output> {
+        let this = &mut *self;
+        match (this.f)().into_option() {
+            Some(value) => {
+                if this.last.as_ref() == Some(&value) {
+                    this.count += 1;
+                } else {
+                    this.last = Some(value);
+                    this.count = 1;
+                }
+            }
+            None => {
+                this.last = None;
+                this.count = 0;
+            }
+        }
+        if this.count >= this.ticks {
+            if let Some(value) = this.last.take() {
+                return Poll::Ready(value);
+            }
+        }
+        Poll::Pending
+    }
+}
+
+/// Calls a function once per tick, allowing the values it returns to be
+/// awaited. This is created by [`each_tick`].
+#[must_use = "You need to await the values of the ticker."]
+pub struct Ticker<F> {
+    f: F,
+    started: bool,
+}
+
+impl<T, F: FnMut() -> T + Unpin> Ticker<F> {
+    /// Returns a future that resolves to the next value. The first value is
+    /// returned without waiting, while every further one is returned on the
+    /// tick after the previous one.
+    pub const fn tick(&mut self) -> TickerNext<'_, F> {
+        let wait = self.started;
+        self.started = true;
+        TickerNext { ticker: self, wait }
+    }
+
+    /// Returns a future that calls the function on every tick until the
+    /// value it returns matches the predicate. That value is then returned.
+    /// The function is called on the current tick first, unless it already
+    /// got called on it by a previous future of this ticker.
+    pub const fn until<P: FnMut(&T) -> bool + Unpin>(
+        &mut self,
+        predicate: P,
+    ) -> TickerUntil<'_, F, P> {
+        let wait = self.started;
+        self.started = true;
+        TickerUntil {
+            ticker: self,
+            predicate,
+            wait,
+        }
+    }
+}
+
+/// A future that resolves to the next value of a [`Ticker`].
+#[must_use = "You need to await this future."]
+pub struct TickerNext<'a, F> {
+    ticker: &'a mut Ticker<F>,
+    wait: bool,
+}
+
+impl<T, F: FnMut() -> T + Unpin> Future for TickerNext<'_, F> {
+    type Output = T;
+
+    fn poll(mut self: Pin<&mut Self>, _: &mut Context<'_>) -> Poll<Self::Output> {
+        if mem::replace(&mut self.wait, false) {
+            Poll::Pending
+        } else {
+            Poll::Ready((self.ticker.f)())
+        }
+    }
+}
+
+/// A future that resolves to the first value of a [`Ticker`] that matches a
+/// predicate.
+#[must_use = "You need to await this future."]
+pub struct TickerUntil<'a, F, P> {
+    ticker: &'a mut Ticker<F>,
+    predicate: P,
+    wait: bool,
+}
+
+impl<T, F: FnMut() -> T + Unpin, P: FnMut(&T) -> bool + Unpin> Future for TickerUntil<'_, F, P> {
+    type Output = T;
+
+    fn poll(mut self: Pin<&mut Self>, _: &mut Context<'_>) -> Poll<Self::Output> {
+        let this = &mut *self;
+        if mem::replace(&mut this.wait, false) {
+            return Poll::Pending;
+        }
+        let value = (this.ticker.f)();
+        if (this.predicate)(&value) {
+            Poll::Ready(value)
+        } else {
+            Poll::Pending
+        }
+    }
+}
+
 /// Yields back to the runtime and continues on the next tick. It's important to
 /// yield back to the runtime to communicate that the auto splitter is still
 /// alive.
@@ -178,6 +367,23 @@ pub const fn next_tick() -> NextTick {
     NextTick(false)
 }
 
+/// Yields back to the runtime the amount of times specified and continues on
+/// the tick after that. This is the same as awaiting [`next_tick`] `n` times.
+/// If `n` is `0`, this resolves immediately.
+///
+/// # Example
+///
+/// ```no_run
+/// # use asr::future::next_ticks;
+/// # async fn example() {
+/// // Wait for 60 ticks after the load finished.
+/// next_ticks(60).await;
+/// # }
+/// ```
+pub const fn next_ticks(n: u32) -> NextTicks {
+    NextTicks(n)
+}
+
 /// Retries the given function until it returns [`Some`] or [`Ok`], yielding
 /// back to the runtime between each call.
 ///
@@ -209,6 +415,135 @@ pub const fn retry<O: IntoOption, F: FnMut() -> O + Unpin>(f: F) -> Retry<F> {
     Retry { f }
 }
 
+/// Retries the given function until it returns [`Some`] or [`Ok`], yielding
+/// back to the runtime between each call. This works just like [`retry`], but
+/// the function is passed the number of the attempt, starting at `0`. As the
+/// function is called once per tick, this is also the number of ticks that
+/// passed since the first attempt. This allows throttling expensive checks,
+/// while still retrying cheap ones on every tick.
+///
+/// # Example
+///
+/// ```no_run
+/// # use asr::{future::retry_with, Address, Process};
+/// # fn cheap_check(process: &Process) -> Option<Address> { None }
+/// # fn expensive_scan(process: &Process) -> Option<Address> { None }
+/// # async fn example(process: &Process) {
+/// // Only do the expensive scan on every 10th tick.
+/// let address = retry_with(|attempt| {
+///     cheap_check(process).or_else(|| {
+///         if attempt % 10 == 0 {
+///             expensive_scan(process)
+///         } else {
+///             None
+///         }
+///     })
+/// })
+/// .await;
+/// # }
+/// ```
+pub const fn retry_with<O: IntoOption, F: FnMut(u32) -> O + Unpin>(f: F) -> RetryWith<F> {
+    RetryWith { f, attempt: 0 }
+}
+
+/// Retries all the given functions until one of them returns [`Some`],
+/// yielding back to the runtime between each try. The functions are called
+/// in order, and the index of the first one that succeeded is returned along
+/// with its value. This works like [`retry`] with a [`find_map`] over
+/// multiple functions, but also tells which of them succeeded.
+///
+/// [`find_map`]: Iterator::find_map
+///
+/// # Example
+///
+/// ```no_run
+/// # use asr::{future::first_of, Process};
+/// # async fn example() {
+/// let (index, process) = first_of(&mut [
+///     &mut || Process::attach("Game.exe"),
+///     &mut || Process::attach("Game-Demo.exe"),
+/// ])
+/// .await;
+/// let is_demo = index == 1;
+/// # }
+/// ```
+///
+/// If multiple functions succeed on the same tick, the first one wins:
+///
+/// ```
+/// # use asr::future::first_of;
+/// # use core::{future::Future, pin::pin, task::{Context, Poll, Waker}};
+/// let (mut a, mut b, mut c) = (|| None, || Some("b"), || Some("c"));
+/// let mut attempts: [&mut dyn FnMut() -> Option<&'static str>; 3] = [&mut a, &mut b, &mut c];
+/// let future = pin!(first_of(&mut attempts));
+/// let mut cx = Context::from_waker(Waker::noop());
+/// assert_eq!(future.poll(&mut cx), Poll::Ready((1, "b")));
+/// ```
+pub const fn first_of<'a, 'f, T>(
+    fs: &'a mut [&'f mut dyn FnMut() -> Option<T>],
+) -> FirstOf<'a, 'f, T> {
+    FirstOf { fs }
+}
+
+/// Calls the given function on every tick until it returns the same value,
+/// wrapped in [`Some`] or [`Ok`], for the amount of consecutive ticks
+/// specified. That value is then returned. Any differing value, as well as
+/// [`None`] or [`Err`], starts the count from the beginning. This avoids
+/// reacting to values that are only briefly invalid, such as during a
+/// transition in the game.
+///
+/// # Example
+///
+/// ```no_run
+/// # use asr::{future::wait_stable, Address, Process};
+/// # async fn example(process: &Process, address: Address) {
+/// // Wait for the level to stay the same for 5 ticks.
+/// let level = wait_stable(|| process.read::<u8>(address), 5).await;
+/// # }
+/// ```
+pub const fn wait_stable<O: IntoOption, F: FnMut() -> O + Unpin>(
+    f: F,
+    ticks: u32,
+) -> WaitStable<O::T, F> {
+    WaitStable {
+        f,
+        ticks,
+        last: None,
+        count: 0,
+    }
+}
+
+/// Creates a [`Ticker`] that calls the given function once per tick, so that
+/// the values it returns can be awaited. Unlike [`retry`], which stops once
+/// the function succeeds, a ticker keeps producing values, which suits
+/// values that continuously change, such as the state of a game.
+///
+/// # Example
+///
+/// ```no_run
+/// # use asr::{future::each_tick, timer, Address, Process};
+/// # async fn example(process: &Process, address: Address) {
+/// let mut level = each_tick(|| process.read::<u8>(address).ok());
+///
+/// // Wait for the game to enter the first level.
+/// level.until(|&level| level == Some(1)).await;
+///
+/// let mut current = 1;
+/// loop {
+///     // Split whenever the game enters the next level.
+///     if let Some(level) = level.tick().await {
+///         if level > current {
+///             timer::split();
+///         }
+///         current = level;
+///     }
+/// }
+/// # }
+/// ```
+pub const fn each_tick<T, F: FnMut() -> T + Unpin>(f: F) -> Ticker<F> {
+    Ticker { f, started: false }
+}
+
 /// A trait for types that can be converted into an [`Option`].
 // TODO: Replace this with `Try` once that is stable.
 pub trait IntoOption {
@@ -239,6 +574,19 @@ impl Process {
         retry(|| Process::attach(name)).await
     }
 
+    /// Asynchronously awaits attaching to a process with any of the given
+    /// names, yielding back to the runtime between each try. The name of the
+    /// process that got attached to is returned alongside it.
+    #[cfg(any(feature = "godot", feature = "unity", feature = "unreal"))]
+    pub(crate) async fn wait_attach_any<'name>(names: &[&'name str]) -> (&'name str, Process) {
+        retry(|| {
+            names
+                .iter()
+                .find_map(|&name| Some((name, Process::attach(name)?)))
+        })
+        .await
+    }
+
     /// Executes a future until the process closes.
     pub const fn until_closes<F>(&self, future: F) -> UntilProcessCloses<'_, F> {
         UntilProcessCloses {
@@ -247,11 +595,55 @@ impl Process {
         }
     }
 
+    /// Executes a future until the process closes or the amount of ticks
+    /// specified passed, whichever happens first. This prevents logic that
+    /// waits for something that may never happen from running forever. If
+    /// the future times out, it gets dropped right away.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use asr::{future::UntilCloses, Process};
+    /// # async fn example(process: &Process) {
+    /// // Give up on waiting for the module after roughly 10 seconds.
+    /// match process
+    ///     .until_closes_or_timeout(process.wait_module_range("GameAssembly.dll"), 600)
+    ///     .await
+    /// {
+    ///     UntilCloses::Completed(range) => {}
+    ///     UntilCloses::ProcessClosed => {}
+    ///     UntilCloses::TimedOut => {}
+    /// }
+    /// # }
+    /// ```
+    pub const fn until_closes_or_timeout<F>(
+        &self,
+        future: F,
+        ticks: u32,
+    ) -> UntilProcessClosesOrTimeout<'_, F> {
+        UntilProcessClosesOrTimeout {
+            process: self,
+            timeout: TickTimeout::new(future, ticks),
+        }
+    }
+
     /// Asynchronously awaits the address and size of a module in the process,
     /// yielding back to the runtime between each try.
     pub async fn wait_module_range(&self, name: &str) -> (Address, u64) {
         retry(|| self.get_module_range(name)).await
     }
+
+    /// Asynchronously awaits the address, size and
+    /// [binary information](crate::file_format::BinaryInfo) of a module in the
+    /// process, yielding back to the runtime between each try. The headers of
+    /// the module may not be readable right after it got loaded, so this
+    /// keeps trying until they are.
+    pub async fn wait_module_info(
+        &self,
+        name: &str,
+    ) -> (Address, u64, crate::file_format::BinaryInfo) {
+        retry(|| self.get_module_info(name)).await
+    }
 }
 
 #[cfg(feature = "signature")]
@@ -293,6 +685,82 @@ impl<T, F: Future<Output = T>> Future for UntilProcessCloses<'_, F> {
     }
 }
 
+/// The outcome of [`Process::until_closes_or_timeout`].
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum UntilCloses<T> {
+    /// The future completed with the value provided.
+    Completed(T),
+    /// The process closed before the future completed.
+    ProcessClosed,
+    /// The future did not complete in time.
+    TimedOut,
+}
+
+/// A future that executes a future until the process closes or it times out.
+#[must_use = "You need to await this future."]
+pub struct UntilProcessClosesOrTimeout<'a, F> {
+    process: &'a Process,
+    timeout: TickTimeout<F>,
+}
+
+impl<T, F: Future<Output = T>> Future for UntilProcessClosesOrTimeout<'_, F> {
+    type Output = UntilCloses<T>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        if !self.process.is_open() {
+            return Poll::Ready(UntilCloses::ProcessClosed);
+        }
+        // SAFETY: We are simply projecting the Pin.
+        unsafe { Pin::new_unchecked(&mut self.get_unchecked_mut().timeout) }
+            .poll(cx)
+            .map(|value| match value {
+                Some(value) => UntilCloses::Completed(value),
+                None => UntilCloses::TimedOut,
+            })
+    }
+}
+
+/// A future that executes a future for at most the amount of ticks provided.
+/// Resolves to [`None`] if the future times out, in which case the future
+/// gets dropped right away.
+struct TickTimeout<F> {
+    future: Option<F>,
+    ticks_left: u32,
+}
+
+impl<F> TickTimeout<F> {
+    const fn new(future: F, ticks: u32) -> Self {
+        Self {
+            future: Some(future),
+            ticks_left: ticks,
+        }
+    }
+}
+
+impl<T, F: Future<Output = T>> Future for TickTimeout<F> {
+    type Output = Option<T>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        // SAFETY: We are simply projecting the Pin. The future is only ever
+        // dropped in place, never moved.
+        let this = unsafe { self.get_unchecked_mut() };
+        // SAFETY: See above.
+        let mut future = unsafe { Pin::new_unchecked(&mut this.future) };
+        let Some(inner) = future.as_mut().as_pin_mut() else {
+            return Poll::Ready(None);
+        };
+        if let Poll::Ready(value) = inner.poll(cx) {
+            return Poll::Ready(Some(value));
+        }
+        if this.ticks_left == 0 {
+            future.set(None);
+            return Poll::Ready(None);
+        }
+        this.ticks_left -= 1;
+        Poll::Pending
+    }
+}
+
 /// Defines that the auto splitter is using an asynchronous `main` function
 /// instead of the normal poll based `update` function. It is important to
 /// frequently yield back to the runtime to communicate that the auto splitter
@@ -345,14 +813,8 @@ macro_rules! async_main {
         /// Invoking this function yourself causes Undefined Behavior.
         #[no_mangle]
         pub unsafe extern "C" fn update() {
-            use core::{
-                cell::UnsafeCell,
-                future::Future,
-                pin::Pin,
-                ptr,
-                task::{Context, RawWaker, RawWakerVTable, Waker},
-            };
-            use $crate::sync::RacyCell;
+            use core::pin::Pin;
+            use $crate::{future::Executor, sync::RacyCell};
             mod fut {
                 pub type MainFuture = impl core::future::Future<Output = ()>;
                 pub const fn main_type() -> MainFuture {
@@ -362,24 +824,10 @@ macro_rules! async_main {
                 }
             }
 
-            static STATE: RacyCell<fut::MainFuture> = RacyCell::new(fut::main_type());
-            static FINISHED: RacyCell<bool> = RacyCell::new(false);
-            if unsafe { *FINISHED.get() } {
-                return;
-            }
-            static VTABLE: RawWakerVTable = RawWakerVTable::new(
-                |_| RawWaker::new(ptr::null(), &VTABLE),
-                |_| {},
-                |_| {},
-                |_| {},
-            );
-            let raw_waker = RawWaker::new(ptr::null(), &VTABLE);
-            let waker = unsafe { Waker::from_raw(raw_waker) };
-            let mut cx = Context::from_waker(&waker);
+            static STATE: RacyCell<Executor<fut::MainFuture>> =
+                RacyCell::new(Executor::new(fut::main_type()));
             unsafe {
-                *FINISHED.get_mut() = Pin::new_unchecked(&mut *STATE.get_mut())
-                    .poll(&mut cx)
-                    .is_ready();
+                Pin::new_unchecked(&mut *STATE.get_mut()).update();
             }
         }
     };
@@ -390,64 +838,113 @@ macro_rules! async_main {
         #[cfg(target_family = "wasm")]
         pub unsafe extern "C" fn update() {
             use core::{
-                cell::UnsafeCell,
                 future::Future,
                 mem::{self, ManuallyDrop},
                 pin::Pin,
                 ptr,
-                task::{Context, RawWaker, RawWakerVTable, Waker},
             };
-            use $crate::sync::RacyCell;
+            use $crate::{future::Executor, sync::RacyCell};
 
-            static STATE: RacyCell<Option<Pin<&'static mut dyn Future<Output = ()>>>> =
+            static STATE: RacyCell<Option<Executor<Pin<&'static mut dyn Future<Output = ()>>>>> =
                 RacyCell::new(None);
-            static FINISHED: RacyCell<bool> = RacyCell::new(false);
 
-            if unsafe { *FINISHED.get() } {
-                return;
-            }
-
-            static VTABLE: RawWakerVTable = RawWakerVTable::new(
-                |_| RawWaker::new(ptr::null(), &VTABLE),
-                |_| {},
-                |_| {},
-                |_| {},
-            );
-            let raw_waker = RawWaker::new(ptr::null(), &VTABLE);
-            let waker = unsafe { Waker::from_raw(raw_waker) };
-            let mut cx = Context::from_waker(&waker);
             unsafe {
-                *FINISHED.get_mut() =
-                    Pin::new_unchecked((&mut *STATE.get_mut()).get_or_insert_with(|| {
-                        fn allocate<F: Future<Output = ()> + 'static>(
-                            f: ManuallyDrop<F>,
-                        ) -> Pin<&'static mut dyn Future<Output = ()>> {
-                            unsafe {
-                                let size = mem::size_of::<F>();
-                                const PAGE_SIZE: usize = 64 << 10;
-                                assert!(mem::align_of::<F>() <= PAGE_SIZE);
-                                let pages = size.div_ceil(PAGE_SIZE);
-
-                                #[cfg(target_arch = "wasm32")]
-                                let old_page_count = core::arch::wasm32::memory_grow(0, pages);
-                                #[cfg(target_arch = "wasm64")]
-                                let old_page_count = core::arch::wasm64::memory_grow(0, pages);
-
-                                let address = old_page_count * PAGE_SIZE;
-                                let ptr = address as *mut ManuallyDrop<F>;
-                                ptr::write(ptr, f);
-                                let ptr = ptr.cast::<F>();
-                                let future: &'static mut F = &mut *ptr;
-                                let future: &'static mut dyn Future<Output = ()> = future;
-                                Pin::static_mut(future)
-                            }
+                let executor = (&mut *STATE.get_mut()).get_or_insert_with(|| {
+                    fn allocate<F: Future<Output = ()> + 'static>(
+                        f: ManuallyDrop<F>,
+                    ) -> Pin<&'static mut dyn Future<Output = ()>> {
+                        unsafe {
+                            let size = mem::size_of::<F>();
+                            const PAGE_SIZE: usize = 64 << 10;
+                            assert!(mem::align_of::<F>() <= PAGE_SIZE);
+                            let pages = size.div_ceil(PAGE_SIZE);
+
+                            #[cfg(target_arch = "wasm32")]
+                            let old_page_count = core::arch::wasm32::memory_grow(0, pages);
+                            #[cfg(target_arch = "wasm64")]
+                            let old_page_count = core::arch::wasm64::memory_grow(0, pages);
+
+                            let address = old_page_count * PAGE_SIZE;
+                            let ptr = address as *mut ManuallyDrop<F>;
+                            ptr::write(ptr, f);
+                            let ptr = ptr.cast::<F>();
+                            let future: &'static mut F = &mut *ptr;
+                            let future: &'static mut dyn Future<Output = ()> = future;
+                            Pin::static_mut(future)
                         }
+                    }
 
-                        allocate(ManuallyDrop::new(main()))
-                    }))
-                    .poll(&mut cx)
-                    .is_ready();
-            };
+                    Executor::new(allocate(ManuallyDrop::new(main())))
+                });
+                Pin::new(executor).update();
+            }
         }
     };
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use core::{cell::Cell, pin::pin, task::Waker};
+
+    fn poll<F: Future>(future: Pin<&mut F>) -> Poll<F::Output> {
+        future.poll(&mut Context::from_waker(Waker::noop()))
+    }
+
+    #[test]
+    fn next_ticks_is_pending_n_times() {
+        for n in [0, 1, 5] {
+            let mut future = pin!(next_ticks(n));
+            for _ in 0..n {
+                assert!(poll(future.as_mut()).is_pending());
+            }
+            assert!(poll(future.as_mut()).is_ready());
+        }
+    }
+
+    #[test]
+    fn wait_stable_restarts_on_a_differing_value() {
+        let mut samples = [1, 1, 2, 1, 1, 1].into_iter();
+        let mut future = pin!(wait_stable(|| samples.next(), 3));
+        // The 2 resets the count, so the first two 1s don't count towards it.
+        for _ in 0..5 {
+            assert!(poll(future.as_mut()).is_pending());
+        }
+        assert_eq!(poll(future.as_mut()), Poll::Ready(1));
+    }
+
+    #[test]
+    fn timing_out_drops_the_future() {
+        struct DropFlag<'a>(&'a Cell<bool>);
+
+        impl Drop for DropFlag<'_> {
+            fn drop(&mut self) {
+                self.0.set(true);
+            }
+        }
+
+        let dropped = Cell::new(false);
+        let flag = DropFlag(&dropped);
+        let mut future = pin!(TickTimeout::new(
+            async move {
+                let _flag = flag;
+                core::future::pending::<()>().await
+            },
+            2,
+        ));
+        for _ in 0..2 {
+            assert!(poll(future.as_mut()).is_pending());
+            assert!(!dropped.get());
+        }
+        assert_eq!(poll(future.as_mut()), Poll::Ready(None));
+        assert!(dropped.get());
+    }
+
+    #[test]
+    fn completing_in_time_returns_the_value() {
+        let mut future = pin!(TickTimeout::new(next_ticks(2), 2));
+        assert!(poll(future.as_mut()).is_pending());
+        assert!(poll(future.as_mut()).is_pending());
+        assert_eq!(poll(future.as_mut()), Poll::Ready(Some(())));
+    }
+}
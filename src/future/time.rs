@@ -7,6 +7,8 @@ use core::{
 
 use crate::time_util::Instant;
 
+use super::{retry, IntoOption, Retry};
+
 /// A type that provides futures that resolve in fixed intervals.
 ///
 /// # Example
@@ -159,3 +161,29 @@ pub fn timeout<F: Future>(duration: Duration, future: F) -> Timeout<F> {
         future,
     }
 }
+
+/// Retries the given function until it returns [`Some`] or [`Ok`], yielding
+/// back to the runtime between each call. If the function still hasn't
+/// succeeded after the given amount of time, the future resolves to [`None`].
+/// This is useful for waiting on modules or signatures that may never show
+/// up, such as when a module name is misspelled or the game version is not
+/// supported.
+///
+/// # Example
+///
+/// ```no_run
+/// let module = retry_timeout(Duration::from_secs(10), || {
+///     process.get_module_address("GameAssembly.dll")
+/// })
+/// .await;
+///
+/// if module.is_none() {
+///     print_message("GameAssembly.dll was not found within 10 seconds.");
+/// }
+/// ```
+pub fn retry_timeout<O: IntoOption, F: FnMut() -> O + Unpin>(
+    duration: Duration,
+    f: F,
+) -> Timeout<Retry<F>> {
+    timeout(duration, retry(f))
+}
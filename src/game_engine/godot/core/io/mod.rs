@@ -0,0 +1,3 @@
+mod resource;
+
+pub use resource::*;
@@ -0,0 +1,49 @@
+//! <https://github.com/godotengine/godot/blob/07cf36d21c9056fb4055f020949fb90ebd795afb/core/io/resource.h>
+
+use crate::{
+    game_engine::godot::{Ptr, RefCounted, String},
+    Error, Process,
+};
+
+#[allow(unused)]
+mod offsets {
+    // String
+    pub const NAME: u64 = 0x118;
+    // String
+    pub const PATH_CACHE: u64 = 0x120;
+    // String
+    pub const SCENE_UNIQUE_ID: u64 = 0x128;
+}
+
+/// Base class for serializable objects, such as textures, audio streams or
+/// the scenes stored as a `PackedScene`.
+///
+/// [`Resource`](https://docs.godotengine.org/en/4.2/classes/class_resource.html)
+///
+/// Check the [`Ptr<Resource>`] documentation to see all the methods you can
+/// call on it.
+#[derive(Debug, Copy, Clone)]
+#[repr(transparent)]
+pub struct Resource;
+extends!(Resource: RefCounted);
+
+impl Ptr<Resource> {
+    /// Returns the name of the resource, which is optional and empty unless
+    /// it has been set explicitly.
+    ///
+    /// [`Resource.get_name`](https://docs.godotengine.org/en/4.2/classes/class_resource.html#class-resource-property-resource-name)
+    pub fn get_name<const N: usize>(self, process: &Process) -> Result<String<N>, Error> {
+        Ptr::<String<N>>::new(self.addr() + offsets::NAME).read(process)
+    }
+
+    /// Returns the path the resource got loaded from, such as
+    /// `res://levels/level_1.tscn`. This is empty for resources that were
+    /// created at runtime and for the ones that are embedded in another
+    /// resource, such as a scene. For a `PackedScene`, this identifies the
+    /// scene even before it gets instantiated.
+    ///
+    /// [`Resource.get_path`](https://docs.godotengine.org/en/4.2/classes/class_resource.html#class-resource-property-resource-path)
+    pub fn get_path<const N: usize>(self, process: &Process) -> Result<String<N>, Error> {
+        Ptr::<String<N>>::new(self.addr() + offsets::PATH_CACHE).read(process)
+    }
+}
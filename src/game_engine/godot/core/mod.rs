@@ -1,9 +1,11 @@
+mod io;
 mod object;
 mod os;
 mod string;
 mod templates;
 mod variant;
 
+pub use io::*;
 pub use object::*;
 pub use os::*;
 pub use string::*;
@@ -1,7 +1,9 @@
 mod object;
+mod ref_counted;
 mod script_instance;
 mod script_language;
 
 pub use object::*;
+pub use ref_counted::*;
 pub use script_instance::*;
 pub use script_language::*;
@@ -1,7 +1,9 @@
 //! <https://github.com/godotengine/godot/blob/07cf36d21c9056fb4055f020949fb90ebd795afb/core/object/object.h>
 
+use bytemuck::CheckedBitPattern;
+
 use crate::{
-    game_engine::godot::{Ptr, VTable, VariantType},
+    game_engine::godot::{HashMap, Ptr, StringName, VTable, Variant, VariantType},
     Error, Process,
 };
 
@@ -73,6 +75,14 @@ impl Ptr<Object> {
         self.read_at_byte_offset(offsets::VTABLE_PTR, process)
     }
 
+    /// Checks whether the object is about to be deleted. Godot marks objects
+    /// this way right before destructing them, so nothing should be read from
+    /// the object anymore once this returns [`true`].
+    pub fn is_predeleting(self, process: &Process) -> Result<bool, Error> {
+        self.read_at_byte_offset(offsets::PREDELETE_OK, process)
+            .map(|predelete_ok: i32| predelete_ok != 0)
+    }
+
     /// Returns the object's Script instance, or [`None`] if no script is
     /// attached.
     ///
@@ -85,4 +95,37 @@ impl Ptr<Object> {
             self.read_at_byte_offset(offsets::SCRIPT_INSTANCE, process)?;
         Ok(if ptr.is_null() { None } else { Some(ptr) })
     }
+
+    /// Returns a [`HashMap`] that maps the name of each metadata entry of the
+    /// object to its value.
+    ///
+    /// [`Object.get_meta_list`](https://docs.godotengine.org/en/4.2/classes/class_object.html#class-object-method-get-meta-list)
+    pub fn get_metadata(self) -> Ptr<HashMap<StringName, Variant>> {
+        Ptr::new(self.addr() + offsets::METADATA)
+    }
+
+    /// Looks up a metadata entry of the object by its name and decodes its
+    /// value, returning [`None`] if there is no such entry or its value can't
+    /// be decoded as the type requested. See [`Variant::decode`] for how the
+    /// values are decoded. Entries that are objects are decoded from the
+    /// pointer to the object, so a [`Ptr`] of the correct type needs to be
+    /// requested for those.
+    ///
+    /// Built-in properties, such as `visible` or `position`, are not metadata
+    /// entries, so they can't be looked up this way. They are regular fields
+    /// of the engine's classes, so they have their own accessors, like the
+    /// position of a [`Node2D`](crate::game_engine::godot::Node2D). Members of
+    /// scripts can be accessed through the script instance instead.
+    ///
+    /// [`Object.get_meta`](https://docs.godotengine.org/en/4.2/classes/class_object.html#class-object-method-get-meta)
+    pub fn get_meta<T: CheckedBitPattern, const N: usize>(
+        self,
+        name: &[u8; N],
+        process: &Process,
+    ) -> Result<Option<T>, Error> {
+        let Some(variant) = self.get_metadata().get(name, process)? else {
+            return Ok(None);
+        };
+        Ok(variant.deref(process)?.decode())
+    }
 }
@@ -0,0 +1,93 @@
+//! <https://github.com/godotengine/godot/blob/07cf36d21c9056fb4055f020949fb90ebd795afb/core/object/ref_counted.h>
+
+use core::{fmt, marker::PhantomData};
+
+use bytemuck::{Pod, Zeroable};
+
+use crate::{
+    game_engine::godot::{Object, Ptr},
+    Error, Process,
+};
+
+#[allow(unused)]
+mod offsets {
+    // SafeRefCount
+    pub const REFCOUNT: u64 = 0x110;
+    // SafeRefCount
+    pub const REFCOUNT_INIT: u64 = 0x114;
+}
+
+/// Base class for reference-counted objects.
+///
+/// [`RefCounted`](https://docs.godotengine.org/en/4.2/classes/class_refcounted.html)
+///
+/// Check the [`Ptr<RefCounted>`] documentation to see all the methods you can
+/// call on it.
+#[derive(Debug, Copy, Clone)]
+#[repr(transparent)]
+pub struct RefCounted;
+extends!(RefCounted: Object);
+
+impl Ptr<RefCounted> {
+    /// Returns the current reference count of the object.
+    ///
+    /// [`RefCounted.get_reference_count`](https://docs.godotengine.org/en/4.2/classes/class_refcounted.html#class-refcounted-method-get-reference-count)
+    pub fn get_reference_count(self, process: &Process) -> Result<u32, Error> {
+        self.read_at_byte_offset(offsets::REFCOUNT, process)
+    }
+
+    /// Checks whether the object is still alive, meaning that it is still
+    /// referenced by something and its destruction has not started yet. Once
+    /// the last reference is dropped, the object is deleted and its memory may
+    /// be reused at any point, so anything read from it afterwards can't be
+    /// trusted.
+    pub fn is_alive(self, process: &Process) -> Result<bool, Error> {
+        Ok(self.get_reference_count(process)? != 0 && !self.is_predeleting(process)?)
+    }
+}
+
+/// A reference to a [`RefCounted`] object. This is the smart pointer that Godot
+/// uses to keep reference-counted objects, such as resources, alive.
+///
+/// [`Ref`](https://docs.godotengine.org/en/4.2/contributing/development/core_and_modules/core_types.html#references)
+#[repr(transparent)]
+pub struct Ref<T>(Ptr<T>, PhantomData<fn() -> T>);
+
+impl<T> fmt::Debug for Ref<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("Ref").field(&self.0).finish()
+    }
+}
+
+impl<T> Copy for Ref<T> {}
+
+impl<T> Clone for Ref<T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+// SAFETY: The type is transparent over a `Ptr`, which is `Pod`.
+unsafe impl<T: 'static> Pod for Ref<T> {}
+
+// SAFETY: The type is transparent over a `Ptr`, which is `Zeroable`.
+unsafe impl<T> Zeroable for Ref<T> {}
+
+impl<T> Ref<T> {
+    /// Returns the pointer to the referenced object without checking whether
+    /// the object is still alive. The pointer may be null.
+    pub const fn unchecked_ptr(self) -> Ptr<T> {
+        self.0
+    }
+
+    /// Returns the pointer to the referenced object, or [`None`] if the
+    /// reference is null or the object is no longer alive. See
+    /// [`Ptr<RefCounted>::is_alive`] for what this means.
+    pub fn get(self, process: &Process) -> Result<Option<Ptr<T>>, Error> {
+        let ptr = self.0;
+        if ptr.is_null() || !ptr.unchecked_cast::<RefCounted>().is_alive(process)? {
+            return Ok(None);
+        }
+        Ok(Some(ptr))
+    }
+}
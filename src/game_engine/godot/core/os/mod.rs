@@ -1,3 +1,6 @@
 mod main_loop;
+#[allow(clippy::module_inception)]
+mod os;
 
 pub use main_loop::*;
+pub use os::*;
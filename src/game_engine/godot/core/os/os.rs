@@ -0,0 +1,80 @@
+//! <https://github.com/godotengine/godot/blob/07cf36d21c9056fb4055f020949fb90ebd795afb/core/os/os.h>
+
+use arrayvec::ArrayString;
+
+use crate::{file_format::pe, Address, Process};
+
+/// Provides access to common operating system functionalities.
+///
+/// [`OS`](https://docs.godotengine.org/en/4.2/classes/class_os.html)
+#[derive(Debug, Copy, Clone)]
+pub struct OS;
+
+impl OS {
+    /// Reads the version of the engine that the game was built with, such as
+    /// `4.2.2.stable.official`. This can be used to make sure the game uses a
+    /// supported version of Godot before reading anything else. Returns
+    /// [`None`] if the version can't be found in the main module or if it
+    /// doesn't fit into the string.
+    ///
+    /// [`Engine.get_version_info`](https://docs.godotengine.org/en/4.2/classes/class_engine.html#class-engine-method-get-version-info)
+    ///
+    /// This searches the read-only and the initialized data of the main
+    /// module for the version string, so the result should be cached.
+    pub fn get_version_string<const N: usize>(
+        process: &Process,
+        main_module: Address,
+    ) -> Option<ArrayString<N>> {
+        // The full version is `VERSION_NUMBER.VERSION_STATUS.VERSION_BUILD`,
+        // where the build is either `official` or `custom_build`, unless
+        // overridden by whoever compiled the engine.
+        const SUFFIXES: [&[u8]; 2] = [b".official\0", b".custom_build\0"];
+        const CHUNK_SIZE: u64 = 16 << 10;
+        // Versions that start at the end of one chunk are found in the next.
+        const OVERLAP: u64 = 64;
+
+        let mut buf = [0; CHUNK_SIZE as usize];
+
+        // String constants end up in `.rdata`, unless they are stored in a
+        // mutable array, which ends up in `.data`.
+        [&b".rdata"[..], b".data"]
+            .into_iter()
+            .filter_map(|name| pe::find_section(process, main_module, name))
+            .find_map(|(start, size)| {
+                let mut offset = 0;
+                while offset < size {
+                    let len = (size - offset).min(CHUNK_SIZE) as usize;
+                    let buf = &mut buf[..len];
+                    if process.read_into_buf_quietly(start + offset, buf).is_ok() {
+                        if let Some(version) = SUFFIXES.iter().find_map(|suffix| {
+                            buf.windows(suffix.len())
+                                .enumerate()
+                                .filter(|(_, window)| window == suffix)
+                                .find_map(|(end, _)| parse_version(&buf[..end + suffix.len() - 1]))
+                        }) {
+                            return ArrayString::from(version).ok();
+                        }
+                    }
+                    if offset + len as u64 >= size {
+                        break;
+                    }
+                    offset += len as u64 - OVERLAP;
+                }
+                None
+            })
+    }
+}
+
+/// Takes the bytes up to the end of a potential version string and returns the
+/// version string, if the string it is part of looks like a version.
+fn parse_version(bytes: &[u8]) -> Option<&str> {
+    let start = bytes.iter().rposition(|&b| b == 0).map_or(0, |pos| pos + 1);
+    let version = core::str::from_utf8(&bytes[start..]).ok()?;
+
+    let mut parts = version.split('.');
+    let is_version = parts.next()?.parse::<u8>().is_ok()
+        && parts.next()?.parse::<u8>().is_ok()
+        && version.bytes().all(|b| b.is_ascii_graphic());
+
+    is_version.then_some(version)
+}
@@ -1,12 +1,11 @@
 //! <https://github.com/godotengine/godot/blob/07cf36d21c9056fb4055f020949fb90ebd795afb/core/string/string_name.h>
 
-use core::mem::MaybeUninit;
-
 use arrayvec::ArrayVec;
 use bytemuck::{Pod, Zeroable};
 
 use crate::{
     game_engine::godot::{Hash, Ptr, SizeInTargetProcess},
+    string::ArrayCString,
     Address64, Error, Process,
 };
 
@@ -74,27 +73,27 @@ impl SizeInTargetProcess for StringName {
 struct Data(Address64);
 
 impl StringName {
-    /// Reads the string from the target process.
+    /// Reads the string from the target process. Both names that are backed by
+    /// a static C string and names that have their own copy of the string are
+    /// supported. A null name is read as an empty string.
     pub fn read<const N: usize>(self, process: &Process) -> Result<String<N>, Error> {
-        // FIXME: This skips cname entirely atm.
-
-        // FIXME: Use CowData
-        let cow_data: Address64 = self
-            .data
-            .read_at_byte_offset(offsets::data::NAME, process)?;
+        let mut out = ArrayVec::new();
 
-        // Only on 4.2 or before.
-        let len = process
-            .read::<u32>(cow_data + -0x4)?
-            .checked_sub(1)
-            .ok_or(Error {})?;
-        let mut buf = [MaybeUninit::uninit(); N];
-        let buf = buf.get_mut(..len as usize).ok_or(Error {})?;
-        let buf = process.read_into_uninit_slice(cow_data, buf)?;
+        if self.data.is_null() {
+            return Ok(String(out));
+        }
 
-        let mut out = ArrayVec::new();
-        out.extend(buf.iter().copied());
+        // Names created from static C strings only store the pointer to the C
+        // string, while their name stays empty.
+        let cname: Address64 = self
+            .data
+            .read_at_byte_offset(offsets::data::CNAME, process)?;
+        if !cname.is_null() {
+            let cname = process.read::<ArrayCString<N>>(cname)?;
+            out.extend(cname.as_bytes().iter().map(|&b| b as u32));
+            return Ok(String(out));
+        }
 
-        Ok(String(out))
+        Ptr::<String<N>>::new(self.data.addr() + offsets::data::NAME).read(process)
     }
 }
@@ -1,8 +1,13 @@
 //! <https://github.com/godotengine/godot/blob/07cf36d21c9056fb4055f020949fb90ebd795afb/core/string/ustring.h>
 
+use core::mem::MaybeUninit;
+
 use arrayvec::{ArrayString, ArrayVec};
 
-use crate::game_engine::godot::SizeInTargetProcess;
+use crate::{
+    game_engine::godot::{Ptr, SizeInTargetProcess},
+    Address64, Error, Process,
+};
 
 /// A built-in type for strings.
 ///
@@ -38,3 +43,30 @@ impl<const N: usize> String<N> {
         self.chars().eq(text.chars())
     }
 }
+
+impl<const N: usize> Ptr<String<N>> {
+    /// Reads the string from the target process. A null string is read as an
+    /// empty string.
+    pub fn read(self, process: &Process) -> Result<String<N>, Error> {
+        let mut out = ArrayVec::new();
+
+        // FIXME: Use CowData
+        let cow_data: Address64 = self.read_at_byte_offset(0x0, process)?;
+        if cow_data.is_null() {
+            return Ok(String(out));
+        }
+
+        // Only on 4.2 or before.
+        let len = process
+            .read::<u32>(cow_data + -0x4)?
+            .checked_sub(1)
+            .ok_or(Error {})?;
+        let mut buf = [MaybeUninit::uninit(); N];
+        let buf = buf.get_mut(..len as usize).ok_or(Error {})?;
+        let buf = process.read_into_uninit_slice(cow_data, buf)?;
+
+        out.extend(buf.iter().copied());
+
+        Ok(String(out))
+    }
+}
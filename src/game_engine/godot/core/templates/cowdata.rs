@@ -2,7 +2,7 @@
 
 use bytemuck::{Pod, Zeroable};
 
-use crate::game_engine::godot::Ptr;
+use crate::{game_engine::godot::Ptr, Error, Process};
 
 /// A copy-on-write data type. This is not publicly exposed in Godot.
 #[repr(transparent)]
@@ -27,4 +27,15 @@ impl<T> CowData<T> {
     pub fn ptr(self) -> Ptr<T> {
         self.0
     }
+
+    /// Returns the number of elements. The number is stored right in front of
+    /// the data, unless there is no data at all, in which case there are no
+    /// elements.
+    pub fn len(self, process: &Process) -> Result<u32, Error> {
+        if self.0.is_null() {
+            return Ok(0);
+        }
+        // Only on 4.2 or before.
+        process.read(self.0.addr() + -0x4)
+    }
 }
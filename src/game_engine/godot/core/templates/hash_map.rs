@@ -70,7 +70,7 @@ impl<K: 'static, V: 'static> Ptr<HashMapElement<K, V>> {
 
 impl<K: 'static, V: 'static> Ptr<HashMap<K, V>> {
     /// Returns an iterator over the key-value pairs in this hash map.
-    pub fn iter<'a>(&'a self, process: &'a Process) -> impl Iterator<Item = (Ptr<K>, Ptr<V>)> + 'a
+    pub fn iter<'a>(self, process: &'a Process) -> impl Iterator<Item = (Ptr<K>, Ptr<V>)> + 'a
     where
         K: SizeInTargetProcess,
     {
@@ -89,10 +89,7 @@ impl<K: 'static, V: 'static> Ptr<HashMap<K, V>> {
     }
 
     /// Returns a backwards iterator over the key-value pairs in this hash map.
-    pub fn iter_back<'a>(
-        &'a self,
-        process: &'a Process,
-    ) -> impl Iterator<Item = (Ptr<K>, Ptr<V>)> + 'a
+    pub fn iter_back<'a>(self, process: &'a Process) -> impl Iterator<Item = (Ptr<K>, Ptr<V>)> + 'a
     where
         K: SizeInTargetProcess,
     {
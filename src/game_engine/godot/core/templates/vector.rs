@@ -4,7 +4,10 @@ use core::mem::size_of;
 
 use bytemuck::{Pod, Zeroable};
 
-use crate::game_engine::godot::{Ptr, SizeInTargetProcess};
+use crate::{
+    game_engine::godot::{Ptr, SizeInTargetProcess},
+    Error, Process,
+};
 
 use super::CowData;
 
@@ -34,10 +37,23 @@ unsafe impl<T: 'static> Pod for Vector<T> {}
 // SAFETY: The type is transparent over a `CowData` and a byte array, which is `Zeroable`.
 unsafe impl<T> Zeroable for Vector<T> {}
 
+impl<T> Vector<T> {
+    /// Returns the number of elements in the vector.
+    pub fn len(&self, process: &Process) -> Result<u32, Error> {
+        self.cowdata.len(process)
+    }
+}
+
 impl<T: SizeInTargetProcess> Vector<T> {
     /// Returns the pointer to the underlying data at the given index. This does
     /// not perform bounds checking.
     pub fn unchecked_at(&self, index: u64) -> Ptr<T> {
         Ptr::new(self.cowdata.ptr().addr() + index.wrapping_mul(T::SIZE))
     }
+
+    /// Returns an iterator over the pointers to the elements of the vector. If
+    /// the number of elements can't be read, the iterator is empty.
+    pub fn iter(&self, process: &Process) -> impl Iterator<Item = Ptr<T>> + '_ {
+        (0..self.len(process).unwrap_or_default()).map(|i| self.unchecked_at(i as u64))
+    }
 }
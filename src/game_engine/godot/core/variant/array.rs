@@ -0,0 +1,63 @@
+//! <https://github.com/godotengine/godot/blob/07cf36d21c9056fb4055f020949fb90ebd795afb/core/variant/array.cpp>
+
+use crate::{
+    game_engine::godot::{Ptr, Variant, Vector},
+    Error, Process,
+};
+
+#[allow(unused)]
+mod offsets {
+    // SafeRefCount
+    pub const REFCOUNT: u64 = 0x0;
+    // Vector<Variant>
+    pub const ARRAY: u64 = 0x8;
+    // *const Variant
+    pub const READ_ONLY: u64 = 0x18;
+}
+
+/// A built-in data structure that holds a sequence of elements of any type.
+/// It can be retrieved from a [`Variant`] with [`Variant::get_array`].
+///
+/// [`Array`](https://docs.godotengine.org/en/4.2/classes/class_array.html)
+///
+/// Check the [`Ptr<Array>`] documentation to see all the methods you can call
+/// on it.
+#[derive(Debug, Copy, Clone)]
+#[repr(transparent)]
+pub struct Array;
+
+impl Ptr<Array> {
+    /// Returns the elements of the array.
+    pub fn get_elements(self, process: &Process) -> Result<Vector<Variant>, Error> {
+        self.read_at_byte_offset(offsets::ARRAY, process)
+    }
+
+    /// Returns the number of elements in the array.
+    ///
+    /// [`Array.size`](https://docs.godotengine.org/en/4.2/classes/class_array.html#class-array-method-size)
+    pub fn size(self, process: &Process) -> Result<u32, Error> {
+        self.get_elements(process)?.len(process)
+    }
+
+    /// Reads the element at the given index, returning [`None`] if the index
+    /// is out of bounds.
+    pub fn get(self, index: u32, process: &Process) -> Result<Option<Variant>, Error> {
+        let elements = self.get_elements(process)?;
+        if index >= elements.len(process)? {
+            return Ok(None);
+        }
+        elements.unchecked_at(index as u64).deref(process).map(Some)
+    }
+
+    /// Returns an iterator over the elements of the array. Elements that can't
+    /// be read end the iteration. Elements that are containers themselves are
+    /// not read, but they can be accessed with [`Variant::get_array`] and
+    /// [`Variant::get_dictionary`] in turn, as deeply as needed.
+    pub fn iter(self, process: &Process) -> impl Iterator<Item = Variant> + '_ {
+        let elements = self.get_elements(process).ok();
+        let len = elements
+            .and_then(|elements| elements.len(process).ok())
+            .unwrap_or_default();
+        (0..len).map_while(move |i| elements?.unchecked_at(i as u64).deref(process).ok())
+    }
+}
@@ -0,0 +1,53 @@
+//! <https://github.com/godotengine/godot/blob/07cf36d21c9056fb4055f020949fb90ebd795afb/core/variant/dictionary.cpp>
+
+use crate::{
+    game_engine::godot::{HashMap, Ptr, Variant},
+    Error, Process,
+};
+
+#[allow(unused)]
+mod offsets {
+    // SafeRefCount
+    pub const REFCOUNT: u64 = 0x0;
+    // *const Variant
+    pub const READ_ONLY: u64 = 0x8;
+    // HashMap<Variant, Variant, VariantHasher, StringLikeVariantComparator>
+    pub const VARIANT_MAP: u64 = 0x10;
+}
+
+/// A built-in data structure that holds key-value pairs, where both the keys
+/// and the values can be of any type. It can be retrieved from a [`Variant`]
+/// with [`Variant::get_dictionary`].
+///
+/// [`Dictionary`](https://docs.godotengine.org/en/4.2/classes/class_dictionary.html)
+///
+/// Check the [`Ptr<Dictionary>`] documentation to see all the methods you can
+/// call on it.
+#[derive(Debug, Copy, Clone)]
+#[repr(transparent)]
+pub struct Dictionary;
+
+impl Ptr<Dictionary> {
+    /// Returns the [`HashMap`] that stores the entries of the dictionary.
+    pub fn get_variant_map(self) -> Ptr<HashMap<Variant, Variant>> {
+        Ptr::new(self.addr() + offsets::VARIANT_MAP)
+    }
+
+    /// Returns the number of entries in the dictionary.
+    ///
+    /// [`Dictionary.size`](https://docs.godotengine.org/en/4.2/classes/class_dictionary.html#class-dictionary-method-size)
+    pub fn size(self, process: &Process) -> Result<u32, Error> {
+        self.get_variant_map().size(process)
+    }
+
+    /// Returns an iterator over the keys and values of the dictionary, in the
+    /// order they were inserted. Entries that can't be read end the
+    /// iteration. Values that are containers themselves are not read, but
+    /// they can be accessed with [`Variant::get_array`] and
+    /// [`Variant::get_dictionary`] in turn, as deeply as needed.
+    pub fn iter(self, process: &Process) -> impl Iterator<Item = (Variant, Variant)> + '_ {
+        self.get_variant_map()
+            .iter(process)
+            .map_while(|(key, value)| Some((key.deref(process).ok()?, value.deref(process).ok()?)))
+    }
+}
@@ -1,3 +1,7 @@
+mod array;
+mod dictionary;
 mod variant;
 
+pub use array::*;
+pub use dictionary::*;
 pub use variant::*;
@@ -2,9 +2,9 @@
 
 use core::{fmt, mem::size_of};
 
-use bytemuck::{Pod, Zeroable};
+use bytemuck::{checked, CheckedBitPattern, Pod, Zeroable};
 
-use crate::game_engine::godot::SizeInTargetProcess;
+use crate::game_engine::godot::{Array, Dictionary, Ptr, Resource, SizeInTargetProcess};
 
 /// The type of a [`Variant`].
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Pod, Zeroable)]
@@ -141,6 +141,53 @@ impl Variant {
         let [f, _, _, _]: &[f32; 4] = bytemuck::cast_ref(&self.data);
         *f
     }
+
+    /// Returns the [`Array`] stored in the variant, or [`None`] if the variant
+    /// is not of the type [`VariantType::ARRAY`].
+    pub fn get_array(&self) -> Option<Ptr<Array>> {
+        if self.ty != VariantType::ARRAY {
+            return None;
+        }
+        self.decode()
+    }
+
+    /// Returns the [`Dictionary`] stored in the variant, or [`None`] if the
+    /// variant is not of the type [`VariantType::DICTIONARY`].
+    pub fn get_dictionary(&self) -> Option<Ptr<Dictionary>> {
+        if self.ty != VariantType::DICTIONARY {
+            return None;
+        }
+        self.decode()
+    }
+
+    /// Returns the [`Resource`] stored in the variant, or [`None`] if the
+    /// variant is not of the type [`VariantType::OBJECT`] or doesn't
+    /// reference any object. Variants don't know the class of the object
+    /// they reference, so make sure the object is a resource beforehand,
+    /// such as by only using this for properties that are declared as one.
+    pub fn get_resource(&self) -> Option<Ptr<Resource>> {
+        if self.ty != VariantType::OBJECT {
+            return None;
+        }
+        self.decode::<Ptr<Resource>>()
+            .filter(|resource| !resource.is_null())
+    }
+
+    /// Decodes the data of the variant as the type requested, returning
+    /// [`None`] if the type is larger than the data or the data is not a valid
+    /// bit pattern for it. Values are decoded from the start of the data. For
+    /// variants of the type [`VariantType::OBJECT`], the data starts with the
+    /// ID of the object, so the pointer to the object that follows it is
+    /// decoded instead. Make sure the type of the variant matches the type
+    /// requested beforehand.
+    pub fn decode<T: CheckedBitPattern>(&self) -> Option<T> {
+        let data = if self.ty == VariantType::OBJECT {
+            &self.data[8..]
+        } else {
+            &self.data[..]
+        };
+        checked::try_pod_read_unaligned(data.get(..size_of::<T>())?).ok()
+    }
 }
 
 impl fmt::Debug for Variant {
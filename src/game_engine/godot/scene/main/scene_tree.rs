@@ -42,6 +42,26 @@ mod offsets {
     pub const PENDING_NEW_SCENE: u64 = PREV_SCENE + 8;
 }
 
+/// Attaches to a Godot game running as a process with any of the names
+/// provided and waits for its [`SceneTree`] to be located, using the main
+/// module of the process. If the process closes before that, the process gets
+/// attached to again and the whole procedure starts over. This way, the
+/// returned [`SceneTree`] always belongs to the returned process.
+pub async fn wait_attach_full(process_names: &[&str]) -> (Process, Ptr<SceneTree>) {
+    loop {
+        let (name, process) = Process::wait_attach_any(process_names).await;
+        let scene_tree = process
+            .until_closes(async {
+                let main_module = retry(|| process.get_module_address(name)).await;
+                SceneTree::wait_locate(&process, main_module).await
+            })
+            .await;
+        if let Some(scene_tree) = scene_tree {
+            return (process, scene_tree);
+        }
+    }
+}
+
 /// Manages the game loop via a hierarchy of nodes.
 ///
 /// [`SceneTree`](https://docs.godotengine.org/en/4.2/classes/class_scenetree.html)
@@ -98,4 +118,32 @@ impl Ptr<SceneTree> {
         let scene: Ptr<Node> = self.read_at_byte_offset(offsets::CURRENT_SCENE, process)?;
         Ok(if scene.is_null() { None } else { Some(scene) })
     }
+
+    /// Looks up the autoload (singleton) with the name provided, returning
+    /// [`None`] if there is no such autoload. Autoloads are configured in the
+    /// project settings and are usually where the global state of a game is
+    /// stored. Godot adds them as children of the [root](Self::get_root)
+    /// before the main scene is loaded, so they stay alive across scene
+    /// changes. The name is the one of the node, which is the name the
+    /// autoload was given in the project settings. The current scene is a
+    /// child of the root as well, but is never returned.
+    ///
+    /// [Singletons (Autoload)](https://docs.godotengine.org/en/4.2/tutorials/scripting/singletons_autoload.html)
+    pub fn get_autoload<const N: usize>(
+        self,
+        name: &[u8; N],
+        process: &Process,
+    ) -> Result<Option<Ptr<Node>>, Error> {
+        let Some(node) = self.get_root(process)?.find_child(name, process)? else {
+            return Ok(None);
+        };
+        let current_scene = self.get_current_scene(process)?;
+        Ok(
+            if current_scene.is_some_and(|scene| scene.addr() == node.addr()) {
+                None
+            } else {
+                Some(node)
+            },
+        )
+    }
 }
@@ -0,0 +1,319 @@
+use crate::{future::retry, Address, Process};
+
+use super::{il2cpp, mono};
+
+/// The scripting backend that a Unity game is using.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum Backend {
+    /// The game is using the standard Mono backend. Its game code is stored
+    /// as .NET assemblies that get JIT compiled at runtime.
+    Mono,
+    /// The game is using the IL2CPP backend. Its game code is compiled ahead
+    /// of time into the `GameAssembly` library.
+    Il2Cpp,
+}
+
+/// Detects which scripting [backend](Backend) the Unity game is using based
+/// on the modules that are loaded. If the `GameAssembly` library is loaded,
+/// the game is using IL2CPP. Otherwise, if one of the Mono libraries is
+/// loaded, the game is using Mono. If neither is loaded yet, [`None`] is
+/// returned. Both the Windows and the Linux builds of the libraries are
+/// detected, even though the [`Module`] can currently only attach to the
+/// Windows builds.
+pub fn detect_backend(process: &Process) -> Option<Backend> {
+    const IL2CPP_MODULES: [&str; 2] = ["GameAssembly.dll", "GameAssembly.so"];
+    const MONO_MODULES: [&str; 6] = [
+        "mono.dll",
+        "mono-2.0-bdwgc.dll",
+        "mono-2.0-sgen.dll",
+        "libmono.so",
+        "libmonobdwgc-2.0.so",
+        "libmonosgen-2.0.so",
+    ];
+
+    let is_loaded = |names: &[&str]| {
+        names
+            .iter()
+            .any(|&name| process.get_module_address(name).is_ok())
+    };
+
+    if is_loaded(&IL2CPP_MODULES) {
+        Some(Backend::Il2Cpp)
+    } else if is_loaded(&MONO_MODULES) {
+        Some(Backend::Mono)
+    } else {
+        None
+    }
+}
+
+/// Attaches to a Unity game running as a process with any of the names
+/// provided and waits for its [`Module`] and its `Assembly-CSharp`
+/// [image](Image) to be resolved. If the process closes before that, for
+/// example because the game crashed or got restarted while loading, the
+/// process gets attached to again and the whole procedure starts over. This
+/// way, the returned module and image always belong to the returned process.
+///
+/// # Example
+///
+/// ```no_run
+/// # async fn example() {
+/// use asr::game_engine::unity;
+///
+/// let (process, module, image) = unity::wait_attach_full(&["Game.exe"]).await;
+/// # }
+/// ```
+pub async fn wait_attach_full(process_names: &[&str]) -> (Process, Module, Image) {
+    loop {
+        let (_, process) = Process::wait_attach_any(process_names).await;
+        let attached = process
+            .until_closes(async {
+                let module = Module::wait_attach_auto_detect(&process).await;
+                let image = module.wait_get_default_image(&process).await;
+                (module, image)
+            })
+            .await;
+        if let Some((module, image)) = attached {
+            return (process, module, image);
+        }
+    }
+}
+
+/// Represents access to a Unity game regardless of the scripting
+/// [backend](Backend) it is using. This dispatches to either the
+/// [`mono::Module`] or the [`il2cpp::Module`], which allows a single auto
+/// splitter to support games that ship with either backend. If more
+/// functionality is needed than what is provided here, the module of the
+/// backend can be accessed directly by matching on it.
+pub enum Module {
+    /// The game is using the standard Mono backend.
+    Mono(mono::Module),
+    /// The game is using the IL2CPP backend.
+    Il2Cpp(il2cpp::Module),
+}
+
+impl Module {
+    /// Tries attaching to a Unity game. The [backend](Backend) is detected
+    /// with [`detect_backend`] and the version of the backend is detected
+    /// automatically as well.
+    pub fn attach_auto_detect(process: &Process) -> Option<Self> {
+        Some(match detect_backend(process)? {
+            Backend::Mono => Self::Mono(mono::Module::attach_auto_detect(process)?),
+            Backend::Il2Cpp => Self::Il2Cpp(il2cpp::Module::attach_auto_detect(process)?),
+        })
+    }
+
+    /// Returns the scripting [backend](Backend) that the game is using.
+    pub const fn backend(&self) -> Backend {
+        match self {
+            Self::Mono(_) => Backend::Mono,
+            Self::Il2Cpp(_) => Backend::Il2Cpp,
+        }
+    }
+
+    /// Looks for the specified binary [image](Image) inside the target process.
+    /// An [image](Image) is a .NET DLL that is loaded by the game.
+    pub fn get_image(&self, process: &Process, assembly_name: &str) -> Option<Image> {
+        Some(match self {
+            Self::Mono(module) => Image::Mono(module.get_image(process, assembly_name)?),
+            Self::Il2Cpp(module) => Image::Il2Cpp(module.get_image(process, assembly_name)?),
+        })
+    }
+
+    /// Looks for the `Assembly-CSharp` binary [image](Image) inside the target
+    /// process. The `Assembly-CSharp` [image](Image) is the main game
+    /// assembly, and contains all the game logic.
+    pub fn get_default_image(&self, process: &Process) -> Option<Image> {
+        self.get_image(process, "Assembly-CSharp")
+    }
+
+    /// Looks for the specified [.NET class](Class) in all the [images](Image)
+    /// loaded by the game and returns it along with the [image](Image) it was
+    /// found in. This is a lot slower than [`Image::get_class`], so it's
+    /// meant for when it's not known which assembly contains the class.
+    pub fn find_class_in_any_image(
+        &self,
+        process: &Process,
+        class_name: &str,
+    ) -> Option<(Image, Class)> {
+        Some(match self {
+            Self::Mono(module) => {
+                let (image, class) = module.find_class_in_any_image(process, class_name)?;
+                (Image::Mono(image), Class::Mono(class))
+            }
+            Self::Il2Cpp(module) => {
+                let (image, class) = module.find_class_in_any_image(process, class_name)?;
+                (Image::Il2Cpp(image), Class::Il2Cpp(class))
+            }
+        })
+    }
+
+    /// Attaches to a Unity game, detecting both the [backend](Backend) and its
+    /// version.
+    ///
+    /// This is the `await`able version of the
+    /// [`attach_auto_detect`](Self::attach_auto_detect) function, yielding back
+    /// to the runtime between each try.
+    pub async fn wait_attach_auto_detect(process: &Process) -> Module {
+        retry(|| Self::attach_auto_detect(process)).await
+    }
+
+    /// Looks for the specified binary [image](Image) inside the target process.
+    ///
+    /// This is the `await`able version of the [`get_image`](Self::get_image)
+    /// function, yielding back to the runtime between each try.
+    pub async fn wait_get_image(&self, process: &Process, assembly_name: &str) -> Image {
+        retry(|| self.get_image(process, assembly_name)).await
+    }
+
+    /// Looks for the `Assembly-CSharp` binary [image](Image) inside the target
+    /// process.
+    ///
+    /// This is the `await`able version of the
+    /// [`get_default_image`](Self::get_default_image) function, yielding back
+    /// to the runtime between each try.
+    pub async fn wait_get_default_image(&self, process: &Process) -> Image {
+        retry(|| self.get_default_image(process)).await
+    }
+}
+
+/// A .NET DLL that is loaded by the game, regardless of the scripting
+/// [backend](Backend) it is using. It can only be used with the [`Module`] it
+/// was retrieved from.
+#[derive(Copy, Clone)]
+pub enum Image {
+    /// An image of the standard Mono backend.
+    Mono(mono::Image),
+    /// An image of the IL2CPP backend.
+    Il2Cpp(il2cpp::Image),
+}
+
+impl Image {
+    /// Tries to find the specified [.NET class](Class) in the image. If the
+    /// image and the module are not of the same [backend](Backend), [`None`]
+    /// is returned.
+    pub fn get_class(&self, process: &Process, module: &Module, class_name: &str) -> Option<Class> {
+        Some(match (self, module) {
+            (Self::Mono(image), Module::Mono(module)) => {
+                Class::Mono(image.get_class(process, module, class_name)?)
+            }
+            (Self::Il2Cpp(image), Module::Il2Cpp(module)) => {
+                Class::Il2Cpp(image.get_class(process, module, class_name)?)
+            }
+            _ => return None,
+        })
+    }
+
+    /// Tries to find the specified [.NET class](Class) in the image.
+    ///
+    /// This is the `await`able version of the [`get_class`](Self::get_class)
+    /// function, yielding back to the runtime between each try.
+    pub async fn wait_get_class(
+        &self,
+        process: &Process,
+        module: &Module,
+        class_name: &str,
+    ) -> Class {
+        retry(|| self.get_class(process, module, class_name)).await
+    }
+}
+
+/// A .NET class that is part of an [`Image`], regardless of the scripting
+/// [backend](Backend) the game is using. It can only be used with the
+/// [`Module`] it was retrieved from.
+#[derive(Copy, Clone)]
+pub enum Class {
+    /// A class of the standard Mono backend.
+    Mono(mono::Class),
+    /// A class of the IL2CPP backend.
+    Il2Cpp(il2cpp::Class),
+}
+
+impl Class {
+    /// Tries to find the offset for a field with the specified name in the
+    /// class. If it's a static field, the offset will be from the start of the
+    /// static table. If the class and the module are not of the same
+    /// [backend](Backend), [`None`] is returned.
+    pub fn get_field_offset(
+        &self,
+        process: &Process,
+        module: &Module,
+        field_name: &str,
+    ) -> Option<u32> {
+        match (self, module) {
+            (Self::Mono(class), Module::Mono(module)) => {
+                class.get_field_offset(process, module, field_name)
+            }
+            (Self::Il2Cpp(class), Module::Il2Cpp(module)) => {
+                class.get_field_offset(process, module, field_name)
+            }
+            _ => None,
+        }
+    }
+
+    /// Tries to find the address of the static table of the class. If the
+    /// class and the module are not of the same [backend](Backend), [`None`]
+    /// is returned.
+    pub fn get_static_table(&self, process: &Process, module: &Module) -> Option<Address> {
+        match (self, module) {
+            (Self::Mono(class), Module::Mono(module)) => class.get_static_table(process, module),
+            (Self::Il2Cpp(class), Module::Il2Cpp(module)) => {
+                class.get_static_table(process, module)
+            }
+            _ => None,
+        }
+    }
+
+    /// Tries to find the parent class. If the class and the module are not of
+    /// the same [backend](Backend), [`None`] is returned.
+    pub fn get_parent(&self, process: &Process, module: &Module) -> Option<Class> {
+        Some(match (self, module) {
+            (Self::Mono(class), Module::Mono(module)) => {
+                Class::Mono(class.get_parent(process, module)?)
+            }
+            (Self::Il2Cpp(class), Module::Il2Cpp(module)) => {
+                Class::Il2Cpp(class.get_parent(process, module)?)
+            }
+            _ => return None,
+        })
+    }
+
+    /// Tries to find the offset for a field with the specified name in the
+    /// class.
+    ///
+    /// This is the `await`able version of the
+    /// [`get_field_offset`](Self::get_field_offset) function, yielding back
+    /// to the runtime between each try.
+    pub async fn wait_get_field_offset(
+        &self,
+        process: &Process,
+        module: &Module,
+        field_name: &str,
+    ) -> u32 {
+        retry(|| self.get_field_offset(process, module, field_name)).await
+    }
+
+    /// Tries to find the address of a static instance of the class based on
+    /// its field name. This waits until the field is not null. If the class
+    /// and the module are not of the same [backend](Backend), this never
+    /// finishes.
+    pub async fn wait_get_static_instance(
+        &self,
+        process: &Process,
+        module: &Module,
+        field_name: &str,
+    ) -> Address {
+        match (self, module) {
+            (Self::Mono(class), Module::Mono(module)) => {
+                class
+                    .wait_get_static_instance(process, module, field_name)
+                    .await
+            }
+            (Self::Il2Cpp(class), Module::Il2Cpp(module)) => {
+                class
+                    .wait_get_static_instance(process, module, field_name)
+                    .await
+            }
+            _ => retry(|| None).await,
+        }
+    }
+}
@@ -0,0 +1,134 @@
+/// The type of a field of a .NET class, as it is encoded by both Mono and
+/// IL2CPP. The values match the element types of the ECMA-335 standard.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum FieldType {
+    /// `void`
+    Void,
+    /// `bool`
+    Boolean,
+    /// `char` (UTF-16 code unit)
+    Char,
+    /// `sbyte`
+    I1,
+    /// `byte`
+    U1,
+    /// `short`
+    I2,
+    /// `ushort`
+    U2,
+    /// `int`
+    I4,
+    /// `uint`
+    U4,
+    /// `long`
+    I8,
+    /// `ulong`
+    U8,
+    /// `float`
+    R4,
+    /// `double`
+    R8,
+    /// `string`, stored as a pointer to the string object.
+    String,
+    /// An unmanaged pointer.
+    Ptr,
+    /// A managed reference, such as the type of a `ref` parameter.
+    ByRef,
+    /// A value type, such as a struct or an enum, stored inline.
+    ValueType,
+    /// A reference type, stored as a pointer to the object.
+    Class,
+    /// A generic parameter of the class.
+    Var,
+    /// A multi-dimensional array, stored as a pointer to the array object.
+    Array,
+    /// An instance of a generic type, such as `List<int>`. This may either be
+    /// a value type or a reference type.
+    GenericInst,
+    /// A typed reference.
+    TypedByRef,
+    /// `nint`, a pointer-sized signed integer.
+    I,
+    /// `nuint`, a pointer-sized unsigned integer.
+    U,
+    /// A function pointer.
+    FnPtr,
+    /// `object`, stored as a pointer to the object.
+    Object,
+    /// A single-dimensional array, stored as a pointer to the array object.
+    SzArray,
+    /// A generic parameter of a method.
+    MVar,
+}
+
+impl FieldType {
+    pub(super) const fn from_raw(raw: u8) -> Option<Self> {
+        Some(match raw {
+            0x01 => Self::Void,
+            0x02 => Self::Boolean,
+            0x03 => Self::Char,
+            0x04 => Self::I1,
+            0x05 => Self::U1,
+            0x06 => Self::I2,
+            0x07 => Self::U2,
+            0x08 => Self::I4,
+            0x09 => Self::U4,
+            0x0A => Self::I8,
+            0x0B => Self::U8,
+            0x0C => Self::R4,
+            0x0D => Self::R8,
+            0x0E => Self::String,
+            0x0F => Self::Ptr,
+            0x10 => Self::ByRef,
+            0x11 => Self::ValueType,
+            0x12 => Self::Class,
+            0x13 => Self::Var,
+            0x14 => Self::Array,
+            0x15 => Self::GenericInst,
+            0x16 => Self::TypedByRef,
+            0x18 => Self::I,
+            0x19 => Self::U,
+            0x1B => Self::FnPtr,
+            0x1C => Self::Object,
+            0x1D => Self::SzArray,
+            0x1E => Self::MVar,
+            _ => return None,
+        })
+    }
+
+    /// Returns the name of the class in the core library that values of
+    /// builtin types are instances of. Their types don't reference the class,
+    /// so it has to be looked up by this name instead.
+    pub(super) const fn core_class_name(self) -> Option<&'static str> {
+        Some(match self {
+            Self::Boolean => "Boolean",
+            Self::Char => "Char",
+            Self::I1 => "SByte",
+            Self::U1 => "Byte",
+            Self::I2 => "Int16",
+            Self::U2 => "UInt16",
+            Self::I4 => "Int32",
+            Self::U4 => "UInt32",
+            Self::I8 => "Int64",
+            Self::U8 => "UInt64",
+            Self::R4 => "Single",
+            Self::R8 => "Double",
+            Self::String => "String",
+            Self::I => "IntPtr",
+            Self::U => "UIntPtr",
+            Self::Object => "Object",
+            _ => return None,
+        })
+    }
+
+    /// Checks whether the field stores a pointer to a managed object rather
+    /// than the value itself. Generic instances may be either, so they are
+    /// not considered references.
+    pub const fn is_reference(self) -> bool {
+        matches!(
+            self,
+            Self::String | Self::Class | Self::Array | Self::Object | Self::SzArray
+        )
+    }
+}
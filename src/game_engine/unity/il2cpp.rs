@@ -2,21 +2,36 @@
 
 use core::{
     array,
-    cell::RefCell,
+    cell::{Cell, RefCell},
     iter::{self, FusedIterator},
+    mem,
 };
 
 use crate::{
-    file_format::pe, future::retry, signature::Signature, string::ArrayCString, Address, Address64,
-    Error, PointerSize, Process,
+    file_format::{self, pe},
+    future::retry,
+    game_engine::unity::{version, FieldType},
+    signature::Signature,
+    string::ArrayCString,
+    Address, Address64, Error, PointerSize, Process,
 };
 
 #[cfg(feature = "derive")]
 pub use asr_derive::Il2cppClass as Class;
-use bytemuck::CheckedBitPattern;
+use bytemuck::{AnyBitPattern, CheckedBitPattern};
 
 const CSTR: usize = 128;
 
+/// `GameAssembly.dll` is usually quite large, so the signatures are scanned
+/// for in larger chunks than a single page.
+const SCAN_CHUNK_SIZE: usize = 64 << 10;
+
+const IL2CPP_TYPE_VALUETYPE: u8 = 0x11;
+const IL2CPP_TYPE_CLASS: u8 = 0x12;
+const IL2CPP_TYPE_GENERICINST: u8 = 0x15;
+
+const FIELD_ATTRIBUTE_STATIC: u16 = 0x10;
+
 /// Represents access to a Unity game that is using the IL2CPP backend.
 pub struct Module {
     pointer_size: PointerSize,
@@ -24,6 +39,7 @@ pub struct Module {
     offsets: &'static Offsets,
     assemblies: Address,
     type_info_definition_table: Address,
+    metadata: Cell<Option<Address>>,
 }
 
 impl Module {
@@ -47,10 +63,7 @@ impl Module {
             (address, size)
         };
 
-        let pointer_size = match pe::MachineType::read(process, mono_module.0)? {
-            pe::MachineType::X86_64 => PointerSize::Bit64,
-            _ => PointerSize::Bit32,
-        };
+        let pointer_size = file_format::read_binary_info(process, mono_module.0)?.pointer_size?;
 
         let offsets = Offsets::new(version, pointer_size)?;
 
@@ -59,14 +72,18 @@ impl Module {
                 const ASSEMBLIES_TRG_SIG: Signature<12> =
                     Signature::new("48 FF C5 80 3C ?? 00 75 ?? 48 8B 1D");
 
-                let addr = ASSEMBLIES_TRG_SIG.scan_process_range(process, mono_module)? + 12;
+                let addr = ASSEMBLIES_TRG_SIG
+                    .scan_process_range_chunked::<SCAN_CHUNK_SIZE>(process, mono_module)?
+                    + 12;
                 addr + 0x4 + process.read::<i32>(addr).ok()?
             }
             PointerSize::Bit32 => {
                 const ASSEMBLIES_TRG_SIG: Signature<9> =
                     Signature::new("8A 07 47 84 C0 75 ?? 8B 35");
 
-                let addr = ASSEMBLIES_TRG_SIG.scan_process_range(process, mono_module)? + 9;
+                let addr = ASSEMBLIES_TRG_SIG
+                    .scan_process_range_chunked::<SCAN_CHUNK_SIZE>(process, mono_module)?
+                    + 9;
                 process.read_pointer(addr, pointer_size).ok()?
             }
             _ => return None,
@@ -77,7 +94,7 @@ impl Module {
                 Signature::new("48 83 3C ?? 00 75 ?? 8B C? E8");
 
             let addr = TYPE_INFO_DEFINITION_TABLE_TRG_SIG
-                .scan_process_range(process, mono_module)?
+                .scan_process_range_chunked::<SCAN_CHUNK_SIZE>(process, mono_module)?
                 .add_signed(-4);
 
             process
@@ -88,8 +105,9 @@ impl Module {
             const TYPE_INFO_DEFINITION_TABLE_TRG_SIG: Signature<10> =
                 Signature::new("C3 A1 ?? ?? ?? ?? 83 3C ?? 00");
 
-            let addr =
-                TYPE_INFO_DEFINITION_TABLE_TRG_SIG.scan_process_range(process, mono_module)? + 2;
+            let addr = TYPE_INFO_DEFINITION_TABLE_TRG_SIG
+                .scan_process_range_chunked::<SCAN_CHUNK_SIZE>(process, mono_module)?
+                + 2;
 
             process
                 .read_pointer(process.read_pointer(addr, pointer_size).ok()?, pointer_size)
@@ -103,6 +121,23 @@ impl Module {
             offsets,
             assemblies,
             type_info_definition_table,
+            metadata: Cell::new(None),
+        })
+    }
+
+    /// Tries attaching to a Unity game that is using the IL2CPP backend
+    /// with each of the [IL2CPP versions](Version) provided, in order. A
+    /// version is only considered correct if the `Assembly-CSharp`
+    /// [image](Image) can be resolved with it, as attaching with the wrong
+    /// version may succeed but produce a module that can't find anything.
+    /// Returns the first module that passes this check. This is useful if
+    /// [`attach_auto_detect`](Self::attach_auto_detect) fails for a game, but
+    /// the version is known to be one of a few candidates.
+    pub fn attach_any(process: &Process, versions: &[Version]) -> Option<Self> {
+        versions.iter().find_map(|&version| {
+            let module = Self::attach(process, version)?;
+            module.get_default_image(process)?;
+            Some(module)
         })
     }
 
@@ -154,6 +189,39 @@ impl Module {
             .and_then(|assembly| assembly.get_image(process, self))
     }
 
+    /// Checks whether an assembly with the specified name is loaded by the
+    /// game, without resolving its [image](Image). This is useful for
+    /// detecting the version of a game based on the assemblies it ships with.
+    pub fn has_image(&self, process: &Process, assembly_name: &str) -> bool {
+        self.assemblies(process).any(|assembly| {
+            assembly
+                .get_name::<CSTR>(process, self)
+                .is_ok_and(|name| name.matches(assembly_name))
+        })
+    }
+
+    /// Looks for the specified [.NET class](struct@Class) in all the
+    /// [images](Image) loaded by the game, in the order the assemblies were
+    /// loaded in. This helps with games that split their code across
+    /// multiple assemblies, such as `Assembly-CSharp` and
+    /// `Assembly-CSharp-firstpass`, when it's not known which one contains
+    /// the class. The [image](Image) the class was found in is returned as
+    /// well, so any further classes of it can be looked up directly.
+    ///
+    /// All the classes of every image are searched until the class is found,
+    /// so this is a lot slower than [`Image::get_class`]. If multiple images
+    /// contain a class with the same name, the first one is returned.
+    pub fn find_class_in_any_image(
+        &self,
+        process: &Process,
+        class_name: &str,
+    ) -> Option<(Image, Class)> {
+        self.assemblies(process).find_map(|assembly| {
+            let image = assembly.get_image(process, self)?;
+            Some((image, image.get_class(process, self, class_name)?))
+        })
+    }
+
     /// Looks for the `Assembly-CSharp` binary [image](Image) inside the target
     /// process. An [image](Image) is a .NET DLL that is loaded
     /// by the game. The `Assembly-CSharp` [image](Image) is the main
@@ -164,6 +232,71 @@ impl Module {
         self.get_image(process, "Assembly-CSharp")
     }
 
+    /// Reads the string literal with the index provided from the
+    /// `global-metadata.dat` file of the game. String literals are all the
+    /// strings that are written directly in the game code, such as the names
+    /// of levels or achievements that get passed around. The index can be
+    /// found by decompiling the game, for example with Il2CppDumper, which
+    /// lists the string literals in the order they are stored in the
+    /// metadata. The string is truncated if it's longer than `N` bytes.
+    ///
+    /// The metadata file is mapped into memory by the game, so the first call
+    /// looks for it in all the memory ranges of the process, which is slow.
+    /// The address is cached for any subsequent calls. This supports the
+    /// metadata versions 24 to 29 and 31, which are used by Unity 2018 to
+    /// Unity 2022.
+    pub fn get_string_literal<const N: usize>(
+        &self,
+        process: &Process,
+        index: u32,
+    ) -> Option<ArrayCString<N>> {
+        const SANITY: u32 = 0xFAB11BAF;
+        const LITERAL_SIZE: u64 = 8;
+
+        let metadata = match self.metadata.get() {
+            Some(metadata) => metadata,
+            None => {
+                let metadata = process.memory_ranges().find_map(|range| {
+                    let address = range.address().ok()?;
+                    let [sanity, version] = process.read::<[u32; 2]>(address).ok()?;
+                    (sanity == SANITY && matches!(version, 24..=29 | 31)).then_some(address)
+                })?;
+                self.metadata.set(Some(metadata));
+                metadata
+            }
+        };
+
+        // Il2CppGlobalMetadataHeader: stringLiteralOffset, stringLiteralSize,
+        // stringLiteralDataOffset, stringLiteralDataSize
+        let [literals, literals_size, data, data_size] =
+            process.read::<[u32; 4]>(metadata + 0x8).ok()?;
+
+        if (index as u64 + 1) * LITERAL_SIZE > literals_size as u64 {
+            return None;
+        }
+
+        // Il2CppStringLiteral: length, dataIndex
+        let [length, data_index] = process
+            .read::<[u32; 2]>(metadata + literals as u64 + index as u64 * LITERAL_SIZE)
+            .ok()?;
+
+        if data_index as u64 + length as u64 > data_size as u64 {
+            return None;
+        }
+
+        // The literals are not nul-terminated, so only their bytes are read
+        // into the zeroed string.
+        let mut string = ArrayCString::<N>::new();
+        let len = (length as usize).min(N);
+        process
+            .read_into_buf(
+                metadata + data as u64 + data_index as u64,
+                &mut bytemuck::bytes_of_mut(&mut string)[..len],
+            )
+            .ok()?;
+        Some(string)
+    }
+
     /// Attaches to a Unity game that is using the IL2CPP backend. This function
     /// automatically detects the [IL2CPP version](Version). If you know the
     /// version in advance or it fails detecting it, use
@@ -187,6 +320,16 @@ impl Module {
         retry(|| Self::attach(process, version)).await
     }
 
+    /// Attaches to a Unity game that is using the IL2CPP backend with the
+    /// first of the [IL2CPP versions](Version) provided that is able to
+    /// resolve the `Assembly-CSharp` [image](Image).
+    ///
+    /// This is the `await`able version of the [`attach_any`](Self::attach_any)
+    /// function, yielding back to the runtime between each try.
+    pub async fn wait_attach_any(process: &Process, versions: &[Version]) -> Self {
+        retry(|| Self::attach_any(process, versions)).await
+    }
+
     /// Looks for the specified binary [image](Image) inside the target process.
     /// An [image](Image) is a .NET DLL that is loaded
     /// by the game. The `Assembly-CSharp` [image](Image) is the main game
@@ -215,6 +358,46 @@ impl Module {
         retry(|| self.get_default_image(process)).await
     }
 
+    /// Reads a .NET `Nullable<T>` (`T?`) that is stored at the address
+    /// provided, such as a field of that type. A nullable value type is
+    /// stored inline as a `HasValue` flag followed by the value, which is
+    /// aligned to the alignment of `T`. Reading it as the raw value instead
+    /// would mix the flag into the value. Returns [`None`] if the nullable
+    /// doesn't have a value. `T` needs to match the layout of the value type.
+    ///
+    /// Fields of such a type are reported as [`FieldType::GenericInst`] by
+    /// [`Class::get_field_type`].
+    pub fn read_nullable<T: CheckedBitPattern>(
+        &self,
+        process: &Process,
+        address: Address,
+    ) -> Result<Option<T>, Error> {
+        if !process.read::<bool>(address)? {
+            return Ok(None);
+        }
+        let value_offset = 1u64.next_multiple_of(mem::align_of::<T>() as u64);
+        process.read(address + value_offset).map(Some)
+    }
+
+    /// Reads a value type that is boxed into the IL2CPP object located at the
+    /// address provided. This is how value types are stored in fields of
+    /// type `object` or of an interface type. The value is stored right after
+    /// the object header. `T` needs to match the layout of the value type.
+    ///
+    /// Fields of type `object` are reported as [`FieldType::Object`] by
+    /// [`Class::get_field_type`]. Such a field itself is a pointer to the box.
+    pub fn read_boxed<T: CheckedBitPattern>(
+        &self,
+        process: &Process,
+        object: Address,
+    ) -> Result<T, Error> {
+        if object.is_null() {
+            return Err(Error {});
+        }
+        // The object header consists of the vtable and the monitor
+        process.read(object + self.size_of_ptr().wrapping_mul(2))
+    }
+
     #[inline]
     const fn size_of_ptr(&self) -> u64 {
         self.pointer_size as u64
@@ -232,14 +415,13 @@ impl Assembly {
         process: &Process,
         module: &Module,
     ) -> Result<ArrayCString<N>, Error> {
-        process
-            .read_pointer(
-                self.assembly
-                    + module.offsets.monoassembly_aname
-                    + module.offsets.monoassemblyname_name,
-                module.pointer_size,
-            )
-            .and_then(|addr| process.read(addr))
+        process.read_through(
+            self.assembly
+                + module.offsets.monoassembly_aname
+                + module.offsets.monoassemblyname_name,
+            module.pointer_size,
+            0,
+        )
     }
 
     fn get_image(&self, process: &Process, module: &Module) -> Option<Image> {
@@ -261,46 +443,106 @@ pub struct Image {
 }
 
 impl Image {
+    /// Returns the address of the image's first entry in the type info
+    /// definition table, together with the number of types in the image.
+    fn type_definitions(&self, process: &Process, module: &Module) -> Option<(Address, u32)> {
+        let type_count = process
+            .read::<u32>(self.image + module.offsets.monoimage_typecount)
+            .ok()
+            .filter(|val| !val.eq(&0))?;
+
+        let metadata_ptr = match module.version {
+            Version::V2020 => process
+                .read_pointer(
+                    self.image + module.offsets.monoimage_metadatahandle,
+                    module.pointer_size,
+                )
+                .ok()?,
+            _ => self.image + module.offsets.monoimage_metadatahandle,
+        };
+
+        let metadata_handle = process.read::<u32>(metadata_ptr).ok()?;
+
+        Some((
+            module.type_info_definition_table
+                + (metadata_handle as u64).wrapping_mul(module.size_of_ptr()),
+            type_count,
+        ))
+    }
+
     /// Iterates over all [.NET classes](struct@Class) in the image.
     pub fn classes<'a>(
         &self,
         process: &'a Process,
         module: &'a Module,
     ) -> impl DoubleEndedIterator<Item = Class> + 'a {
-        let type_count = process
-            .read::<u32>(self.image + module.offsets.monoimage_typecount)
-            .ok()
-            .filter(|val| !val.eq(&0));
+        let (ptr, type_count) = self
+            .type_definitions(process, module)
+            .unwrap_or((Address::NULL, 0));
 
-        let metadata_ptr = type_count.and_then(|_| match module.version {
-            Version::V2020 => process
+        (0..type_count as u64).filter_map(move |i| {
+            process
                 .read_pointer(
-                    self.image + module.offsets.monoimage_metadatahandle,
+                    ptr + i.wrapping_mul(module.size_of_ptr()),
                     module.pointer_size,
                 )
-                .ok(),
-            _ => Some(self.image + module.offsets.monoimage_metadatahandle),
-        });
+                .ok()
+                .filter(|val| !val.is_null())
+                .map(|class| Class { class })
+        })
+    }
 
-        let metadata_handle = type_count
-            .and_then(|_| metadata_ptr)
-            .and_then(|x| process.read::<u32>(x).ok());
+    /// Looks up a [.NET class](struct@Class) by its metadata token, by
+    /// directly indexing into the type info definition table instead of
+    /// searching for the class by its name. Tokens are stable for a specific
+    /// build of a game, so they can also be used to quickly find classes
+    /// whose names are obfuscated.
+    ///
+    /// Tokens are specific to the image the class is defined in. They are
+    /// shown as the `Token` attribute on each class in the dummy DLLs that
+    /// tools such as [Il2CppDumper](https://github.com/Perfare/Il2CppDumper)
+    /// generate. The token of a type definition has the form `0x02XXXXXX`.
+    ///
+    /// Returns [`None`] if the token doesn't refer to a type defined in the
+    /// image or if the class is not initialized yet.
+    pub fn get_class_by_token(
+        &self,
+        process: &Process,
+        module: &Module,
+        token: u32,
+    ) -> Option<Class> {
+        const TYPE_DEF_TABLE: u32 = 0x02;
 
-        let ptr = metadata_handle.map(|val| {
-            module.type_info_definition_table + (val as u64).wrapping_mul(module.size_of_ptr())
-        });
+        if token >> 24 != TYPE_DEF_TABLE {
+            return None;
+        }
 
-        (0..type_count.unwrap_or_default() as u64).filter_map(move |i| {
-            ptr.and_then(|ptr| {
-                process
-                    .read_pointer(
-                        ptr + i.wrapping_mul(module.size_of_ptr()),
-                        module.pointer_size,
-                    )
-                    .ok()
-            })
+        // The row ids of the tokens start at 1.
+        let index = (token & 0x00FF_FFFF).checked_sub(1)?;
+
+        let (ptr, type_count) = self.type_definitions(process, module)?;
+        if index >= type_count {
+            return None;
+        }
+
+        process
+            .read_pointer(
+                ptr + (index as u64).wrapping_mul(module.size_of_ptr()),
+                module.pointer_size,
+            )
+            .ok()
             .filter(|val| !val.is_null())
             .map(|class| Class { class })
+    }
+
+    /// Checks whether the image defines a [.NET class](struct@Class) with the
+    /// specified name. This is useful for telling apart different versions or
+    /// patches of a game, based on classes that only exist in some of them.
+    pub fn has_class(&self, process: &Process, module: &Module, class_name: &str) -> bool {
+        self.classes(process, module).any(|class| {
+            class
+                .get_name::<CSTR>(process, module)
+                .is_ok_and(|name| name.matches(class_name))
         })
     }
 
@@ -338,12 +580,11 @@ impl Class {
         process: &Process,
         module: &Module,
     ) -> Result<ArrayCString<N>, Error> {
-        process
-            .read_pointer(
-                self.class + module.offsets.monoclass_name,
-                module.pointer_size,
-            )
-            .and_then(|addr| process.read(addr))
+        process.read_through(
+            self.class + module.offsets.monoclass_name,
+            module.pointer_size,
+            0,
+        )
     }
 
     fn get_name_space<const N: usize>(
@@ -351,12 +592,11 @@ impl Class {
         process: &Process,
         module: &Module,
     ) -> Result<ArrayCString<N>, Error> {
-        process
-            .read_pointer(
-                self.class + module.offsets.monoclass_name_space,
-                module.pointer_size,
-            )
-            .and_then(|addr| process.read(addr))
+        process.read_through(
+            self.class + module.offsets.monoclass_name_space,
+            module.pointer_size,
+            0,
+        )
     }
 
     fn fields<'a>(
@@ -423,6 +663,45 @@ impl Class {
             .and_then(|field| field.get_offset(process, module))
     }
 
+    /// Iterates over the names and offsets of all the instance fields of the
+    /// class, including the ones inherited from its parent classes. Static
+    /// fields are skipped. This is meant for figuring out the layout of a
+    /// class, for example by printing it with
+    /// [`print_limited`](crate::print_limited). Names longer than `N` bytes
+    /// are skipped.
+    pub fn dump_fields<'a, const N: usize>(
+        &'a self,
+        process: &'a Process,
+        module: &'a Module,
+    ) -> impl Iterator<Item = (ArrayCString<N>, u32)> + 'a {
+        self.fields(process, module)
+            .filter(|field| field.is_static(process, module) == Some(false))
+            .filter_map(|field| {
+                Some((
+                    field.get_name(process, module).ok()?,
+                    field.get_offset(process, module)?,
+                ))
+            })
+    }
+
+    /// Tries to find the type of a field with the specified name in the class.
+    /// This is mostly useful when figuring out the layout of a class, as the
+    /// type determines how the field needs to be read.
+    pub fn get_field_type(
+        &self,
+        process: &Process,
+        module: &Module,
+        field_name: &str,
+    ) -> Option<FieldType> {
+        self.fields(process, module)
+            .find(|field| {
+                field
+                    .get_name::<CSTR>(process, module)
+                    .is_ok_and(|name| name.matches(field_name))
+            })
+            .and_then(|field| field.get_type(process, module))
+    }
+
     /// Tries to find the address of a static instance of the class based on its
     /// field name. This waits until the field is not null.
     pub async fn wait_get_static_instance(
@@ -471,6 +750,115 @@ impl Class {
             .map(|class| Class { class })
     }
 
+    /// Iterates over the type arguments of a generic class instantiation, such
+    /// as `int` for a `List<int>`, or `string` and `float` for a
+    /// `Dictionary<string, float>`. If the class is not an instantiation of a
+    /// generic class, the iterator is empty. Type arguments that can't be
+    /// resolved to a class, such as arrays, pointers or classes that have not
+    /// been initialized by the game yet, are skipped.
+    pub fn get_generic_args<'a>(
+        &'a self,
+        process: &'a Process,
+        module: &'a Module,
+    ) -> impl FusedIterator<Item = Class> + 'a {
+        // Il2CppGenericClass.context.class_inst
+        let class_inst = process
+            .read_pointer(
+                self.class + module.offsets.monoclass_generic_class,
+                module.pointer_size,
+            )
+            .ok()
+            .filter(|val| !val.is_null())
+            .and_then(|generic_class| {
+                process
+                    .read_pointer(generic_class + module.size_of_ptr(), module.pointer_size)
+                    .ok()
+            })
+            .filter(|val| !val.is_null());
+
+        let type_argc = class_inst
+            .and_then(|class_inst| process.read::<u32>(class_inst).ok())
+            .unwrap_or_default();
+
+        let type_argv = class_inst.and_then(|class_inst| {
+            process
+                .read_pointer(class_inst + module.size_of_ptr(), module.pointer_size)
+                .ok()
+        });
+
+        (0..type_argc as u64)
+            .filter_map(move |i| {
+                let il2cpp_type = process
+                    .read_pointer(
+                        type_argv? + i.wrapping_mul(module.size_of_ptr()),
+                        module.pointer_size,
+                    )
+                    .ok()?;
+                Class::from_type(process, module, il2cpp_type)
+            })
+            .fuse()
+    }
+
+    fn from_type(process: &Process, module: &Module, il2cpp_type: Address) -> Option<Class> {
+        let data = process
+            .read_pointer(il2cpp_type, module.pointer_size)
+            .ok()?
+            .value();
+
+        // Il2CppType.type is placed right after the 16 bit wide attrs bitfield
+        let kind = process
+            .read::<u8>(il2cpp_type + module.size_of_ptr() + 0x2)
+            .ok()?;
+
+        match kind {
+            IL2CPP_TYPE_VALUETYPE | IL2CPP_TYPE_CLASS => match module.version {
+                // The type refers to the definition of the class, which needs
+                // to be matched against the definitions of the loaded classes.
+                Version::V2020 => module
+                    .assemblies(process)
+                    .filter_map(|assembly| assembly.get_image(process, module))
+                    .find_map(|image| {
+                        image.classes(process, module).find(|class| {
+                            process
+                                .read_pointer(
+                                    class.class + module.offsets.monoclass_type_definition,
+                                    module.pointer_size,
+                                )
+                                .is_ok_and(|val| val.value() == data)
+                        })
+                    }),
+                // The type refers to the index of the class in the type info
+                // definition table.
+                _ => process
+                    .read_pointer(
+                        module.type_info_definition_table
+                            + (data as i32 as u64).wrapping_mul(module.size_of_ptr()),
+                        module.pointer_size,
+                    )
+                    .ok()
+                    .filter(|val| !val.is_null())
+                    .map(|class| Class { class }),
+            },
+            // Il2CppGenericClass.cached_class
+            IL2CPP_TYPE_GENERICINST => process
+                .read_pointer(
+                    Address::new(data) + module.size_of_ptr().wrapping_mul(3),
+                    module.pointer_size,
+                )
+                .ok()
+                .filter(|val| !val.is_null())
+                .map(|class| Class { class }),
+            _ => {
+                // The classes of builtin types need to be looked up in the core
+                // library.
+                let name = FieldType::from_raw(kind)?.core_class_name()?;
+                module
+                    .get_image(process, "mscorlib")?
+                    .get_class(process, module, name)
+            }
+        }
+    }
+
     /// Tries to find a field with the specified name in the class. This returns
     /// the offset of the field from the start of an instance of the class. If
     /// it's a static field, the offset will be from the start of the static
@@ -510,12 +898,11 @@ impl Field {
         process: &Process,
         module: &Module,
     ) -> Result<ArrayCString<N>, Error> {
-        process
-            .read_pointer(
-                self.field + module.offsets.monoclassfield_name,
-                module.pointer_size,
-            )
-            .and_then(|addr| process.read(addr))
+        process.read_through(
+            self.field + module.offsets.monoclassfield_name,
+            module.pointer_size,
+            0,
+        )
     }
 
     fn get_offset(&self, process: &Process, module: &Module) -> Option<u32> {
@@ -523,6 +910,27 @@ impl Field {
             .read(self.field + module.offsets.monoclassfield_offset)
             .ok()
     }
+
+    fn get_type_ptr(&self, process: &Process, module: &Module) -> Option<Address> {
+        process
+            .read_pointer(
+                self.field + module.offsets.monoclassfield_type,
+                module.pointer_size,
+            )
+            .ok()
+    }
+
+    fn get_type(&self, process: &Process, module: &Module) -> Option<FieldType> {
+        let ty = self.get_type_ptr(process, module)?;
+        // The type is placed right after the 16 bit wide attrs bitfield
+        FieldType::from_raw(process.read(ty + module.size_of_ptr() + 0x2).ok()?)
+    }
+
+    fn is_static(&self, process: &Process, module: &Module) -> Option<bool> {
+        let ty = self.get_type_ptr(process, module)?;
+        let attrs = process.read::<u16>(ty + module.size_of_ptr()).ok()?;
+        Some(attrs & FIELD_ATTRIBUTE_STATIC != 0)
+    }
 }
 
 /// An IL2CPP-specific implementation for automatic pointer path resolution
@@ -543,6 +951,20 @@ struct UnityPointerCache<const CAP: usize> {
     starting_class: Option<Class>,
 }
 
+impl<const CAP: usize> UnityPointerCache<CAP> {
+    const fn is_resolved(&self, depth: usize) -> bool {
+        self.resolved_offsets == depth
+    }
+
+    /// Forgets everything but the starting class, as class definitions don't
+    /// move while the game is running.
+    const fn invalidate(&mut self) {
+        self.base_address = Address::NULL;
+        self.offsets = [0; CAP];
+        self.resolved_offsets = 0;
+    }
+}
+
 impl<const CAP: usize> UnityPointer<CAP> {
     /// Creates a new instance of the Pointer struct
     ///
@@ -572,12 +994,26 @@ impl<const CAP: usize> UnityPointer<CAP> {
         }
     }
 
+    /// Creates a new instance of the Pointer struct, returning [`None`] if
+    /// more fields are provided than `CAP` can hold. Unlike
+    /// [`new`](Self::new), this never silently truncates the pointer path.
+    pub fn try_new(
+        class_name: &'static str,
+        nr_of_parents: usize,
+        fields: &[&'static str],
+    ) -> Option<Self> {
+        if fields.len() > CAP {
+            return None;
+        }
+        Some(Self::new(class_name, nr_of_parents, fields))
+    }
+
     /// Tries to resolve the pointer path for the `IL2CPP` class specified
     fn find_offsets(&self, process: &Process, module: &Module, image: &Image) -> Result<(), Error> {
         let mut cache = self.cache.borrow_mut();
 
         // If the pointer path has already been found, there's no need to continue
-        if cache.resolved_offsets == self.depth {
+        if cache.is_resolved(self.depth) {
             return Ok(());
         }
 
@@ -668,6 +1104,21 @@ impl<const CAP: usize> UnityPointer<CAP> {
         Ok(())
     }
 
+    /// Returns [`true`] if all the offsets of the pointer path have been
+    /// resolved and are currently cached.
+    pub fn is_resolved(&self) -> bool {
+        self.cache.borrow().is_resolved(self.depth)
+    }
+
+    /// Clears the cached offsets and the cached address of the static table,
+    /// forcing the pointer path to be resolved again the next time it gets
+    /// dereferenced. This is useful if the objects the path goes through may
+    /// have been replaced, for example after a scene reload. The starting class
+    /// is kept, as class definitions don't move while the game is running.
+    pub fn invalidate(&self) {
+        self.cache.borrow_mut().invalidate();
+    }
+
     /// Dereferences the pointer path, returning the memory address of the value of interest
     pub fn deref_offsets(
         &self,
@@ -694,6 +1145,20 @@ impl<const CAP: usize> UnityPointer<CAP> {
     ) -> Result<T, Error> {
         process.read(self.deref_offsets(process, module, image)?)
     }
+
+    /// Dereferences the pointer path, filling the slice provided with the
+    /// contiguous values stored at the final memory address, such as the
+    /// elements of an inventory array. Just like [`deref`](Self::deref), this
+    /// makes use of the cached offsets.
+    pub fn deref_into_slice<T: AnyBitPattern>(
+        &self,
+        process: &Process,
+        module: &Module,
+        image: &Image,
+        out: &mut [T],
+    ) -> Result<(), Error> {
+        process.read_into_slice(self.deref_offsets(process, module, image)?, out)
+    }
 }
 
 struct Offsets {
@@ -708,9 +1173,12 @@ struct Offsets {
     monoclass_field_count: u16,
     monoclass_static_fields: u8,
     monoclass_parent: u8,
+    monoclass_generic_class: u8,
+    monoclass_type_definition: u8,
     monoclassfield_structsize: u8,
     monoclassfield_name: u8,
     monoclassfield_offset: u8,
+    monoclassfield_type: u8,
 }
 
 impl Offsets {
@@ -730,9 +1198,12 @@ impl Offsets {
                         monoclass_field_count: 0x114,
                         monoclass_static_fields: 0xB8,
                         monoclass_parent: 0x58,
+                        monoclass_generic_class: 0x60,
+                        monoclass_type_definition: 0x68,
                         monoclassfield_structsize: 0x20,
                         monoclassfield_name: 0x0,
                         monoclassfield_offset: 0x18,
+                        monoclassfield_type: 0x8,
                     },
                     Version::V2019 => &Self {
                         monoassembly_image: 0x0,
@@ -746,9 +1217,12 @@ impl Offsets {
                         monoclass_field_count: 0x11C,
                         monoclass_static_fields: 0xB8,
                         monoclass_parent: 0x58,
+                        monoclass_generic_class: 0x60,
+                        monoclass_type_definition: 0x68,
                         monoclassfield_structsize: 0x20,
                         monoclassfield_name: 0x0,
                         monoclassfield_offset: 0x18,
+                        monoclassfield_type: 0x8,
                     },
                     Version::V2020 => &Self {
                         monoassembly_image: 0x0,
@@ -762,9 +1236,12 @@ impl Offsets {
                         monoclass_field_count: 0x120,
                         monoclass_static_fields: 0xB8,
                         monoclass_parent: 0x58,
+                        monoclass_generic_class: 0x60,
+                        monoclass_type_definition: 0x68,
                         monoclassfield_structsize: 0x20,
                         monoclassfield_name: 0x0,
                         monoclassfield_offset: 0x18,
+                        monoclassfield_type: 0x8,
                     },
                 })
             }
@@ -786,20 +1263,15 @@ pub enum Version {
 }
 
 fn detect_version(process: &Process) -> Option<Version> {
-    let unity_module = {
-        let address = process.get_module_address("UnityPlayer.dll").ok()?;
-        let size = pe::read_size_of_image(process, address)? as u64;
-        (address, size)
-    };
+    let unity_module = version::unity_player_range(process)?;
 
     if pe::MachineType::read(process, unity_module.0)? == pe::MachineType::X86 {
         return Some(Version::Base);
     }
 
-    const SIG_202X: Signature<6> = Signature::new("00 32 30 32 ?? 2E");
-    const SIG_2019: Signature<6> = Signature::new("00 32 30 31 39 2E");
+    let unity = version::scan_unity_release(process, unity_module).map(|(year, _)| year);
 
-    if SIG_202X.scan_process_range(process, unity_module).is_some() {
+    if matches!(unity, Some(2020..)) {
         let il2cpp_version = {
             const SIG: Signature<14> = Signature::new("48 2B ?? 48 2B ?? ?? ?? ?? ?? 48 F7 ?? 48");
             let address = process.get_module_address("GameAssembly.dll").ok()?;
@@ -819,7 +1291,7 @@ fn detect_version(process: &Process) -> Option<Version> {
         } else {
             Version::V2019
         })
-    } else if SIG_2019.scan_process_range(process, unity_module).is_some() {
+    } else if unity == Some(2019) {
         Some(Version::V2019)
     } else {
         Some(Version::Base)
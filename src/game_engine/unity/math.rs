@@ -0,0 +1,124 @@
+use bytemuck::{Pod, Zeroable};
+
+macro_rules! define_vector {
+    ($(#[$attr:meta])* $name:ident, $n:literal, $($field:ident),+) => {
+        $(#[$attr])*
+        #[derive(Copy, Clone, Debug, Default, PartialEq, Pod, Zeroable)]
+        #[repr(C)]
+        pub struct $name {
+            $(
+                #[doc = concat!("The `", stringify!($field), "` component.")]
+                pub $field: f32,
+            )+
+        }
+
+        impl $name {
+            /// Creates a new value from its components.
+            #[inline]
+            pub const fn new($($field: f32),+) -> Self {
+                Self { $($field),+ }
+            }
+
+            /// Returns the components as an array.
+            #[inline]
+            pub const fn to_array(self) -> [f32; $n] {
+                [$(self.$field),+]
+            }
+        }
+
+        impl From<[f32; $n]> for $name {
+            #[inline]
+            fn from([$($field),+]: [f32; $n]) -> Self {
+                Self { $($field),+ }
+            }
+        }
+
+        impl From<$name> for [f32; $n] {
+            #[inline]
+            fn from(value: $name) -> Self {
+                value.to_array()
+            }
+        }
+    };
+}
+
+define_vector!(
+    /// A `UnityEngine.Vector2`, usually a position or direction in 2D space.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use asr::{game_engine::unity::Vector2, Address, Process};
+    /// # fn example(process: &Process, instance: Address, position_offset: u32) {
+    /// if let Ok(position) = process.read::<Vector2>(instance + position_offset) {
+    ///     let _ = (position.x, position.y);
+    /// }
+    /// # }
+    /// ```
+    Vector2,
+    2,
+    x,
+    y
+);
+
+define_vector!(
+    /// A `UnityEngine.Vector3`, usually a position or direction in 3D space.
+    /// It is stored as three contiguous floats, so it can be read with
+    /// [`Process::read`](crate::Process::read) from the offset of a field of
+    /// this type.
+    Vector3,
+    3,
+    x,
+    y,
+    z
+);
+
+define_vector!(
+    /// A `UnityEngine.Vector4`.
+    Vector4,
+    4,
+    x,
+    y,
+    z,
+    w
+);
+
+define_vector!(
+    /// A `UnityEngine.Quaternion`, which represents a rotation. Unity stores
+    /// the imaginary components first, followed by the real component `w`.
+    Quaternion,
+    4,
+    x,
+    y,
+    z,
+    w
+);
+
+impl Quaternion {
+    /// The quaternion that doesn't apply any rotation.
+    pub const IDENTITY: Self = Self::new(0.0, 0.0, 0.0, 1.0);
+
+    /// Rotates the vector provided by the rotation this quaternion represents.
+    /// The quaternion is expected to be normalized, which all the rotations
+    /// stored by Unity are.
+    pub fn rotate(self, Vector3 { x, y, z }: Vector3) -> Vector3 {
+        let Self {
+            x: qx,
+            y: qy,
+            z: qz,
+            w: qw,
+        } = self;
+
+        // t = 2 * cross(q.xyz, v)
+        let tx = 2.0 * (qy * z - qz * y);
+        let ty = 2.0 * (qz * x - qx * z);
+        let tz = 2.0 * (qx * y - qy * x);
+
+        // v + w * t + cross(q.xyz, t)
+        Vector3::new(
+            x + qw * tx + (qy * tz - qz * ty),
+            y + qw * ty + (qz * tx - qx * tz),
+            z + qw * tz + (qx * ty - qy * tx),
+        )
+    }
+}
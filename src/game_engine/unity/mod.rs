@@ -77,6 +77,11 @@
 //!
 //! If only static fields are present, the `read` method does not take an
 //! instance argument.
+//!
+//! If the auto splitter needs to support games using either backend, the
+//! [`Module`] at the top level of this module detects the backend with
+//! [`detect_backend`] and dispatches to the [`mono`] or [`il2cpp`] module
+//! accordingly.
 
 // References:
 // https://github.com/just-ero/asl-help/tree/4c87822df0125b027d1af75e8e348c485817592d/src/Unity
@@ -86,5 +91,9 @@
 pub mod il2cpp;
 pub mod mono;
 
+mod backend;
+mod field_type;
+mod math;
 mod scene;
-pub use self::scene::*;
+mod version;
+pub use self::{backend::*, field_type::*, math::*, scene::*, version::*};
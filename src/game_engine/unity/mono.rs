@@ -2,21 +2,34 @@
 //! backend.
 
 use crate::{
-    file_format::pe, future::retry, signature::Signature, string::ArrayCString, Address, Address32,
-    Address64, Error, PointerSize, Process,
+    file_format::{self, pe},
+    future::retry,
+    game_engine::unity::{version, FieldType},
+    signature::Signature,
+    string::ArrayCString,
+    Address, Address32, Address64, Error, PointerSize, Process,
 };
 use core::{
     array,
     cell::RefCell,
     iter::{self, FusedIterator},
+    mem::{self, MaybeUninit},
 };
 
 #[cfg(feature = "derive")]
 pub use asr_derive::MonoClass as Class;
-use bytemuck::CheckedBitPattern;
+use bytemuck::{AnyBitPattern, CheckedBitPattern, Zeroable};
 
 const CSTR: usize = 128;
 
+const MONO_CLASS_GINST: u8 = 3;
+
+const MONO_TYPE_VALUETYPE: u8 = 0x11;
+const MONO_TYPE_CLASS: u8 = 0x12;
+const MONO_TYPE_GENERICINST: u8 = 0x15;
+
+const FIELD_ATTRIBUTE_STATIC: u16 = 0x10;
+
 /// Represents access to a Unity game that is using the standard Mono backend.
 pub struct Module {
     pointer_size: PointerSize,
@@ -40,14 +53,16 @@ impl Module {
     /// correct for this function to work. If you don't know the version in
     /// advance, use [`attach_auto_detect`](Self::attach_auto_detect) instead.
     pub fn attach(process: &Process, version: Version) -> Option<Self> {
-        let module = ["mono.dll", "mono-2.0-bdwgc.dll"]
+        let module = ["mono.dll", "mono-2.0-bdwgc.dll", "mono-2.0-sgen.dll"]
             .iter()
             .find_map(|&name| process.get_module_address(name).ok())?;
 
-        let pointer_size = match pe::MachineType::read(process, module)? {
-            pe::MachineType::X86_64 => PointerSize::Bit64,
-            _ => PointerSize::Bit32,
-        };
+        // The symbols are looked up in the export table of the Windows builds.
+        let info = file_format::read_binary_info(process, module)?;
+        if info.format != file_format::BinaryFormat::PE {
+            return None;
+        }
+        let pointer_size = info.pointer_size?;
 
         let offsets = Offsets::new(version, pointer_size)?;
 
@@ -88,6 +103,22 @@ impl Module {
         })
     }
 
+    /// Tries attaching to a Unity game that is using the standard Mono backend
+    /// with each of the [Mono versions](Version) provided, in order. A
+    /// version is only considered correct if the `Assembly-CSharp`
+    /// [image](Image) can be resolved with it, as attaching with the wrong
+    /// version may succeed but produce a module that can't find anything.
+    /// Returns the first module that passes this check. This is useful if
+    /// [`attach_auto_detect`](Self::attach_auto_detect) fails for a game, but
+    /// the version is known to be one of a few candidates.
+    pub fn attach_any(process: &Process, versions: &[Version]) -> Option<Self> {
+        versions.iter().find_map(|&version| {
+            let module = Self::attach(process, version)?;
+            module.get_default_image(process)?;
+            Some(module)
+        })
+    }
+
     fn assemblies<'a>(&'a self, process: &'a Process) -> impl FusedIterator<Item = Assembly> + 'a {
         let mut assembly = process
             .read_pointer(self.assemblies, self.pointer_size)
@@ -129,6 +160,39 @@ impl Module {
             .and_then(|assembly| assembly.get_image(process, self))
     }
 
+    /// Checks whether an assembly with the specified name is loaded by the
+    /// game, without resolving its [image](Image). This is useful for
+    /// detecting the version of a game based on the assemblies it ships with.
+    pub fn has_image(&self, process: &Process, assembly_name: &str) -> bool {
+        self.assemblies(process).any(|assembly| {
+            assembly
+                .get_name::<CSTR>(process, self)
+                .is_ok_and(|name| name.matches(assembly_name))
+        })
+    }
+
+    /// Looks for the specified [.NET class](struct@Class) in all the
+    /// [images](Image) loaded by the game, in the order the assemblies were
+    /// loaded in. This helps with games that split their code across
+    /// multiple assemblies, such as `Assembly-CSharp` and
+    /// `Assembly-CSharp-firstpass`, when it's not known which one contains
+    /// the class. The [image](Image) the class was found in is returned as
+    /// well, so any further classes of it can be looked up directly.
+    ///
+    /// All the classes of every image are searched until the class is found,
+    /// so this is a lot slower than [`Image::get_class`]. If multiple images
+    /// contain a class with the same name, the first one is returned.
+    pub fn find_class_in_any_image(
+        &self,
+        process: &Process,
+        class_name: &str,
+    ) -> Option<(Image, Class)> {
+        self.assemblies(process).find_map(|assembly| {
+            let image = assembly.get_image(process, self)?;
+            Some((image, image.get_class(process, self, class_name)?))
+        })
+    }
+
     /// Looks for the `Assembly-CSharp` binary [image](Image) inside the target
     /// process. An [image](Image) is a .NET DLL that is loaded
     /// by the game. The `Assembly-CSharp` [image](Image) is the main
@@ -139,6 +203,141 @@ impl Module {
         self.get_image(process, "Assembly-CSharp")
     }
 
+    /// Reads the contents of a .NET `List<T>` located at the address provided
+    /// into the buffer provided, which does not need to be initialized. If
+    /// the list contains more elements than fit into the buffer, only the
+    /// first `out.len()` elements are read. Returns the elements that were
+    /// read.
+    ///
+    /// The elements are read directly from the backing array of the list, so
+    /// `T` needs to match the layout of the element type. For lists of
+    /// reference types, such as `List<string>`, `T` needs to be a pointer
+    /// like [`Address64`] or [`Address32`] that matches the pointer size of
+    /// the game.
+    pub fn read_list<'out, T: CheckedBitPattern>(
+        &self,
+        process: &Process,
+        list: Address,
+        out: &'out mut [MaybeUninit<T>],
+    ) -> Result<&'out mut [T], Error> {
+        let (items_offset, size_offset) = list_offsets(self.version, self.pointer_size);
+        let items = process.read_pointer(list + items_offset, self.pointer_size)?;
+        let size = process.read::<i32>(list + size_offset)?;
+
+        if items.is_null() {
+            return Err(Error {});
+        }
+
+        let len = (size.max(0) as usize).min(out.len());
+
+        // MonoArray.vector comes after the object header, the bounds pointer
+        // and the length of the array
+        let vector = items + self.size_of_ptr().wrapping_mul(4);
+
+        process.read_into_uninit_slice(vector, &mut out[..len])
+    }
+
+    /// Reads the entries of a .NET `Dictionary<TKey, TValue>` located at the
+    /// address provided into the buffer provided, which does not need to be
+    /// initialized. If the dictionary has more entries than fit into the
+    /// buffer, only the first `out.len()` entries are read. Removed entries
+    /// are skipped, so fewer entries than that may be returned. The entries
+    /// are in the order they are stored in, which is usually the order they
+    /// were added in, unless entries got removed.
+    ///
+    /// Just like with [`read_list`](Self::read_list), `K` and `V` need to
+    /// match the layout of the key and value types. For reference types, they
+    /// need to be a pointer like [`Address64`] or [`Address32`] that matches
+    /// the pointer size of the game. If any of the keys or values read is not
+    /// a valid bit pattern for its type, such as a `bool` that is neither `0`
+    /// nor `1`, an error is returned.
+    ///
+    /// Only the dictionary layout of the newer versions of Mono is supported,
+    /// so this returns an error for [`Version::V1`] and
+    /// [`Version::V1Cattrs`].
+    pub fn read_dictionary<'out, K: CheckedBitPattern, V: CheckedBitPattern>(
+        &self,
+        process: &Process,
+        dictionary: Address,
+        out: &'out mut [MaybeUninit<DictionaryEntry<K, V>>],
+    ) -> Result<&'out mut [DictionaryEntry<K, V>], Error> {
+        // The old Mono runtime ships its own implementation of the dictionary,
+        // which stores the keys and values in separate arrays.
+        if matches!(self.version, Version::V1 | Version::V1Cattrs) {
+            return Err(Error {});
+        }
+
+        // The reference fields of the dictionary come first (buckets,
+        // entries, comparer, keys, values and the sync root), followed by
+        // the count of used entries, which includes the removed ones.
+        let entries = process.read_pointer(
+            dictionary + self.size_of_ptr().wrapping_mul(3),
+            self.pointer_size,
+        )?;
+        let count = process.read::<i32>(dictionary + self.size_of_ptr().wrapping_mul(8))?;
+
+        if entries.is_null() {
+            return Err(Error {});
+        }
+
+        let len = (count.max(0) as usize).min(out.len());
+
+        let vector = entries + self.size_of_ptr().wrapping_mul(4);
+        let entries = process.read_into_uninit_slice(vector, &mut out[..len])?;
+
+        // Removed entries have their hash code set to -1, so we move all the
+        // other entries to the front.
+        let mut len = 0;
+        for i in 0..entries.len() {
+            if entries[i].hash_code >= 0 {
+                entries[len] = entries[i];
+                len += 1;
+            }
+        }
+
+        Ok(&mut entries[..len])
+    }
+
+    /// Reads a .NET `Nullable<T>` (`T?`) that is stored at the address
+    /// provided, such as a field of that type. A nullable value type is
+    /// stored inline as a `HasValue` flag followed by the value, which is
+    /// aligned to the alignment of `T`. Reading it as the raw value instead
+    /// would mix the flag into the value. Returns [`None`] if the nullable
+    /// doesn't have a value. `T` needs to match the layout of the value type.
+    ///
+    /// Fields of such a type are reported as [`FieldType::GenericInst`] by
+    /// [`Class::get_field_type`].
+    pub fn read_nullable<T: CheckedBitPattern>(
+        &self,
+        process: &Process,
+        address: Address,
+    ) -> Result<Option<T>, Error> {
+        if !process.read::<bool>(address)? {
+            return Ok(None);
+        }
+        let value_offset = 1u64.next_multiple_of(mem::align_of::<T>() as u64);
+        process.read(address + value_offset).map(Some)
+    }
+
+    /// Reads a value type that is boxed into the Mono object located at the
+    /// address provided. This is how value types are stored in fields of
+    /// type `object` or of an interface type. The value is stored right after
+    /// the object header. `T` needs to match the layout of the value type.
+    ///
+    /// Fields of type `object` are reported as [`FieldType::Object`] by
+    /// [`Class::get_field_type`]. Such a field itself is a pointer to the box.
+    pub fn read_boxed<T: CheckedBitPattern>(
+        &self,
+        process: &Process,
+        object: Address,
+    ) -> Result<T, Error> {
+        if object.is_null() {
+            return Err(Error {});
+        }
+        // The object header consists of the vtable and the monitor
+        process.read(object + self.size_of_ptr().wrapping_mul(2))
+    }
+
     /// Attaches to a Unity game that is using the standard Mono backend. This
     /// function automatically detects the [Mono version](Version). If you
     /// know the version in advance or it fails detecting it, use
@@ -162,6 +361,16 @@ impl Module {
         retry(|| Self::attach(process, version)).await
     }
 
+    /// Attaches to a Unity game that is using the standard Mono backend with the
+    /// first of the [Mono versions](Version) provided that is able to
+    /// resolve the `Assembly-CSharp` [image](Image).
+    ///
+    /// This is the `await`able version of the [`attach_any`](Self::attach_any)
+    /// function, yielding back to the runtime between each try.
+    pub async fn wait_attach_any(process: &Process, versions: &[Version]) -> Self {
+        retry(|| Self::attach_any(process, versions)).await
+    }
+
     /// Looks for the specified binary [image](Image) inside the target process.
     /// An [image](Image) is a .NET DLL that is loaded
     /// by the game. The `Assembly-CSharp` [image](Image) is the main game
@@ -207,12 +416,11 @@ impl Assembly {
         process: &Process,
         module: &Module,
     ) -> Result<ArrayCString<N>, Error> {
-        process
-            .read_pointer(
-                self.assembly + module.offsets.monoassembly_aname,
-                module.pointer_size,
-            )
-            .and_then(|addr| process.read(addr))
+        process.read_through(
+            self.assembly + module.offsets.monoassembly_aname,
+            module.pointer_size,
+            0,
+        )
     }
 
     fn get_image(&self, process: &Process, module: &Module) -> Option<Image> {
@@ -288,6 +496,17 @@ impl Image {
         })
     }
 
+    /// Checks whether the image defines a [.NET class](struct@Class) with the
+    /// specified name. This is useful for telling apart different versions or
+    /// patches of a game, based on classes that only exist in some of them.
+    pub fn has_class(&self, process: &Process, module: &Module, class_name: &str) -> bool {
+        self.classes(process, module).any(|class| {
+            class
+                .get_name::<CSTR>(process, module)
+                .is_ok_and(|name| name.matches(class_name))
+        })
+    }
+
     /// Tries to find the specified [.NET class](struct@Class) in the image.
     pub fn get_class(&self, process: &Process, module: &Module, class_name: &str) -> Option<Class> {
         self.classes(process, module).find(|class| {
@@ -322,12 +541,11 @@ impl Class {
         process: &Process,
         module: &Module,
     ) -> Result<ArrayCString<N>, Error> {
-        process
-            .read_pointer(
-                self.class + module.offsets.monoclassdef_klass + module.offsets.monoclass_name,
-                module.pointer_size,
-            )
-            .and_then(|addr| process.read(addr))
+        process.read_through(
+            self.class + module.offsets.monoclassdef_klass + module.offsets.monoclass_name,
+            module.pointer_size,
+            0,
+        )
     }
 
     fn get_name_space<const N: usize>(
@@ -335,14 +553,11 @@ impl Class {
         process: &Process,
         module: &Module,
     ) -> Result<ArrayCString<N>, Error> {
-        process
-            .read_pointer(
-                self.class
-                    + module.offsets.monoclassdef_klass
-                    + module.offsets.monoclass_name_space,
-                module.pointer_size,
-            )
-            .and_then(|addr| process.read(addr))
+        process.read_through(
+            self.class + module.offsets.monoclassdef_klass + module.offsets.monoclass_name_space,
+            module.pointer_size,
+            0,
+        )
     }
 
     fn fields<'a>(
@@ -414,6 +629,45 @@ impl Class {
             .and_then(|field| field.get_offset(process, module))
     }
 
+    /// Iterates over the names and offsets of all the instance fields of the
+    /// class, including the ones inherited from its parent classes. Static
+    /// fields are skipped. This is meant for figuring out the layout of a
+    /// class, for example by printing it with
+    /// [`print_limited`](crate::print_limited). Names longer than `N` bytes
+    /// are skipped.
+    pub fn dump_fields<'a, const N: usize>(
+        &'a self,
+        process: &'a Process,
+        module: &'a Module,
+    ) -> impl Iterator<Item = (ArrayCString<N>, u32)> + 'a {
+        self.fields(process, module)
+            .filter(|field| field.is_static(process, module) == Some(false))
+            .filter_map(|field| {
+                Some((
+                    field.get_name(process, module).ok()?,
+                    field.get_offset(process, module)?,
+                ))
+            })
+    }
+
+    /// Tries to find the type of a field with the specified name in the class.
+    /// This is mostly useful when figuring out the layout of a class, as the
+    /// type determines how the field needs to be read.
+    pub fn get_field_type(
+        &self,
+        process: &Process,
+        module: &Module,
+        field_name: &str,
+    ) -> Option<FieldType> {
+        self.fields(process, module)
+            .find(|field| {
+                field
+                    .get_name::<CSTR>(process, module)
+                    .is_ok_and(|name| name.matches(field_name))
+            })
+            .and_then(|field| field.get_type(process, module))
+    }
+
     /// Tries to find the address of a static instance of the class based on its
     /// field name. This waits until the field is not null.
     pub async fn wait_get_static_instance(
@@ -504,6 +758,96 @@ impl Class {
             .map(|class| Class { class })
     }
 
+    /// Iterates over the type arguments of a generic class instantiation, such
+    /// as `int` for a `List<int>`, or `string` and `float` for a
+    /// `Dictionary<string, float>`. If the class is not an instantiation of a
+    /// generic class, the iterator is empty. Type arguments that can't be
+    /// resolved to a class, such as arrays or pointers, are skipped.
+    pub fn get_generic_args<'a>(
+        &'a self,
+        process: &'a Process,
+        module: &'a Module,
+    ) -> impl FusedIterator<Item = Class> + 'a {
+        // Mono V1 doesn't have class kinds, but stores the generic class
+        // directly in the class, which is null for non-generic classes.
+        let is_generic_inst = match module.version {
+            Version::V1 | Version::V1Cattrs => true,
+            _ => process
+                .read::<u8>(self.class + module.offsets.monoclass_class_kind)
+                .is_ok_and(|kind| kind == MONO_CLASS_GINST),
+        };
+
+        let generic_class = is_generic_inst
+            .then(|| {
+                process
+                    .read_pointer(
+                        self.class + module.offsets.monoclass_generic_class,
+                        module.pointer_size,
+                    )
+                    .ok()
+            })
+            .flatten()
+            .filter(|val| !val.is_null());
+
+        // MonoGenericClass.context.class_inst
+        let class_inst = generic_class
+            .and_then(|generic_class| {
+                process
+                    .read_pointer(generic_class + module.size_of_ptr(), module.pointer_size)
+                    .ok()
+            })
+            .filter(|val| !val.is_null());
+
+        // MonoGenericInst.type_argc is a 22 bit wide bitfield
+        let type_argc = class_inst
+            .and_then(|class_inst| process.read::<u32>(class_inst + 0x4).ok())
+            .map(|val| val & 0x3F_FFFF)
+            .unwrap_or_default();
+
+        (0..type_argc as u64)
+            .filter_map(move |i| {
+                let type_argv = class_inst? + 0x8;
+                let mono_type = process
+                    .read_pointer(
+                        type_argv + i.wrapping_mul(module.size_of_ptr()),
+                        module.pointer_size,
+                    )
+                    .ok()?;
+                Class::from_type(process, module, mono_type)
+            })
+            .fuse()
+    }
+
+    fn from_type(process: &Process, module: &Module, mono_type: Address) -> Option<Class> {
+        let data = process.read_pointer(mono_type, module.pointer_size).ok()?;
+
+        // MonoType.type is placed right after the 16 bit wide attrs bitfield
+        let kind = process
+            .read::<u8>(mono_type + module.size_of_ptr() + 0x2)
+            .ok()?;
+
+        match kind {
+            MONO_TYPE_VALUETYPE | MONO_TYPE_CLASS => Some(data).filter(|val| !val.is_null()),
+            // MonoGenericClass.cached_class
+            MONO_TYPE_GENERICINST => process
+                .read_pointer(
+                    data + module.size_of_ptr().wrapping_mul(4),
+                    module.pointer_size,
+                )
+                .ok()
+                .filter(|val| !val.is_null()),
+            _ => {
+                // Builtin types don't point to their class, so they need to be
+                // looked up in the core library instead.
+                let name = FieldType::from_raw(kind)?.core_class_name()?;
+                return module
+                    .get_image(process, "mscorlib")?
+                    .get_class(process, module, name);
+            }
+        }
+        .map(|class| Class { class })
+    }
+
     /// Tries to find a field with the specified name in the class. This returns
     /// the offset of the field from the start of an instance of the class. If
     /// it's a static field, the offset will be from the start of the static
@@ -543,12 +887,11 @@ impl Field {
         process: &Process,
         module: &Module,
     ) -> Result<ArrayCString<N>, Error> {
-        process
-            .read_pointer(
-                self.field + module.offsets.monoclassfield_name,
-                module.pointer_size,
-            )
-            .and_then(|addr| process.read(addr))
+        process.read_through(
+            self.field + module.offsets.monoclassfield_name,
+            module.pointer_size,
+            0,
+        )
     }
 
     fn get_offset(&self, process: &Process, module: &Module) -> Option<u32> {
@@ -556,6 +899,27 @@ impl Field {
             .read(self.field + module.offsets.monoclassfield_offset)
             .ok()
     }
+
+    fn get_type_ptr(&self, process: &Process, module: &Module) -> Option<Address> {
+        process
+            .read_pointer(
+                self.field + module.offsets.monoclassfield_type,
+                module.pointer_size,
+            )
+            .ok()
+    }
+
+    fn get_type(&self, process: &Process, module: &Module) -> Option<FieldType> {
+        let ty = self.get_type_ptr(process, module)?;
+        // The type is placed right after the 16 bit wide attrs bitfield
+        FieldType::from_raw(process.read(ty + module.size_of_ptr() + 0x2).ok()?)
+    }
+
+    fn is_static(&self, process: &Process, module: &Module) -> Option<bool> {
+        let ty = self.get_type_ptr(process, module)?;
+        let attrs = process.read::<u16>(ty + module.size_of_ptr()).ok()?;
+        Some(attrs & FIELD_ATTRIBUTE_STATIC != 0)
+    }
 }
 
 /// A Mono-specific implementation for automatic pointer path resolution
@@ -576,6 +940,83 @@ struct UnityPointerCache<const CAP: usize> {
     starting_class: Option<Class>,
 }
 
+impl<const CAP: usize> UnityPointerCache<CAP> {
+    const fn is_resolved(&self, depth: usize) -> bool {
+        self.resolved_offsets == depth
+    }
+
+    /// Forgets everything but the starting class, as class definitions don't
+    /// move while the game is running.
+    const fn invalidate(&mut self) {
+        self.base_address = Address::NULL;
+        self.offsets = [0; CAP];
+        self.resolved_offsets = 0;
+    }
+
+    /// Resolves the offsets of the fields that aren't cached yet. The
+    /// functions provided look up the address of the static table of the
+    /// starting class, read a pointer, and look up the offset of a field in a
+    /// class by its name.
+    fn resolve_offsets(
+        &mut self,
+        fields: &[&str],
+        starting_class: Class,
+        static_table: impl FnOnce() -> Result<Address, Error>,
+        mut read_pointer: impl FnMut(Address) -> Result<Address, Error>,
+        mut field_offset: impl FnMut(Class, &str) -> Result<u64, Error>,
+    ) -> Result<(), Error> {
+        // Recovering the address of the static table is not very CPU intensive,
+        // but it might be worth caching it as well
+        if self.base_address.is_null() {
+            self.base_address = static_table()?;
+        };
+
+        // If we already resolved some offsets, we need to traverse them again starting from the base address
+        // of the static table in order to recalculate the address of the farthest object we can reach.
+        // If no offsets have been resolved yet, we just need to read the base address instead.
+        let mut current_object = {
+            let mut addr = self.base_address;
+            for &i in &self.offsets[..self.resolved_offsets] {
+                addr = read_pointer(addr + i)?;
+            }
+            addr
+        };
+
+        // We keep track of the already resolved offsets in order to skip resolving them again
+        for (i, &field) in fields.iter().enumerate().skip(self.resolved_offsets) {
+            let offset_from_string = match field.strip_prefix("0x") {
+                Some(rem) => u32::from_str_radix(rem, 16).ok(),
+                _ => field.parse().ok(),
+            };
+
+            let current_offset = match offset_from_string {
+                Some(offset) => offset as u64,
+                _ => {
+                    let current_class = match i {
+                        0 => starting_class,
+                        _ => read_pointer(current_object)
+                            .ok()
+                            .filter(|val| !val.is_null())
+                            .and_then(|addr| read_pointer(addr).ok())
+                            .filter(|val| !val.is_null())
+                            .map(|class| Class { class })
+                            .ok_or(Error {})?,
+                    };
+
+                    field_offset(current_class, field)?
+                }
+            };
+
+            self.offsets[i] = current_offset;
+            self.resolved_offsets += 1;
+
+            current_object = read_pointer(current_object + current_offset)?;
+        }
+
+        Ok(())
+    }
+}
+
 impl<const CAP: usize> UnityPointer<CAP> {
     /// Creates a new instance of the Pointer struct
     ///
@@ -605,12 +1046,26 @@ impl<const CAP: usize> UnityPointer<CAP> {
         }
     }
 
+    /// Creates a new instance of the Pointer struct, returning [`None`] if
+    /// more fields are provided than `CAP` can hold. Unlike
+    /// [`new`](Self::new), this never silently truncates the pointer path.
+    pub fn try_new(
+        class_name: &'static str,
+        nr_of_parents: usize,
+        fields: &[&'static str],
+    ) -> Option<Self> {
+        if fields.len() > CAP {
+            return None;
+        }
+        Some(Self::new(class_name, nr_of_parents, fields))
+    }
+
     /// Tries to resolve the pointer path for the `Mono` class specified
     fn find_offsets(&self, process: &Process, module: &Module, image: &Image) -> Result<(), Error> {
         let mut cache = self.cache.borrow_mut();
 
         // If the pointer path has already been found, there's no need to continue
-        if cache.resolved_offsets == self.depth {
+        if cache.is_resolved(self.depth) {
             return Ok(());
         }
 
@@ -636,71 +1091,44 @@ impl<const CAP: usize> UnityPointer<CAP> {
             }
         };
 
-        // Recovering the address of the static table is not very CPU intensive,
-        // but it might be worth caching it as well
-        if cache.base_address.is_null() {
-            cache.base_address = starting_class
-                .get_static_table(process, module)
-                .ok_or(Error {})?;
-        };
-
-        // If we already resolved some offsets, we need to traverse them again starting from the base address
-        // of the static table in order to recalculate the address of the farthest object we can reach.
-        // If no offsets have been resolved yet, we just need to read the base address instead.
-        let mut current_object = {
-            let mut addr = cache.base_address;
-            for &i in &cache.offsets[..cache.resolved_offsets] {
-                addr = process.read_pointer(addr + i, module.pointer_size)?;
-            }
-            addr
-        };
-
-        // We keep track of the already resolved offsets in order to skip resolving them again
-        for i in cache.resolved_offsets..self.depth {
-            let offset_from_string = match self.fields[i].strip_prefix("0x") {
-                Some(rem) => u32::from_str_radix(rem, 16).ok(),
-                _ => self.fields[i].parse().ok(),
-            };
-
-            let current_offset = match offset_from_string {
-                Some(offset) => offset as u64,
-                _ => {
-                    let current_class = match i {
-                        0 => starting_class,
-                        _ => process
-                            .read_pointer(current_object, module.pointer_size)
-                            .ok()
-                            .filter(|val| !val.is_null())
-                            .and_then(|addr| process.read_pointer(addr, module.pointer_size).ok())
-                            .filter(|val| !val.is_null())
-                            .map(|class| Class { class })
-                            .ok_or(Error {})?,
-                    };
-
-                    let val = current_class
-                        .fields(process, module)
-                        .find(|field| {
-                            field
-                                .get_name::<CSTR>(process, module)
-                                .is_ok_and(|name| name.matches(self.fields[i]))
-                        })
-                        .and_then(|val| val.get_offset(process, module))
-                        .ok_or(Error {})? as u64;
-
-                    // Explicitly allowing this clippy because of borrowing rules shenanigans
-                    #[allow(clippy::let_and_return)]
-                    val
-                }
-            };
-
-            cache.offsets[i] = current_offset;
-            cache.resolved_offsets += 1;
+        let pointer_size = module.pointer_size;
+        cache.resolve_offsets(
+            &self.fields[..self.depth],
+            starting_class,
+            || {
+                starting_class
+                    .get_static_table(process, module)
+                    .ok_or(Error {})
+            },
+            |address| process.read_pointer(address, pointer_size),
+            |class, name| {
+                class
+                    .fields(process, module)
+                    .find(|field| {
+                        field
+                            .get_name::<CSTR>(process, module)
+                            .is_ok_and(|field_name| field_name.matches(name))
+                    })
+                    .and_then(|field| field.get_offset(process, module))
+                    .map(|offset| offset as u64)
+                    .ok_or(Error {})
+            },
+        )
+    }
 
-            current_object =
-                process.read_pointer(current_object + current_offset, module.pointer_size)?;
-        }
+    /// Returns [`true`] if all the offsets of the pointer path have been
+    /// resolved and are currently cached.
+    pub fn is_resolved(&self) -> bool {
+        self.cache.borrow().is_resolved(self.depth)
+    }
 
-        Ok(())
+    /// Clears the cached offsets and the cached address of the static table,
+    /// forcing the pointer path to be resolved again the next time it gets
+    /// dereferenced. This is useful if the objects the path goes through may
+    /// have been replaced, for example after a scene reload. The starting class
+    /// is kept, as class definitions don't move while the game is running.
+    pub fn invalidate(&self) {
+        self.cache.borrow_mut().invalidate();
     }
 
     /// Dereferences the pointer path, returning the memory address of the value of interest
@@ -729,6 +1157,20 @@ impl<const CAP: usize> UnityPointer<CAP> {
     ) -> Result<T, Error> {
         process.read(self.deref_offsets(process, module, image)?)
     }
+
+    /// Dereferences the pointer path, filling the slice provided with the
+    /// contiguous values stored at the final memory address, such as the
+    /// elements of an inventory array. Just like [`deref`](Self::deref), this
+    /// makes use of the cached offsets.
+    pub fn deref_into_slice<T: AnyBitPattern>(
+        &self,
+        process: &Process,
+        module: &Module,
+        image: &Image,
+        out: &mut [T],
+    ) -> Result<(), Error> {
+        process.read_into_slice(self.deref_offsets(process, module, image)?, out)
+    }
 }
 
 struct Offsets {
@@ -746,8 +1188,11 @@ struct Offsets {
     monoclass_runtime_info: u16,
     monoclass_vtable_size: u8,
     monoclass_parent: u8,
+    monoclass_class_kind: u8,
+    monoclass_generic_class: u16,
     monoclassfield_name: u8,
     monoclassfield_offset: u8,
+    monoclassfield_type: u8,
     monoclassruntimeinfo_domain_vtables: u8,
     monovtable_vtable: u8,
     monoclassfieldalignment: u8,
@@ -772,8 +1217,11 @@ impl Offsets {
                     monoclass_runtime_info: 0xF8,
                     monoclass_vtable_size: 0x18, // MonoVtable.data
                     monoclass_parent: 0x30,
+                    monoclass_class_kind: 0x0, // Not present in V1
+                    monoclass_generic_class: 0xD8,
                     monoclassfield_name: 0x8,
                     monoclassfield_offset: 0x18,
+                    monoclassfield_type: 0x0,
                     monoclassruntimeinfo_domain_vtables: 0x8,
                     monovtable_vtable: 0x48,
                     monoclassfieldalignment: 0x20,
@@ -793,8 +1241,11 @@ impl Offsets {
                     monoclass_runtime_info: 0x100,
                     monoclass_vtable_size: 0x18, // MonoVtable.data
                     monoclass_parent: 0x30,
+                    monoclass_class_kind: 0x0, // Not present in V1
+                    monoclass_generic_class: 0xE0,
                     monoclassfield_name: 0x8,
                     monoclassfield_offset: 0x18,
+                    monoclassfield_type: 0x0,
                     monoclassruntimeinfo_domain_vtables: 0x8,
                     monovtable_vtable: 0x48,
                     monoclassfieldalignment: 0x20,
@@ -814,8 +1265,11 @@ impl Offsets {
                     monoclass_runtime_info: 0xD0,
                     monoclass_vtable_size: 0x5C,
                     monoclass_parent: 0x30,
+                    monoclass_class_kind: 0x1B,
+                    monoclass_generic_class: 0xF0,
                     monoclassfield_name: 0x8,
                     monoclassfield_offset: 0x18,
+                    monoclassfield_type: 0x0,
                     monoclassruntimeinfo_domain_vtables: 0x8,
                     monovtable_vtable: 0x40,
                     monoclassfieldalignment: 0x20,
@@ -835,8 +1289,11 @@ impl Offsets {
                     monoclass_runtime_info: 0xD0,
                     monoclass_vtable_size: 0x5C,
                     monoclass_parent: 0x30,
+                    monoclass_class_kind: 0x1B,
+                    monoclass_generic_class: 0xF0,
                     monoclassfield_name: 0x8,
                     monoclassfield_offset: 0x18,
+                    monoclassfield_type: 0x0,
                     monoclassruntimeinfo_domain_vtables: 0x8,
                     monovtable_vtable: 0x48,
                     monoclassfieldalignment: 0x20,
@@ -858,8 +1315,11 @@ impl Offsets {
                     monoclass_runtime_info: 0xA4,
                     monoclass_vtable_size: 0xC, // MonoVtable.data
                     monoclass_parent: 0x24,
+                    monoclass_class_kind: 0x0, // Not present in V1
+                    monoclass_generic_class: 0x8C,
                     monoclassfield_name: 0x4,
                     monoclassfield_offset: 0xC,
+                    monoclassfield_type: 0x0,
                     monoclassruntimeinfo_domain_vtables: 0x4,
                     monovtable_vtable: 0x28,
                     monoclassfieldalignment: 0x10,
@@ -879,8 +1339,11 @@ impl Offsets {
                     monoclass_runtime_info: 0xA8,
                     monoclass_vtable_size: 0xC, // MonoVtable.data
                     monoclass_parent: 0x24,
+                    monoclass_class_kind: 0x0, // Not present in V1
+                    monoclass_generic_class: 0x90,
                     monoclassfield_name: 0x4,
                     monoclassfield_offset: 0xC,
+                    monoclassfield_type: 0x0,
                     monoclassruntimeinfo_domain_vtables: 0x4,
                     monovtable_vtable: 0x28,
                     monoclassfieldalignment: 0x10,
@@ -900,8 +1363,11 @@ impl Offsets {
                     monoclass_runtime_info: 0x84,
                     monoclass_vtable_size: 0x38,
                     monoclass_parent: 0x20,
+                    monoclass_class_kind: 0xF,
+                    monoclass_generic_class: 0x94,
                     monoclassfield_name: 0x4,
                     monoclassfield_offset: 0xC,
+                    monoclassfield_type: 0x0,
                     monoclassruntimeinfo_domain_vtables: 0x4,
                     monovtable_vtable: 0x28,
                     monoclassfieldalignment: 0x10,
@@ -921,8 +1387,11 @@ impl Offsets {
                     monoclass_runtime_info: 0x7C,
                     monoclass_vtable_size: 0x38,
                     monoclass_parent: 0x20,
+                    monoclass_class_kind: 0xF,
+                    monoclass_generic_class: 0x8C,
                     monoclassfield_name: 0x4,
                     monoclassfield_offset: 0xC,
+                    monoclassfield_type: 0x0,
                     monoclassruntimeinfo_domain_vtables: 0x4,
                     monovtable_vtable: 0x2C,
                     monoclassfieldalignment: 0x10,
@@ -933,6 +1402,69 @@ impl Offsets {
     }
 }
 
+/// Returns the offsets of `List<T>._items` and `List<T>._size`. Mono lays out
+/// the reference fields of a class first, so `_items` comes right after the
+/// object header. The class library of the old Mono runtime only has `_size`
+/// and `_version` besides it, while the reference source based one of the
+/// newer versions also has the `_syncRoot` reference, which comes before
+/// `_size`.
+const fn list_offsets(version: Version, pointer_size: PointerSize) -> (u64, u64) {
+    let size_of_ptr = pointer_size as u64;
+    match version {
+        Version::V1 | Version::V1Cattrs => (2 * size_of_ptr, 3 * size_of_ptr),
+        Version::V2 | Version::V3 => (2 * size_of_ptr, 4 * size_of_ptr),
+    }
+}
+
+/// An entry of a .NET `Dictionary<TKey, TValue>`, as read by
+/// [`Module::read_dictionary`]. Its layout matches the layout of the entries
+/// in the dictionary.
+#[derive(Copy, Clone, Debug)]
+#[repr(C)]
+pub struct DictionaryEntry<K, V> {
+    hash_code: i32,
+    next: i32,
+    /// The key of the entry.
+    pub key: K,
+    /// The value of the entry.
+    pub value: V,
+}
+
+/// The bits of a [`DictionaryEntry`] before its key and value are checked.
+/// This only needs to be public because it's the [`CheckedBitPattern::Bits`]
+/// of the entry.
+#[doc(hidden)]
+#[derive(Copy, Clone)]
+#[repr(C)]
+pub struct DictionaryEntryBits<K, V> {
+    hash_code: i32,
+    next: i32,
+    key: K,
+    value: V,
+}
+
+// SAFETY: The entry consists of integers, keys and values, which can all be
+// zeroed.
+unsafe impl<K: Zeroable, V: Zeroable> Zeroable for DictionaryEntryBits<K, V> {}
+
+// SAFETY: The entry consists of integers, keys and values, which are all valid
+// for any bit pattern. The padding between them is never read.
+unsafe impl<K: AnyBitPattern, V: AnyBitPattern> AnyBitPattern for DictionaryEntryBits<K, V> {}
+
+// SAFETY: The bits have the same layout as the entry, as they are both
+// `repr(C)` and the bits of the keys and values have the same layout as the
+// keys and values themselves. The integers are valid for any bit pattern, so
+// only the keys and values need to be checked.
+unsafe impl<K: CheckedBitPattern, V: CheckedBitPattern> CheckedBitPattern
+    for DictionaryEntry<K, V>
+{
+    type Bits = DictionaryEntryBits<K::Bits, V::Bits>;
+
+    fn is_valid_bit_pattern(bits: &Self::Bits) -> bool {
+        K::is_valid_bit_pattern(&bits.key) && V::is_valid_bit_pattern(&bits.value)
+    }
+}
+
 /// The version of Mono that was used for the game. These don't correlate to the
 /// Mono version numbers.
 #[derive(Copy, Clone, PartialEq, Hash, Debug)]
@@ -972,44 +1504,100 @@ fn detect_version(process: &Process) -> Option<Version> {
         });
     }
 
-    let unity_module = {
-        let address = process.get_module_address("UnityPlayer.dll").ok()?;
-        let range = pe::read_size_of_image(process, address)? as u64;
-        (address, range)
-    };
-
-    const SIG_202X: Signature<6> = Signature::new("00 32 30 32 ?? 2E");
+    let unity_module = version::unity_player_range(process)?;
 
-    let Some(addr) = SIG_202X.scan_process_range(process, unity_module) else {
+    let Some((unity, unity_minor)) = version::scan_unity_release(process, unity_module) else {
         return Some(Version::V2);
     };
 
-    const ZERO: u8 = b'0';
-    const NINE: u8 = b'9';
+    Some(if (unity == 2021 && unity_minor >= 2) || (unity > 2021) {
+        Version::V3
+    } else {
+        Version::V2
+    })
+}
 
-    let version_string = process.read::<[u8; 6]>(addr + 1).ok()?;
+#[cfg(test)]
+mod tests {
+    use core::cell::Cell;
 
-    let (before, after) = version_string.split_at(version_string.iter().position(|&x| x == b'.')?);
+    use super::*;
 
-    let mut unity: u32 = 0;
-    for &val in before {
-        match val {
-            ZERO..=NINE => unity = unity * 10 + (val - ZERO) as u32,
-            _ => break,
+    #[test]
+    fn list_layout_of_the_old_class_library() {
+        for version in [Version::V1, Version::V1Cattrs] {
+            assert_eq!(list_offsets(version, PointerSize::Bit64), (0x10, 0x18));
+            assert_eq!(list_offsets(version, PointerSize::Bit32), (0x8, 0xC));
         }
     }
 
-    let mut unity_minor: u32 = 0;
-    for &val in &after[1..] {
-        match val {
-            ZERO..=NINE => unity_minor = unity_minor * 10 + (val - ZERO) as u32,
-            _ => break,
+    #[test]
+    fn list_layout_with_sync_root() {
+        for version in [Version::V2, Version::V3] {
+            assert_eq!(list_offsets(version, PointerSize::Bit64), (0x10, 0x20));
+            assert_eq!(list_offsets(version, PointerSize::Bit32), (0x8, 0x10));
         }
     }
 
-    Some(if (unity == 2021 && unity_minor >= 2) || (unity > 2021) {
-        Version::V3
-    } else {
-        Version::V2
-    })
+    #[test]
+    fn dictionary_entries_with_invalid_values_are_rejected() {
+        let bits = |value| DictionaryEntryBits {
+            hash_code: 1,
+            next: -1,
+            key: 5u32,
+            value,
+        };
+        assert!(DictionaryEntry::<u32, bool>::is_valid_bit_pattern(&bits(1)));
+        assert!(!DictionaryEntry::<u32, bool>::is_valid_bit_pattern(&bits(
+            2
+        )));
+    }
+
+    #[test]
+    fn invalidate_resolves_the_path_again() {
+        let pointer = UnityPointer::<2>::new("Manager", 0, &["instance", "0x10"]);
+        let starting_class = Class {
+            class: Address::new(0x2000),
+        };
+        let static_table = Cell::new(0x1000);
+        let lookups = Cell::new(0);
+        let resolve = || {
+            pointer.cache.borrow_mut().resolve_offsets(
+                &pointer.fields[..pointer.depth],
+                starting_class,
+                || Ok(Address::new(static_table.get())),
+                |address| match address.value() {
+                    // The `instance` field of the static table and the
+                    // field at 0x10 of the object it points to.
+                    0x1018 => Ok(Address::new(0x5000)),
+                    0x1118 => Ok(Address::new(0x6000)),
+                    0x5010 | 0x6010 => Ok(Address::new(0x7000)),
+                    _ => Err(Error {}),
+                },
+                |class, name| {
+                    assert_eq!(class.class, starting_class.class);
+                    assert_eq!(name, "instance");
+                    lookups.set(lookups.get() + 1);
+                    Ok(0x18)
+                },
+            )
+        };
+
+        assert!(resolve().is_ok());
+        assert!(pointer.is_resolved());
+        assert_eq!(pointer.cache.borrow().offsets, [0x18, 0x10]);
+        assert_eq!(lookups.get(), 1);
+
+        // The game reloaded and the static table moved.
+        static_table.set(0x1100);
+        pointer.invalidate();
+        assert!(!pointer.is_resolved());
+
+        assert!(resolve().is_ok());
+        assert!(pointer.is_resolved());
+        let cache = pointer.cache.borrow();
+        assert_eq!(cache.base_address, Address::new(0x1100));
+        assert_eq!(cache.offsets, [0x18, 0x10]);
+        assert_eq!(lookups.get(), 2);
+    }
 }
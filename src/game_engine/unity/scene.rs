@@ -8,15 +8,21 @@
 
 use core::{
     array,
+    cell::OnceCell,
     iter::{self, FusedIterator},
     mem::MaybeUninit,
 };
 
 use crate::{
-    file_format::pe, future::retry, signature::Signature, string::ArrayCString, Address, Address32,
-    Address64, Error, PointerSize, Process,
+    file_format::{self, BinaryFormat},
+    future::retry,
+    signature::Signature,
+    string::ArrayCString,
+    Address, Address32, Address64, Error, PointerSize, Process,
 };
 
+use super::{detect_backend, version, Backend, Quaternion};
+
 const CSTR: usize = 128;
 
 /// The scene manager allows you to easily identify the current scene loaded in
@@ -29,6 +35,10 @@ pub struct SceneManager {
     is_il2cpp: bool,
     address: Address,
     offsets: &'static Offsets,
+    /// The year of the Unity version the game is built with, if known. It's
+    /// only looked up once it's needed, as this scans the whole `UnityPlayer`
+    /// module.
+    unity_year: OnceCell<Option<u16>>,
 }
 
 impl SceneManager {
@@ -39,19 +49,16 @@ impl SceneManager {
         const SIG_32_2: Signature<6> = Signature::new("53 8D 41 ?? 33 DB");
         const SIG_32_3: Signature<14> = Signature::new("55 8B EC 83 EC 18 A1 ???????? 33 C9 53");
 
-        let unity_player = process
-            .get_module_address("UnityPlayer.dll")
-            .ok()
-            .and_then(|address| {
-                Some((address, pe::read_size_of_image(process, address)? as u64))
-            })?;
+        let unity_player = version::unity_player_range(process)?;
 
-        let pointer_size = match pe::MachineType::read(process, unity_player.0)? {
-            pe::MachineType::X86_64 => PointerSize::Bit64,
-            _ => PointerSize::Bit32,
-        };
+        // The signatures are only known for the Windows builds.
+        let info = file_format::read_binary_info(process, unity_player.0)?;
+        if info.format != BinaryFormat::PE {
+            return None;
+        }
+        let pointer_size = info.pointer_size?;
 
-        let is_il2cpp = process.get_module_address("GameAssembly.dll").is_ok();
+        let is_il2cpp = detect_backend(process) == Some(Backend::Il2Cpp);
 
         // There are multiple signatures that can be used, depending on the version of Unity
         // used in the target game.
@@ -82,6 +89,7 @@ impl SceneManager {
             is_il2cpp,
             address,
             offsets,
+            unity_year: OnceCell::new(),
         })
     }
 
@@ -98,6 +106,18 @@ impl SceneManager {
         self.pointer_size as u64
     }
 
+    /// Some of the offsets of scenes are only known for the layout used by
+    /// Unity 2018 to Unity 2022, so they are not used for other versions or
+    /// if the version of the game couldn't be determined.
+    fn has_known_scene_layout(&self, process: &Process) -> bool {
+        let unity_year = self.unity_year.get_or_init(|| {
+            let (year, _) =
+                version::scan_unity_release(process, version::unity_player_range(process)?)?;
+            Some(year)
+        });
+        matches!(unity_year, Some(2018..=2022))
+    }
+
     /// Tries to retrieve the current active scene.
     fn get_current_scene(&self, process: &Process) -> Result<Scene, Error> {
         process
@@ -137,6 +157,35 @@ impl SceneManager {
             .and_then(|scene| scene.path(process, self))
     }
 
+    /// Returns the full path to the loaded scene with the specified build
+    /// index. Use [`get_scene_name`] afterwards to get the scene name.
+    ///
+    /// Only scenes that are currently loaded can be looked up. The list of
+    /// all the scenes in the build settings is not accessible, so a table
+    /// mapping every build index to its name needs to be built up while the
+    /// scenes get loaded.
+    pub fn get_loaded_scene_path_by_index<const N: usize>(
+        &self,
+        process: &Process,
+        index: i32,
+    ) -> Result<ArrayCString<N>, Error> {
+        self.scenes(process)
+            .find(|scene| scene.index(process, self).is_ok_and(|i| i == index))
+            .ok_or(Error {})?
+            .path(process, self)
+    }
+
+    /// Looks up the loaded scene with the [handle](Scene::handle) provided.
+    /// Unlike the build index, the handle identifies one specific loaded
+    /// instance of a scene, so this keeps finding the same scene even when
+    /// other scenes are loaded or unloaded additively and the list of scenes
+    /// gets reordered. Once the scene is unloaded, [`None`] is returned, even
+    /// if the same scene gets loaded again later.
+    pub fn get_scene_by_handle(&self, process: &Process, handle: i32) -> Option<Scene> {
+        self.scenes(process)
+            .find(|scene| scene.handle(process, self).is_ok_and(|h| h == handle))
+    }
+
     /// Returns the number of currently loaded scenes in the attached game.
     pub fn get_scene_count(&self, process: &Process) -> Result<u32, Error> {
         process.read(self.address + self.offsets.scene_count)
@@ -174,58 +223,36 @@ impl SceneManager {
         })
     }
 
-    /// Iterates over all root [`Transform`]s declared for the
-    /// specified scene.
-    ///
-    /// Each Unity scene normally has a linked list of [`Transform`]s.
-    /// Each one can, recursively, have one or more children [`Transform`]s
-    /// (and so on), as well as a list of `Component`s, which are classes (eg.
-    /// `MonoBehaviour`) containing data we might want to retrieve for the auto
-    /// splitter logic.
-    fn root_game_objects<'a>(
+    /// Iterates over the full paths of all the currently loaded scenes in the
+    /// attached game. Games that load scenes additively can have many scenes
+    /// loaded at once, so this is useful for inspecting which combination of
+    /// scenes is loaded. Scenes whose path can't be read are skipped.
+    pub fn scene_paths<'a, const N: usize>(
         &'a self,
         process: &'a Process,
-        scene: &Scene,
-    ) -> impl FusedIterator<Item = Transform> + 'a {
-        let list_first = process
-            .read_pointer(
-                scene.address + self.offsets.root_storage_container,
-                self.pointer_size,
-            )
-            .ok()
-            .filter(|val| !val.is_null());
-
-        let mut current_list = list_first;
-
-        iter::from_fn(move || {
-            let [first, _, third]: [Address; 3] = match self.pointer_size {
-                PointerSize::Bit64 => process
-                    .read::<[Address64; 3]>(current_list?)
-                    .ok()
-                    .filter(|[first, _, third]| !first.is_null() && !third.is_null())?
-                    .map(|a| a.into()),
-                _ => process
-                    .read::<[Address32; 3]>(current_list?)
-                    .ok()
-                    .filter(|[first, _, third]| !first.is_null() && !third.is_null())?
-                    .map(|a| a.into()),
-            };
-
-            if first == list_first? {
-                current_list = None;
-            } else {
-                current_list = Some(first);
-            }
+    ) -> impl DoubleEndedIterator<Item = ArrayCString<N>> + 'a {
+        self.scenes(process)
+            .filter_map(move |scene| scene.path(process, self).ok())
+    }
 
-            Some(Transform { address: third })
-        })
-        .fuse()
+    /// Checks whether a scene with the name provided is currently loaded. The
+    /// name can either be the full path of the scene or just its name, as
+    /// returned by [`get_scene_name`]. This includes scenes that are loaded
+    /// additively, not just the active scene. Tracking the result with a
+    /// [`Watcher`](crate::watcher::Watcher) allows detecting when a specific
+    /// scene gets loaded or unloaded. The scene is considered loaded as soon
+    /// as it is part of the list of scenes, use
+    /// [`Scene::loading_state`] if it needs to be fully loaded.
+    pub fn has_scene(&self, process: &Process, name: &str) -> bool {
+        self.scene_paths::<CSTR>(process)
+            .any(|path| path.matches(name) || get_scene_name(path.as_bytes()) == name.as_bytes())
     }
 
     /// Tries to find the specified root [`Transform`] from the currently
     /// active Unity scene.
     pub fn get_root_game_object(&self, process: &Process, name: &str) -> Result<Transform, Error> {
-        self.root_game_objects(process, &self.get_current_scene(process)?)
+        self.get_current_scene(process)?
+            .root_game_objects(process, self)
             .find(|obj| {
                 obj.get_name::<CSTR>(process, self)
                     .is_ok_and(|obj_name| obj_name.matches(name))
@@ -240,7 +267,8 @@ impl SceneManager {
         process: &Process,
         name: &str,
     ) -> Result<Transform, Error> {
-        self.root_game_objects(process, &self.get_dont_destroy_on_load_scene())
+        self.get_dont_destroy_on_load_scene()
+            .root_game_objects(process, self)
             .find(|obj| {
                 obj.get_name::<CSTR>(process, self)
                     .is_ok_and(|obj_name| obj_name.matches(name))
@@ -370,6 +398,85 @@ impl Transform {
             .ok_or(Error {})
     }
 
+    /// Tries to find the parent `Transform` of the current one. Root
+    /// `Transform`s don't have a parent.
+    pub fn parent(&self, process: &Process, scene_manager: &SceneManager) -> Result<Self, Error> {
+        process
+            .read_pointer(
+                self.address + scene_manager.offsets.father,
+                scene_manager.pointer_size,
+            )
+            .ok()
+            .filter(|val| !val.is_null())
+            .map(|address| Self { address })
+            .ok_or(Error {})
+    }
+
+    /// Tries to calculate the position of the current `Transform` in world
+    /// space. This combines the local position of the `Transform` with the
+    /// position, rotation and scale of all of its parents.
+    pub fn position(
+        &self,
+        process: &Process,
+        scene_manager: &SceneManager,
+    ) -> Result<[f32; 3], Error> {
+        let hierarchy = process.read_pointer(
+            self.address + scene_manager.offsets.transform_hierarchy,
+            scene_manager.pointer_size,
+        )?;
+        let mut index =
+            process.read::<i32>(self.address + scene_manager.offsets.transform_hierarchy_index)?;
+
+        let local_transforms = process.read_pointer(
+            hierarchy + scene_manager.offsets.local_transforms,
+            scene_manager.pointer_size,
+        )?;
+        let parent_indices = process.read_pointer(
+            hierarchy + scene_manager.offsets.parent_indices,
+            scene_manager.pointer_size,
+        )?;
+
+        if local_transforms.is_null() || parent_indices.is_null() || index < 0 {
+            return Err(Error {});
+        }
+
+        // Each local transform is stored as a position, a rotation quaternion
+        // and a scale, each of them padded to 16 bytes.
+        let [mut position, ..] = process.read::<[[f32; 4]; 3]>(
+            local_transforms + (index as u64).wrapping_mul(TRANSFORM_TRS_SIZE),
+        )?;
+
+        // Guards against corrupted hierarchies that loop indefinitely
+        for _ in 0..MAX_HIERARCHY_DEPTH {
+            index = process.read::<i32>(parent_indices + (index as u64).wrapping_mul(4))?;
+
+            if index < 0 {
+                let [x, y, z, _] = position;
+                return Ok([x, y, z]);
+            }
+
+            let [parent_position, rotation, scale] = process.read::<[[f32; 4]; 3]>(
+                local_transforms + (index as u64).wrapping_mul(TRANSFORM_TRS_SIZE),
+            )?;
+
+            let scaled = [
+                position[0] * scale[0],
+                position[1] * scale[1],
+                position[2] * scale[2],
+            ];
+            let rotated: [f32; 3] = Quaternion::from(rotation).rotate(scaled.into()).into();
+
+            position = [
+                rotated[0] + parent_position[0],
+                rotated[1] + parent_position[1],
+                rotated[2] + parent_position[2],
+                0.0,
+            ];
+        }
+
+        Err(Error {})
+    }
+
     /// Iterates over children `Transform`s referred by the current one
     pub fn children<'a>(
         &'a self,
@@ -437,18 +544,28 @@ impl Transform {
     }
 }
 
+const TRANSFORM_TRS_SIZE: u64 = 0x30;
+const MAX_HIERARCHY_DEPTH: usize = 256;
+
 struct Offsets {
     scene_count: u8,
     active_scene: u8,
     dont_destroy_on_load_scene: u8,
+    handle: u8,
     asset_path: u8,
     build_index: u8,
+    loading_state: u8,
     root_storage_container: u8,
     game_object: u8,
     game_object_name: u8,
     klass: u8,
     klass_name: u8,
     children_pointer: u8,
+    father: u8,
+    transform_hierarchy: u8,
+    transform_hierarchy_index: u8,
+    local_transforms: u8,
+    parent_indices: u8,
 }
 
 impl Offsets {
@@ -458,27 +575,41 @@ impl Offsets {
                 scene_count: 0x18,
                 active_scene: 0x48,
                 dont_destroy_on_load_scene: 0x70,
+                handle: 0x0,
                 asset_path: 0x10,
                 build_index: 0x98,
+                loading_state: 0x94,
                 root_storage_container: 0xB0,
                 game_object: 0x30,
                 game_object_name: 0x60,
                 klass: 0x28,
                 klass_name: 0x48,
                 children_pointer: 0x70,
+                father: 0x90,
+                transform_hierarchy: 0x38,
+                transform_hierarchy_index: 0x40,
+                local_transforms: 0x18,
+                parent_indices: 0x20,
             },
             _ => &Self {
                 scene_count: 0x10,
                 active_scene: 0x28,
                 dont_destroy_on_load_scene: 0x40,
+                handle: 0x0,
                 asset_path: 0xC,
                 build_index: 0x70,
+                loading_state: 0x6C,
                 root_storage_container: 0x88,
                 game_object: 0x1C,
                 game_object_name: 0x3C,
                 klass: 0x18,
                 klass_name: 0x2C,
                 children_pointer: 0x50,
+                father: 0x60,
+                transform_hierarchy: 0x20,
+                transform_hierarchy_index: 0x24,
+                local_transforms: 0x10,
+                parent_indices: 0x14,
             },
         }
     }
@@ -507,6 +638,118 @@ impl Scene {
         process.read(self.address + scene_manager.offsets.build_index)
     }
 
+    /// Returns the runtime handle of the scene. Every time a scene gets loaded,
+    /// Unity assigns it a new handle that stays the same until the scene is
+    /// unloaded again. This is different from the build [index](Self::index),
+    /// which identifies the scene in the build settings and is therefore shared
+    /// by every loaded instance of the same scene, and also from the position
+    /// of the scene in the list of [loaded scenes](SceneManager::scenes),
+    /// which shifts whenever scenes are loaded or unloaded additively. The
+    /// handle can be stored and then be passed to
+    /// [`SceneManager::get_scene_by_handle`] to find the same scene again.
+    ///
+    /// The handle is the first field of the scene in the layout used by Unity
+    /// 2018 to Unity 2022. As it isn't known for other versions, this returns
+    /// an error for games built with them, as well as for games whose version
+    /// can't be determined by [`read_unity_version`](super::read_unity_version).
+    pub fn handle(&self, process: &Process, scene_manager: &SceneManager) -> Result<i32, Error> {
+        if !scene_manager.has_known_scene_layout(process) {
+            return Err(Error {});
+        }
+        process.read(self.address + scene_manager.offsets.handle)
+    }
+
+    /// Returns the current loading state of the scene. This allows telling
+    /// apart a scene that is still being loaded from one that has finished
+    /// loading, which is what load removal usually needs.
+    ///
+    /// The offset of the loading state is only known for games built with
+    /// Unity 2018 to Unity 2022, so this returns an error for games built with
+    /// other versions of Unity, as well as for games whose version can't be
+    /// determined by [`read_unity_version`](super::read_unity_version).
+    pub fn loading_state(
+        &self,
+        process: &Process,
+        scene_manager: &SceneManager,
+    ) -> Result<SceneLoadingState, Error> {
+        if !scene_manager.has_known_scene_layout(process) {
+            return Err(Error {});
+        }
+        Ok(
+            match process.read::<i32>(self.address + scene_manager.offsets.loading_state)? {
+                0 => SceneLoadingState::NotLoaded,
+                1 => SceneLoadingState::Loading,
+                2 => SceneLoadingState::Loaded,
+                3 => SceneLoadingState::Unloading,
+                _ => return Err(Error {}),
+            },
+        )
+    }
+
+    /// Returns [`true`] if the scene has finished loading. See
+    /// [`loading_state`](Self::loading_state) for more details.
+    pub fn is_loaded(
+        &self,
+        process: &Process,
+        scene_manager: &SceneManager,
+    ) -> Result<bool, Error> {
+        Ok(self.loading_state(process, scene_manager)? == SceneLoadingState::Loaded)
+    }
+
+    /// Iterates over all root [`Transform`]s declared for the scene.
+    ///
+    /// Each Unity scene normally has a linked list of [`Transform`]s.
+    /// Each one can, recursively, have one or more children [`Transform`]s
+    /// (and so on), as well as a list of `Component`s, which are classes (eg.
+    /// `MonoBehaviour`) containing data we might want to retrieve for the auto
+    /// splitter logic.
+    ///
+    /// The list is walked lazily, one entry per call to `next`, so looking for
+    /// a specific object in a large scene only reads up to that object. If
+    /// the scene gets unloaded while iterating, the iterator simply ends. The
+    /// list is owned by the native part of the engine, so it has the same
+    /// layout for both Mono and IL2CPP games. Only the classes of the
+    /// components differ, which [`Transform::classes`] takes care of.
+    pub fn root_game_objects<'a>(
+        &self,
+        process: &'a Process,
+        scene_manager: &'a SceneManager,
+    ) -> impl FusedIterator<Item = Transform> + 'a {
+        let list_first = process
+            .read_pointer(
+                self.address + scene_manager.offsets.root_storage_container,
+                scene_manager.pointer_size,
+            )
+            .ok()
+            .filter(|val| !val.is_null());
+
+        let mut current_list = list_first;
+
+        iter::from_fn(move || {
+            let [first, _, third]: [Address; 3] = match scene_manager.pointer_size {
+                PointerSize::Bit64 => process
+                    .read::<[Address64; 3]>(current_list?)
+                    .ok()
+                    .filter(|[first, _, third]| !first.is_null() && !third.is_null())?
+                    .map(|a| a.into()),
+                _ => process
+                    .read::<[Address32; 3]>(current_list?)
+                    .ok()
+                    .filter(|[first, _, third]| !first.is_null() && !third.is_null())?
+                    .map(|a| a.into()),
+            };
+
+            if first == list_first? {
+                current_list = None;
+            } else {
+                current_list = Some(first);
+            }
+
+            Some(Transform { address: third })
+        })
+        .fuse()
+    }
+
     /// Returns the full path to the scene.
     pub fn path<const N: usize>(
         &self,
@@ -522,6 +765,19 @@ impl Scene {
     }
 }
 
+/// The loading state of a [`Scene`].
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum SceneLoadingState {
+    /// The scene is not loaded.
+    NotLoaded,
+    /// The scene is currently being loaded.
+    Loading,
+    /// The scene has finished loading.
+    Loaded,
+    /// The scene is currently being unloaded.
+    Unloading,
+}
+
 /// Returns the name of the scene from the given scene path. This path is
 /// usually retrieved from [`SceneManager::get_current_scene_path`].
 pub fn get_scene_name(scene_path: &[u8]) -> &[u8] {
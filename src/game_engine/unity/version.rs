@@ -0,0 +1,98 @@
+use arrayvec::ArrayString;
+
+use crate::{signature::Signature, string::ArrayCString, Address, Process};
+
+const MODULE_NAMES: [&str; 2] = ["UnityPlayer.dll", "UnityPlayer.so"];
+
+/// Reads the full version of Unity that the game is built with, such as
+/// `2021.3.16f1`. This is useful for picking the right offsets for a game or
+/// for logging which engine build a game uses.
+///
+/// The version is found by searching the `UnityPlayer` module for a string
+/// that looks like a version, the same way the version of the
+/// [`mono`](super::mono) and [`il2cpp`](super::il2cpp) backends is detected.
+/// Only versions of the form `YYYY.minor.patch` followed by the release type
+/// and number are found, so this returns [`None`] for Unity 5 and older, as
+/// well as for games that are statically linked against Unity, which don't
+/// have a `UnityPlayer` module. The module is scanned on every call, so the
+/// version should only be read once. If the version doesn't fit into `N`
+/// bytes, [`None`] is returned as well.
+pub fn read_unity_version<const N: usize>(process: &Process) -> Option<ArrayString<N>> {
+    scan_unity_version(process, unity_player_range(process)?)
+}
+
+/// Returns the address and size of the `UnityPlayer` module.
+pub(super) fn unity_player_range(process: &Process) -> Option<(Address, u64)> {
+    MODULE_NAMES
+        .iter()
+        .find_map(|&name| process.get_module_range(name).ok())
+}
+
+/// Searches the range of the `UnityPlayer` module for the version of Unity,
+/// just like [`read_unity_version`].
+pub(super) fn scan_unity_version<const N: usize>(
+    process: &Process,
+    (mut address, size): (Address, u64),
+) -> Option<ArrayString<N>> {
+    const SIG: Signature<6> = Signature::new("00 32 30 ?? ?? 2E");
+
+    let end = address.value() + size;
+
+    while address.value() < end {
+        let found = SIG.scan_process_range(process, (address, end - address.value()))? + 1;
+        if let Ok(candidate) = process.read::<ArrayCString<32>>(found) {
+            if is_version(candidate.as_bytes()) {
+                return ArrayString::from(candidate.validate_utf8().ok()?).ok();
+            }
+        }
+        address = found;
+    }
+
+    None
+}
+
+/// Searches the range of the `UnityPlayer` module for the version of Unity
+/// and returns its year and minor version, such as `(2021, 3)`.
+pub(super) fn scan_unity_release(process: &Process, range: (Address, u64)) -> Option<(u16, u16)> {
+    parse_release(&scan_unity_version::<32>(process, range)?)
+}
+
+fn parse_release(version: &str) -> Option<(u16, u16)> {
+    let mut parts = version.split('.');
+    Some((parts.next()?.parse().ok()?, parts.next()?.parse().ok()?))
+}
+
+fn is_version(version: &[u8]) -> bool {
+    let digits = |part: &[u8]| !part.is_empty() && part.iter().all(u8::is_ascii_digit);
+
+    let mut parts = version.split(|&b| b == b'.');
+    let (Some(year), Some(minor), Some(patch), None) =
+        (parts.next(), parts.next(), parts.next(), parts.next())
+    else {
+        return false;
+    };
+
+    let Some(release_type) = patch.iter().position(|b| b"abfpx".contains(b)) else {
+        return false;
+    };
+    let (patch, release) = patch.split_at(release_type);
+
+    year.len() == 4 && digits(year) && digits(minor) && digits(patch) && digits(&release[1..])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn versions_and_their_release() {
+        assert!(is_version(b"2021.3.16f1"));
+        assert!(is_version(b"2019.4.40a12"));
+        assert!(!is_version(b"2021.3.16"));
+        assert!(!is_version(b"2021.3"));
+        assert!(!is_version(b"21.3.16f1"));
+
+        assert_eq!(parse_release("2021.3.16f1"), Some((2021, 3)));
+        assert_eq!(parse_release("2018.10.0b2"), Some((2018, 10)));
+    }
+}
@@ -4,10 +4,11 @@ use core::{
     array,
     cell::RefCell,
     iter::{self, FusedIterator},
-    mem::size_of,
+    mem::{align_of, size_of},
 };
 
-use bytemuck::CheckedBitPattern;
+use arrayvec::ArrayVec;
+use bytemuck::{AnyBitPattern, CheckedBitPattern};
 
 use crate::{
     file_format::pe, future::retry, signature::Signature, string::ArrayCString, Address, Error,
@@ -16,6 +17,27 @@ use crate::{
 
 const CSTR: usize = 128;
 
+/// Attaches to an Unreal Engine game running as a process with any of the
+/// names provided and waits for its [`Module`] to be resolved, using the main
+/// module of the process. The UE version needs to be correct for this to
+/// work. If the process closes before the module is resolved, the process
+/// gets attached to again and the whole procedure starts over. This way, the
+/// returned module always belongs to the returned process.
+pub async fn wait_attach_full(process_names: &[&str], version: Version) -> (Process, Module) {
+    loop {
+        let (name, process) = Process::wait_attach_any(process_names).await;
+        let module = process
+            .until_closes(async {
+                let main_module_address = retry(|| process.get_module_address(name)).await;
+                Module::wait_attach(&process, version, main_module_address).await
+            })
+            .await;
+        if let Some(module) = module {
+            return (process, module);
+        }
+    }
+}
+
 /// Represents access to a Unreal Engine game.
 ///
 /// This struct gives immediate access to 2 important structs present in every UE game:
@@ -28,6 +50,7 @@ pub struct Module {
     g_engine: Address,
     g_world: Address,
     fname_base: Address,
+    g_uobject_array: Option<Address>,
 }
 
 impl Module {
@@ -90,6 +113,21 @@ impl Module {
             addr + 0x4 + process.read::<i32>(addr).ok()?
         };
 
+        // This points to the chunked array of objects inside of
+        // `GUObjectArray`. It's only needed for looking up objects by name, so
+        // not finding it doesn't prevent attaching.
+        let g_uobject_array = {
+            const GUOBJECT_ARRAY: &[(Signature<15>, u8)] = &[(
+                Signature::new("48 8B 05 ?? ?? ?? ?? 48 8B 0C C8 48 8D 04 D1"),
+                3,
+            )];
+
+            GUOBJECT_ARRAY.iter().find_map(|(sig, offset)| {
+                let addr = sig.scan_process_range(process, module_range)? + *offset;
+                Some(addr + 0x4 + process.read::<i32>(addr).ok()?)
+            })
+        };
+
         Some(Self {
             pointer_size,
             //version,
@@ -97,6 +135,7 @@ impl Module {
             g_engine,
             g_world,
             fname_base,
+            g_uobject_array,
         })
     }
 
@@ -136,6 +175,128 @@ impl Module {
         }
     }
 
+    /// Returns the `UGameInstance` of the game, which exists for as long as
+    /// the game is running and is therefore where a lot of games store state
+    /// that persists across levels. It is reached through the
+    /// `OwningGameInstance` field of [GWorld](Self::get_g_world_uobject).
+    /// Returns `None` if there is no world or game instance yet, such as
+    /// while the game is starting up.
+    pub fn get_game_instance(&self, process: &Process) -> Option<UObject> {
+        self.get_g_world_uobject(process)?
+            .read_object_field(process, self, "OwningGameInstance")
+    }
+
+    /// Returns the `APlayerController` of the first local player, which
+    /// controls the pawn the player is playing as. It is reached by
+    /// following `GWorld.OwningGameInstance.LocalPlayers[0].PlayerController`.
+    /// Returns `None` if any object along the way doesn't exist, which is
+    /// the case until the player has been spawned, for example in the main
+    /// menu of some games or while a level is loading.
+    pub fn get_player_controller(&self, process: &Process) -> Option<UObject> {
+        let game_instance = self.get_game_instance(process)?;
+
+        // TArray<ULocalPlayer*> consists of the pointer to the data, followed
+        // by the number of elements and the capacity as i32s.
+        let local_players =
+            game_instance.object + game_instance.get_field_offset(process, self, "LocalPlayers")?;
+        let count = process
+            .read::<i32>(local_players + self.size_of_ptr())
+            .ok()?;
+        if count < 1 {
+            return None;
+        }
+        let data = process
+            .read_pointer(local_players, self.pointer_size)
+            .ok()?;
+        let local_player = match process.read_pointer(data, self.pointer_size) {
+            Ok(Address::NULL) | Err(_) => return None,
+            Ok(val) => UObject { object: val },
+        };
+
+        local_player.read_object_field(process, self, "PlayerController")
+    }
+
+    /// Tries to find the `UEnum` with the specified name, by searching through
+    /// all the objects currently registered in the engine. This includes
+    /// enums defined in Blueprints, which are instances of subclasses of
+    /// `UEnum`, such as `UUserDefinedEnum`. As this is fairly slow, the result
+    /// should be cached. Returns `None` if no such enum exists.
+    pub fn get_enum(&self, process: &Process, enum_name: &str) -> Option<UEnum> {
+        self.objects(process)
+            .find(|object| {
+                object
+                    .get_uclass(process, self)
+                    .is_ok_and(|class| class.is_a(process, self, "Enum"))
+                    && object
+                        .get_fname::<CSTR>(process, self)
+                        .is_ok_and(|name| name.matches(enum_name))
+            })
+            .map(|object| UEnum {
+                object: object.object,
+            })
+    }
+
+    fn objects<'a>(&'a self, process: &'a Process) -> impl FusedIterator<Item = UObject> + 'a {
+        // Source: https://github.com/EpicGames/UnrealEngine/blob/release/Engine/Source/Runtime/CoreUObject/Public/UObject/UObjectArray.h
+        const ELEMENTS_PER_CHUNK: u64 = 64 * 1024;
+
+        let objects = self.g_uobject_array;
+        let num_elements = objects
+            .and_then(|addr| {
+                process
+                    .read::<i32>(addr + 2 * self.size_of_ptr() + 0x4)
+                    .ok()
+            })
+            .unwrap_or_default()
+            .max(0) as u64;
+
+        (0..num_elements)
+            .filter_map(move |index| {
+                let chunk = process
+                    .read_pointer(
+                        process.read_pointer(objects?, self.pointer_size).ok()?
+                            + (index / ELEMENTS_PER_CHUNK).wrapping_mul(self.size_of_ptr()),
+                        self.pointer_size,
+                    )
+                    .ok()?;
+
+                match process.read_pointer(
+                    chunk
+                        + (index % ELEMENTS_PER_CHUNK)
+                            .wrapping_mul(self.offsets.fuobjectitem_size as u64),
+                    self.pointer_size,
+                ) {
+                    Ok(Address::NULL) | Err(_) => None,
+                    Ok(object) => Some(UObject { object }),
+                }
+            })
+            .fuse()
+    }
+
+    /// Reads the `FName` stored at the specified address.
+    fn read_fname<const N: usize>(
+        &self,
+        process: &Process,
+        fname: Address,
+    ) -> Result<ArrayCString<N>, Error> {
+        let [name_offset, chunk_offset] = process.read::<[u16; 2]>(fname)?;
+
+        let addr = process.read_pointer(
+            self.fname_base + self.size_of_ptr().wrapping_mul(chunk_offset as u64 + 2),
+            self.pointer_size,
+        )? + (name_offset as u64).wrapping_mul(size_of::<u16>() as u64);
+
+        let string_size = process
+            .read::<u16>(addr)?
+            .checked_shr(6)
+            .unwrap_or_default() as usize;
+
+        let mut string = process.read::<ArrayCString<N>>(addr + size_of::<u16>() as u64)?;
+        string.set_len(string_size);
+
+        Ok(string)
+    }
+
     #[inline]
     const fn size_of_ptr(&self) -> u64 {
         self.pointer_size as u64
@@ -164,23 +325,68 @@ impl UObject {
         process: &Process,
         module: &Module,
     ) -> Result<ArrayCString<N>, Error> {
-        let [name_offset, chunk_offset] =
-            process.read::<[u16; 2]>(self.object + module.offsets.uobject_fname)?;
+        module.read_fname(process, self.object + module.offsets.uobject_fname)
+    }
 
-        let addr = process.read_pointer(
-            module.fname_base + module.size_of_ptr().wrapping_mul(chunk_offset as u64 + 2),
+    /// Returns the `UObject` that contains the current `UObject`, which is
+    /// called its outer. Returns `None` for the outermost object, which is
+    /// usually a package.
+    pub fn get_outer(&self, process: &Process, module: &Module) -> Option<UObject> {
+        match process.read_pointer(
+            self.object + module.offsets.uobject_outer,
             module.pointer_size,
-        )? + (name_offset as u64).wrapping_mul(size_of::<u16>() as u64);
+        ) {
+            Ok(Address::NULL) | Err(_) => None,
+            Ok(val) => Some(UObject { object: val }),
+        }
+    }
 
-        let string_size = process
-            .read::<u16>(addr)?
-            .checked_shr(6)
-            .unwrap_or_default() as usize;
+    /// Reads the full path of the current `UObject`, which consists of the
+    /// `FName`s of all its outers, starting from the outermost one, followed
+    /// by its own `FName`. Just like `UObject::GetPathName` in Unreal Engine,
+    /// the names are separated by `.`, except for subobjects, which are
+    /// separated from their outer by `:`. This is useful for telling apart
+    /// objects that have the same name. Returns an error if the path doesn't
+    /// fit into `N` bytes or the chain of outers is more than 32 objects
+    /// deep.
+    pub fn get_path_name<const N: usize>(
+        &self,
+        process: &Process,
+        module: &Module,
+    ) -> Result<ArrayCString<N>, Error> {
+        let mut chain = ArrayVec::<UObject, 32>::new();
+        let mut current = Some(*self);
+        while let Some(object) = current {
+            chain.try_push(object).map_err(|_| Error {})?;
+            current = object.get_outer(process, module);
+        }
 
-        let mut string = process.read::<ArrayCString<N>>(addr + size_of::<u16>() as u64)?;
-        string.set_len(string_size);
+        let is_package = |object: &UObject| {
+            object.get_uclass(process, module).is_ok_and(|class| {
+                class
+                    .get_fname::<CSTR>(process, module)
+                    .is_ok_and(|name| name.matches("Package"))
+            })
+        };
 
-        Ok(string)
+        let mut path = [0; N];
+        let mut len = 0;
+        for (index, object) in chain.iter().enumerate().rev() {
+            if let Some(outer) = chain.get(index + 1) {
+                let is_subobject =
+                    !is_package(outer) && chain.get(index + 2).is_some_and(is_package);
+                *path.get_mut(len).ok_or(Error {})? = if is_subobject { b':' } else { b'.' };
+                len += 1;
+            }
+            let name = object.get_fname::<CSTR>(process, module)?;
+            let name = name.as_bytes();
+            path.get_mut(len..len + name.len())
+                .ok_or(Error {})?
+                .copy_from_slice(name);
+            len += name.len();
+        }
+
+        Ok(bytemuck::cast(path))
     }
 
     /// Returns the underlying class definition for the current `UObject`
@@ -206,6 +412,123 @@ impl UObject {
             .ok()?
             .get_field_offset(process, module, field_name)
     }
+
+    /// Reads the value of a `bool` field with the specified name in the current
+    /// UObject. Unreal Engine usually declares `bool` fields as bit fields,
+    /// so multiple of them share a single byte, which is why reading them as
+    /// a [`bool`] at the [offset](Self::get_field_offset) of the field is
+    /// not correct. This reads the mask of the bit from the property and
+    /// applies it. Fields that are not bit fields use a mask that covers the
+    /// whole byte, so this works for them as well. Returns `None` if the
+    /// field can't be found or its property doesn't look like the property of
+    /// a `bool`. The field needs to be a `bool`, no other types of fields can
+    /// be read this way.
+    pub fn read_bool_property(
+        &self,
+        process: &Process,
+        module: &Module,
+        field_name: &str,
+    ) -> Option<bool> {
+        let property = self
+            .get_uclass(process, module)
+            .ok()?
+            .find_property(process, module, field_name)?;
+        let offset = property.get_offset(process, module)?;
+
+        // FBoolProperty stores the FieldSize, ByteOffset, ByteMask and
+        // FieldMask, each as a u8, right after the members of FProperty.
+        let members = process
+            .read::<[u8; 4]>(property.property + module.offsets.uboolproperty_field_size)
+            .ok()?;
+        let element_size = process
+            .read::<i32>(property.property + module.offsets.uproperty_element_size)
+            .ok()?;
+        let (byte_offset, field_mask) = bool_property_mask(members, element_size)?;
+
+        let byte = process
+            .read::<u8>(self.object + offset + byte_offset as u32)
+            .ok()?;
+        Some(byte & field_mask != 0)
+    }
+
+    /// Reads the `UObject` that the field with the specified name points to.
+    /// Returns `None` if the field can't be found or is a null pointer.
+    fn read_object_field(
+        &self,
+        process: &Process,
+        module: &Module,
+        field_name: &str,
+    ) -> Option<UObject> {
+        let offset = self.get_field_offset(process, module, field_name)?;
+        match process.read_pointer(self.object + offset, module.pointer_size) {
+            Ok(Address::NULL) | Err(_) => None,
+            Ok(val) => Some(UObject { object: val }),
+        }
+    }
+
+    /// Iterates over the key value pairs of a `TMap` field with the specified
+    /// name in the current UObject. Returns `None` if the field can't be found
+    /// or the map can't be read.
+    ///
+    /// The key and value types need to match the layout of the types used by
+    /// the map in the game. The entries are read from the sparse array backing
+    /// the map, skipping the slots that are not occupied, so they are yielded
+    /// in storage order, not in any order based on the keys. This assumes the
+    /// default set allocator, which is what the `TMap` type of both Unreal
+    /// Engine 4 and 5 uses unless a custom allocator is specified.
+    // Source: https://github.com/EpicGames/UnrealEngine/blob/release/Engine/Source/Runtime/Core/Public/Containers/SparseArray.h
+    pub fn read_map<'a, K: CheckedBitPattern, V: CheckedBitPattern>(
+        &self,
+        process: &'a Process,
+        module: &'a Module,
+        field_name: &str,
+    ) -> Option<impl FusedIterator<Item = (K, V)> + 'a> {
+        const fn align_up(value: usize, align: usize) -> usize {
+            value.div_ceil(align) * align
+        }
+
+        let map = self.object + self.get_field_offset(process, module, field_name)?;
+
+        // TSparseArray.Data: TArray<TSparseArrayElementOrFreeListLink<TSetElement<TPair<K, V>>>>
+        let data = process.read_pointer(map, module.pointer_size).ok()?;
+        let slots = process.read::<i32>(map + module.size_of_ptr()).ok()?.max(0) as u64;
+
+        // TSparseArray.AllocationFlags: TBitArray with 4 inline words, followed
+        // by a pointer to the heap allocation, if the bits don't fit inline.
+        let allocation_flags = map + module.size_of_ptr() + 0x8;
+        let secondary = process
+            .read_pointer(allocation_flags + 0x10, module.pointer_size)
+            .ok()?;
+        let bits = if secondary.is_null() {
+            allocation_flags
+        } else {
+            secondary
+        };
+
+        // TSetElement<TPair<K, V>> stores the pair, followed by two i32s for
+        // the hash chain. Free slots are reused as a free list link, which
+        // consists of two i32s as well.
+        let value_offset = align_up(size_of::<K>(), align_of::<V>());
+        let pair_align = align_of::<K>().max(align_of::<V>());
+        let pair_size = align_up(value_offset + size_of::<V>(), pair_align);
+        let element_size = align_up(align_up(pair_size, 4) + 0x8, pair_align.max(4));
+
+        Some(
+            (0..slots)
+                .filter_map(move |index| {
+                    let word = process.read::<u32>(bits + (index / 32) * 4).ok()?;
+                    if word & (1 << (index % 32)) == 0 {
+                        return None;
+                    }
+                    let element = data + index.wrapping_mul(element_size as u64);
+                    Some((
+                        process.read(element).ok()?,
+                        process.read(element + value_offset as u64).ok()?,
+                    ))
+                })
+                .fuse(),
+        )
+    }
 }
 
 /// An UClass / UStruct is the object class relative to a specific UObject.
@@ -223,6 +546,35 @@ struct UClass {
 }
 
 impl UClass {
+    fn get_fname<const N: usize>(
+        &self,
+        process: &Process,
+        module: &Module,
+    ) -> Result<ArrayCString<N>, Error> {
+        module.read_fname(process, self.class + module.offsets.uobject_fname)
+    }
+
+    /// Checks whether the class is the class with the name provided or
+    /// derives from it, by walking up the chain of super classes.
+    fn is_a(&self, process: &Process, module: &Module, class_name: &str) -> bool {
+        let mut current_class = *self;
+        loop {
+            if current_class
+                .get_fname::<CSTR>(process, module)
+                .is_ok_and(|name| name.matches(class_name))
+            {
+                return true;
+            }
+            match process.read_pointer(
+                current_class.class + module.offsets.uclass_super_field,
+                module.pointer_size,
+            ) {
+                Ok(Address::NULL) | Err(_) => return false,
+                Ok(super_field) => current_class = UClass { class: super_field },
+            }
+        }
+    }
+
     fn properties<'a>(
         &'a self,
         process: &'a Process,
@@ -284,6 +636,21 @@ impl UClass {
         .fuse()
     }
 
+    /// Returns the property with the specified name.
+    /// Returns `None` on case of failure.
+    fn find_property(
+        &self,
+        process: &Process,
+        module: &Module,
+        field_name: &str,
+    ) -> Option<UProperty> {
+        self.properties(process, module).find(|field| {
+            field
+                .get_fname::<CSTR>(process, module)
+                .is_ok_and(|name| name.matches(field_name))
+        })
+    }
+
     /// Returns the offset for the specified named property.
     /// Returns `None` on case of failure.
     fn get_field_offset(
@@ -292,12 +659,7 @@ impl UClass {
         module: &Module,
         field_name: &str,
     ) -> Option<u32> {
-        self.properties(process, module)
-            .find(|field| {
-                field
-                    .get_fname::<CSTR>(process, module)
-                    .is_ok_and(|name| name.matches(field_name))
-            })?
+        self.find_property(process, module, field_name)?
             .get_offset(process, module)
     }
 }
@@ -317,23 +679,7 @@ impl UProperty {
         process: &Process,
         module: &Module,
     ) -> Result<ArrayCString<N>, Error> {
-        let [name_offset, chunk_offset] =
-            process.read::<[u16; 2]>(self.property + module.offsets.uproperty_fname)?;
-
-        let addr = process.read_pointer(
-            module.fname_base + module.size_of_ptr().wrapping_mul(chunk_offset as u64 + 2),
-            module.pointer_size,
-        )? + (name_offset as u64).wrapping_mul(size_of::<u16>() as u64);
-
-        let string_size = process
-            .read::<u16>(addr)?
-            .checked_shr(6)
-            .unwrap_or_default() as usize;
-
-        let mut string = process.read::<ArrayCString<N>>(addr + size_of::<u16>() as u64)?;
-        string.set_len(string_size);
-
-        Ok(string)
+        module.read_fname(process, self.property + module.offsets.uproperty_fname)
     }
 
     fn get_offset(&self, process: &Process, module: &Module) -> Option<u32> {
@@ -343,6 +689,78 @@ impl UProperty {
     }
 }
 
+/// An `UEnum` is the definition of an enum type used by the game. Unreal Engine
+/// games often store their state as the numeric value of such an enum, so this
+/// can be used to map these values to the names of the variants and back.
+///
+/// The names are returned as they are stored in the engine. For enums declared
+/// as `enum class`, they usually contain the name of the enum as a prefix,
+/// such as `EGameState::Loading`.
+// Source: https://github.com/EpicGames/UnrealEngine/blob/release/Engine/Source/Runtime/CoreUObject/Public/UObject/Class.h
+#[derive(Copy, Clone)]
+pub struct UEnum {
+    object: Address,
+}
+
+impl UEnum {
+    /// Reads the `FName` of the enum itself.
+    pub fn get_fname<const N: usize>(
+        &self,
+        process: &Process,
+        module: &Module,
+    ) -> Result<ArrayCString<N>, Error> {
+        module.read_fname(process, self.object + module.offsets.uobject_fname)
+    }
+
+    /// Iterates over the names and values of all the variants of the enum.
+    pub fn names<'a, const N: usize>(
+        &'a self,
+        process: &'a Process,
+        module: &'a Module,
+    ) -> impl FusedIterator<Item = (ArrayCString<N>, i64)> + 'a {
+        // The names are stored as a `TArray<TPair<FName, int64>>`.
+        let names = self.object + module.offsets.uenum_names;
+        let (data, count) = match (
+            process.read_pointer(names, module.pointer_size),
+            process.read::<i32>(names + module.size_of_ptr()),
+        ) {
+            (Ok(data), Ok(count)) => (data, count.max(0) as u64),
+            _ => (Address::NULL, 0),
+        };
+
+        (0..count)
+            .filter_map(move |index| {
+                let pair = data + index.wrapping_mul(0x10);
+                Some((
+                    module.read_fname(process, pair).ok()?,
+                    process.read::<i64>(pair + 0x8).ok()?,
+                ))
+            })
+            .fuse()
+    }
+
+    /// Returns the name of the variant with the specified numeric value.
+    /// Returns `None` if no variant has this value.
+    pub fn get_name<const N: usize>(
+        &self,
+        process: &Process,
+        module: &Module,
+        value: i64,
+    ) -> Option<ArrayCString<N>> {
+        self.names(process, module)
+            .find(|&(_, variant)| variant == value)
+            .map(|(name, _)| name)
+    }
+
+    /// Returns the numeric value of the variant with the specified name.
+    /// Returns `None` if no variant has this name.
+    pub fn get_value(&self, process: &Process, module: &Module, name: &str) -> Option<i64> {
+        self.names::<CSTR>(process, module)
+            .find(|(variant, _)| variant.matches(name))
+            .map(|(_, value)| value)
+    }
+}
+
 /// An implementation for automatic pointer path resolution
 #[derive(Clone)]
 pub struct UnrealPointer<const CAP: usize> {
@@ -384,6 +802,16 @@ impl<const CAP: usize> UnrealPointer<CAP> {
         }
     }
 
+    /// Creates a new instance of the Pointer struct, returning [`None`] if
+    /// more fields are provided than `CAP` can hold. Unlike
+    /// [`new`](Self::new), this never silently truncates the pointer path.
+    pub fn try_new(base_address: Address, fields: &[&'static str]) -> Option<Self> {
+        if fields.len() > CAP {
+            return None;
+        }
+        Some(Self::new(base_address, fields))
+    }
+
     /// Tries to resolve the pointer path
     fn find_offsets(&self, process: &Process, module: &Module) -> Result<(), Error> {
         let mut cache = self.cache.borrow_mut();
@@ -459,16 +887,68 @@ impl<const CAP: usize> UnrealPointer<CAP> {
             &cache.offsets[..self.depth],
         )
     }
+
+    /// Dereferences the pointer path, filling the slice provided with the
+    /// contiguous values stored at the final memory address, such as the
+    /// elements of an inventory array. Just like [`deref`](Self::deref), this
+    /// makes use of the cached offsets.
+    pub fn deref_into_slice<T: AnyBitPattern>(
+        &self,
+        process: &Process,
+        module: &Module,
+        out: &mut [T],
+    ) -> Result<(), Error> {
+        process.read_into_slice(self.deref_offsets(process, module)?, out)
+    }
+}
+
+/// Returns the offset of the byte that stores the value of a `bool` field and
+/// the mask to apply to it, based on the members of its `FBoolProperty` and
+/// the `ElementSize` of the property. Native `bool` fields use a mask of
+/// `0xFF`, while bit fields use the mask of their bit, which may be in any
+/// byte of the integer that stores the bit field. As `FieldSize` is always
+/// set to the `ElementSize`, this returns [`None`] if they differ, which
+/// means that the offsets don't match the layout used by the game.
+// Source: https://github.com/EpicGames/UnrealEngine/blob/release/Engine/Source/Runtime/CoreUObject/Private/UObject/PropertyBool.cpp
+const fn bool_property_mask(
+    [field_size, byte_offset, _byte_mask, field_mask]: [u8; 4],
+    element_size: i32,
+) -> Option<(u8, u8)> {
+    if field_size as i32 != element_size || byte_offset >= field_size || field_mask == 0 {
+        return None;
+    }
+    Some((byte_offset, field_mask))
 }
 
 struct Offsets {
     uobject_fname: u8,
     uobject_class: u8,
+    uobject_outer: u8,
     uclass_super_field: u8,
     uclass_property_link: u8,
     uproperty_fname: u8,
+    uproperty_element_size: u8,
     uproperty_offset_internal: u8,
     uproperty_property_link_next: u8,
+    /// The offset of the members of `FBoolProperty` (`UBoolProperty` before
+    /// 4.25). They directly follow the members of `FProperty`, so this is the
+    /// size of `FProperty`. Unlike the other offsets, these are derived from
+    /// the layout of `FProperty` in the engine source, rather than tested on
+    /// the games listed below:
+    ///
+    /// - 4.23 and 4.24: `UProperty` follows the 0x30 bytes of `UField`. Its
+    ///   last member, `PostConstructLinkNext`, is at 0x68.
+    /// - 4.25 to 5.2: `FProperty` has the same members, but follows the 0x38
+    ///   bytes of `FField`, so all of them are 8 bytes further back.
+    /// - 5.3 and 5.4: `FField` is 0x30 bytes again, as its owner became a
+    ///   single pointer. `RepNotifyFunc` moved to the end, after
+    ///   `PostConstructLinkNext` at 0x60.
+    ///
+    /// [`UObject::read_bool_property`] rejects the members if their
+    /// `FieldSize` doesn't match the `ElementSize` of the property.
+    uboolproperty_field_size: u8,
+    uenum_names: u8,
+    fuobjectitem_size: u8,
 }
 
 impl Offsets {
@@ -479,11 +959,16 @@ impl Offsets {
                 Version::V4_23 | Version::V4_24 => &Self {
                     uobject_fname: 0x18,
                     uobject_class: 0x10,
+                    uobject_outer: 0x20,
                     uclass_super_field: 0x40,
                     uclass_property_link: 0x48,
                     uproperty_fname: 0x18,
+                    uproperty_element_size: 0x34,
                     uproperty_offset_internal: 0x44,
                     uproperty_property_link_next: 0x50,
+                    uboolproperty_field_size: 0x70,
+                    uenum_names: 0x40,
+                    fuobjectitem_size: 0x18,
                 },
                 // Tested on: Tetris Effect / Kao the Kangaroo
                 Version::V4_25
@@ -494,21 +979,31 @@ impl Offsets {
                 | Version::V5_2 => &Self {
                     uobject_fname: 0x18,
                     uobject_class: 0x10,
+                    uobject_outer: 0x20,
                     uclass_super_field: 0x40,
                     uclass_property_link: 0x50,
                     uproperty_fname: 0x28,
+                    uproperty_element_size: 0x3C,
                     uproperty_offset_internal: 0x4C,
                     uproperty_property_link_next: 0x58,
+                    uboolproperty_field_size: 0x78,
+                    uenum_names: 0x40,
+                    fuobjectitem_size: 0x18,
                 },
                 // Tested on Unreal Physics
                 Version::V5_3 | Version::V5_4 => &Self {
                     uobject_fname: 0x18,
                     uobject_class: 0x10,
+                    uobject_outer: 0x20,
                     uclass_super_field: 0x40,
                     uclass_property_link: 0x50,
                     uproperty_fname: 0x20,
+                    uproperty_element_size: 0x34,
                     uproperty_offset_internal: 0x44,
                     uproperty_property_link_next: 0x48,
+                    uboolproperty_field_size: 0x70,
+                    uenum_names: 0x40,
+                    fuobjectitem_size: 0x18,
                 },
             }),
             _ => None,
@@ -532,3 +1027,46 @@ pub enum Version {
     V5_3,
     V5_4,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn read_bool(members: [u8; 4], element_size: i32, field: &[u8]) -> Option<bool> {
+        let (byte_offset, field_mask) = bool_property_mask(members, element_size)?;
+        Some(field[byte_offset as usize] & field_mask != 0)
+    }
+
+    #[test]
+    fn native_bool_property() {
+        // SetBoolSize(sizeof(bool), true) uses a ByteMask of 1 and a
+        // FieldMask of 0xFF.
+        let members = [1, 0, 1, 0xFF];
+        assert_eq!(read_bool(members, 1, &[0]), Some(false));
+        assert_eq!(read_bool(members, 1, &[1]), Some(true));
+        assert_eq!(read_bool(members, 1, &[0x80]), Some(true));
+    }
+
+    #[test]
+    fn bit_field_bool_property() {
+        // Bit 2 of a uint8 bit field.
+        let members = [1, 0, 0x04, 0x04];
+        assert_eq!(read_bool(members, 1, &[0b1111_1011]), Some(false));
+        assert_eq!(read_bool(members, 1, &[0b0000_0100]), Some(true));
+
+        // Bit 10 of a uint32 bit field is bit 2 of its second byte.
+        let members = [4, 1, 0x04, 0x04];
+        assert_eq!(
+            read_bool(members, 4, &[0xFF, 0x00, 0xFF, 0xFF]),
+            Some(false)
+        );
+        assert_eq!(read_bool(members, 4, &[0x00, 0x04, 0x00, 0x00]), Some(true));
+    }
+
+    #[test]
+    fn mismatching_bool_property_layout() {
+        assert_eq!(bool_property_mask([1, 0, 1, 0xFF], 4), None);
+        assert_eq!(bool_property_mask([1, 1, 1, 0xFF], 1), None);
+        assert_eq!(bool_property_mask([1, 0, 0, 0], 1), None);
+    }
+}
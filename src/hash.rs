@@ -0,0 +1,97 @@
+//! Support for hashing memory, for example to fingerprint the version of a
+//! game by hashing a part of its executable.
+
+/// A hash algorithm that can be used with
+/// [`Process::hash_range`](crate::Process::hash_range).
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum HashAlgo {
+    /// The CRC-32 checksum, as used by zip, PNG and many others.
+    Crc32,
+    /// The 64-bit variant of the Fowler–Noll–Vo 1a hash.
+    Fnv1a,
+}
+
+impl HashAlgo {
+    /// Hashes the given bytes with the algorithm.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use asr::hash::HashAlgo;
+    ///
+    /// assert_eq!(HashAlgo::Crc32.hash(b"123456789"), 0xCBF43926);
+    /// assert_eq!(HashAlgo::Fnv1a.hash(b"a"), 0xAF63DC4C8601EC8C);
+    /// ```
+    pub fn hash(self, bytes: &[u8]) -> u64 {
+        let mut hasher = Hasher::new(self);
+        hasher.update(bytes);
+        hasher.finish()
+    }
+}
+
+/// Incrementally folds bytes into a hash, so that large ranges can be hashed
+/// in chunks.
+pub(crate) struct Hasher {
+    algo: HashAlgo,
+    state: u64,
+}
+
+const FNV_OFFSET_BASIS: u64 = 0xCBF29CE484222325;
+const FNV_PRIME: u64 = 0x100000001B3;
+
+static CRC32_TABLE: [u32; 256] = {
+    let mut table = [0; 256];
+    let mut i = 0;
+    while i < 256 {
+        let mut crc = i as u32;
+        let mut bit = 0;
+        while bit < 8 {
+            crc = if crc & 1 != 0 {
+                (crc >> 1) ^ 0xEDB88320
+            } else {
+                crc >> 1
+            };
+            bit += 1;
+        }
+        table[i] = crc;
+        i += 1;
+    }
+    table
+};
+
+impl Hasher {
+    pub(crate) const fn new(algo: HashAlgo) -> Self {
+        Self {
+            algo,
+            state: match algo {
+                HashAlgo::Crc32 => 0xFFFFFFFF,
+                HashAlgo::Fnv1a => FNV_OFFSET_BASIS,
+            },
+        }
+    }
+
+    pub(crate) fn update(&mut self, bytes: &[u8]) {
+        match self.algo {
+            HashAlgo::Crc32 => {
+                let mut crc = self.state as u32;
+                for &b in bytes {
+                    crc = CRC32_TABLE[((crc ^ b as u32) & 0xFF) as usize] ^ (crc >> 8);
+                }
+                self.state = crc as u64;
+            }
+            HashAlgo::Fnv1a => {
+                for &b in bytes {
+                    self.state = (self.state ^ b as u64).wrapping_mul(FNV_PRIME);
+                }
+            }
+        }
+    }
+
+    pub(crate) const fn finish(&self) -> u64 {
+        match self.algo {
+            HashAlgo::Crc32 => self.state ^ 0xFFFFFFFF,
+            HashAlgo::Fnv1a => self.state,
+        }
+    }
+}
@@ -130,12 +130,14 @@ extern crate alloc;
 mod primitives;
 mod runtime;
 
+pub mod collections;
 pub mod deep_pointer;
 pub mod emulator;
 #[macro_use]
 pub mod future;
 pub mod file_format;
 pub mod game_engine;
+pub mod hash;
 #[cfg(feature = "signature")]
 pub mod signature;
 pub mod string;
@@ -149,6 +151,12 @@ pub use self::{primitives::*, runtime::*};
 pub use arrayvec;
 pub use time;
 
+// The code generated by the derive macros needs to name the traits of
+// `bytemuck`, even in crates that don't depend on it themselves. This is not
+// part of the public API.
+#[doc(hidden)]
+pub use bytemuck as __bytemuck;
+
 #[cfg(feature = "itoa")]
 pub use itoa;
 
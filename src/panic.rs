@@ -71,7 +71,7 @@ macro_rules! panic_handler {
         #[cfg(all(not(test), target_family = "wasm"))]
         #[panic_handler]
         fn panic(info: &core::panic::PanicInfo) -> ! {
-            asr::print_limited::<$N>(info);
+            $crate::print_limited::<$N>(info);
             #[cfg(target_arch = "wasm32")]
             core::arch::wasm32::unreachable();
             #[cfg(target_arch = "wasm64")]
@@ -84,7 +84,7 @@ macro_rules! panic_handler {
         #[panic_handler]
         fn panic(_info: &core::panic::PanicInfo) -> ! {
             #[cfg(debug_assertions)]
-            asr::print_limited::<$N>(_info);
+            $crate::print_limited::<$N>(_info);
             #[cfg(target_arch = "wasm32")]
             core::arch::wasm32::unreachable();
             #[cfg(target_arch = "wasm64")]
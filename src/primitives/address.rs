@@ -141,6 +141,59 @@ define_pod_addr!(#[doc = "32-bit address that can be read from a process's memor
 define_pod_addr!(#[doc = "64-bit address that can be read from a process's memory."] Address64 => u64 => i64);
 define_addr!(#[doc = "general purpose address."] Address => u64 => i64);
 
+impl Address {
+    /// Checks whether the address is in canonical form on x86-64. Only the
+    /// lower 48 bits of an address are used there, while the upper bits need
+    /// to be copies of bit 47. Values that are not canonical can never be
+    /// dereferenced, so they usually indicate following a broken pointer path.
+    #[inline]
+    pub const fn is_canonical(self) -> bool {
+        ((self.0 as i64) << 16 >> 16) as u64 == self.0
+    }
+
+    /// Converts the address into a 32-bit address. Returns [`None`] if the
+    /// address doesn't fit into 32 bits, instead of silently truncating it.
+    #[inline]
+    pub const fn try_into_32(self) -> Option<Address32> {
+        if self.0 > u32::MAX as u64 {
+            return None;
+        }
+        Some(Address32(self.0 as u32))
+    }
+
+    /// Converts the address into a 16-bit address. Returns [`None`] if the
+    /// address doesn't fit into 16 bits, instead of silently truncating it.
+    #[inline]
+    pub const fn try_into_16(self) -> Option<Address16> {
+        if self.0 > u16::MAX as u64 {
+            return None;
+        }
+        Some(Address16(self.0 as u16))
+    }
+
+    /// Converts the address into a 64-bit address. This never fails.
+    #[inline]
+    pub const fn into_64(self) -> Address64 {
+        Address64(self.0)
+    }
+}
+
+impl Address64 {
+    /// Checks whether the address is in canonical form on x86-64. See
+    /// [`Address::is_canonical`] for more information.
+    #[inline]
+    pub const fn is_canonical(self) -> bool {
+        Address::new(self.0).is_canonical()
+    }
+
+    /// Converts the address into a 32-bit address. Returns [`None`] if the
+    /// address doesn't fit into 32 bits, instead of silently truncating it.
+    #[inline]
+    pub const fn try_into_32(self) -> Option<Address32> {
+        Address::new(self.0).try_into_32()
+    }
+}
+
 impl Add<u8> for Address {
     type Output = Self;
 
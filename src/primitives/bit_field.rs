@@ -0,0 +1,102 @@
+use core::iter::{self, FusedIterator};
+
+use bytemuck::{Pod, Zeroable};
+
+use super::FromEndian;
+
+/// A value whose bits are used as individual flags, such as a bitfield where
+/// each bit represents a collectible that was picked up. Bit `0` is the least
+/// significant bit of the value. If the value is stored in a different
+/// endianness than the one of the auto splitter, it needs to be converted with
+/// [`FromEndian`] before querying its bits, so that the indices refer to the
+/// bits of the value rather than the order of the bytes in memory.
+///
+/// # Example
+///
+/// ```
+/// # use asr::{BitField, FromEndian};
+/// // The bytes as they are stored in the memory of a big endian system.
+/// let flags: BitField<u32> = bytemuck::cast([0x80_u8, 0x00, 0x00, 0x05]);
+/// let flags = flags.from_be();
+///
+/// assert!(flags.get(0));
+/// assert!(!flags.get(1));
+/// assert!(flags.get(2));
+/// assert!(flags.get(31));
+/// assert_eq!(flags.count_ones(), 3);
+/// assert!(flags.ones().eq([0, 2, 31]));
+///
+/// // Bits past the end of the value are never set.
+/// assert!(!flags.get(32));
+/// ```
+#[derive(Copy, Clone, Default, PartialEq, Eq, Hash, Debug)]
+#[repr(transparent)]
+pub struct BitField<T>(T);
+
+impl<T> BitField<T> {
+    /// Creates a new bitfield from the value provided.
+    #[inline]
+    pub const fn new(value: T) -> Self {
+        Self(value)
+    }
+
+    /// Returns the underlying value.
+    #[inline]
+    pub fn value(self) -> T {
+        self.0
+    }
+}
+
+macro_rules! define {
+    ($name:ident) => {
+        impl BitField<$name> {
+            /// Checks whether the bit at the index provided is set. Indices
+            /// past the end of the value are never set.
+            #[inline]
+            pub const fn get(self, index: u32) -> bool {
+                index < $name::BITS && (self.0 >> index) & 1 != 0
+            }
+
+            /// Returns the number of bits that are set.
+            #[inline]
+            pub const fn count_ones(self) -> u32 {
+                self.0.count_ones()
+            }
+
+            /// Iterates over the indices of the bits that are set, from the
+            /// least significant to the most significant one.
+            pub fn ones(self) -> impl FusedIterator<Item = u32> {
+                let mut bits = self.0;
+                iter::from_fn(move || {
+                    if bits == 0 {
+                        return None;
+                    }
+                    let index = bits.trailing_zeros();
+                    bits &= bits - 1;
+                    Some(index)
+                })
+                .fuse()
+            }
+        }
+    };
+}
+
+define!(u8);
+define!(u16);
+define!(u32);
+define!(u64);
+define!(u128);
+
+impl<T: FromEndian> FromEndian for BitField<T> {
+    fn from_be(&self) -> Self {
+        Self(self.0.from_be())
+    }
+    fn from_le(&self) -> Self {
+        Self(self.0.from_le())
+    }
+}
+
+/// SAFETY: The type is transparent over `T`, which is `Pod`.
+unsafe impl<T: Pod> Pod for BitField<T> {}
+/// SAFETY: The type is transparent over `T`, which is `Zeroable`.
+unsafe impl<T: Zeroable> Zeroable for BitField<T> {}
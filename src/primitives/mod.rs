@@ -1,14 +1,17 @@
 mod address;
+mod bit_field;
 mod endian;
 
-pub use self::{address::*, endian::*};
+pub use self::{address::*, bit_field::*, endian::*};
 
 /// Pointer size represents the width (in bytes) of memory addresses used
 /// in a certain process.
 #[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
 #[repr(u8)]
 pub enum PointerSize {
-    /// A 16-bit (2 byte wide) pointer size
+    /// A 16-bit (2 byte wide) pointer size. This is mostly useful for reading
+    /// pointers of emulated 8-bit and 16-bit systems with
+    /// [`Process::read_pointer`](crate::Process::read_pointer).
     Bit16 = 0x2,
     /// A 32-bit (4 byte wide) pointer size
     Bit32 = 0x4,
@@ -1,11 +1,15 @@
 pub use memory_range::*;
 pub use process::*;
+pub use process_gate::*;
 
 mod memory_range;
 mod process;
+mod process_gate;
 mod sys;
 
 pub mod settings;
+#[cfg(feature = "stats")]
+pub mod stats;
 pub mod timer;
 
 /// An error returned by a runtime function.
@@ -41,9 +45,27 @@ pub fn print_message(text: &str) {
 /// ```
 #[inline(never)]
 pub fn print_limited<const CAP: usize>(message: &dyn core::fmt::Display) {
+    print_message(&format_limited::<CAP>(message));
+}
+
+/// Formats the given message into a stack allocated buffer with the given
+/// capacity and returns it. This is the same as [`print_limited`], but instead
+/// of printing the message, it gets returned, so it can be used for other
+/// purposes, such as setting a variable on the timer. The message may be
+/// truncated if it is too long.
+///
+/// # Example
+///
+/// ```no_run
+/// let message = asr::format_limited::<128>(&format_args!("Level {}", 3));
+/// asr::timer::set_variable("Level", &message);
+/// ```
+pub fn format_limited<const CAP: usize>(
+    message: &dyn core::fmt::Display,
+) -> arrayvec::ArrayString<CAP> {
     let mut buf = arrayvec::ArrayString::<CAP>::new();
     let _ = core::fmt::Write::write_fmt(&mut buf, format_args!("{message}"));
-    print_message(&buf);
+    buf
 }
 
 /// Queries the name of the operating system that the runtime is running on. Due
@@ -1,19 +1,94 @@
 use bytemuck::{AnyBitPattern, CheckedBitPattern};
 use core::{
+    fmt,
     mem::{self, MaybeUninit},
     slice,
 };
 
-use crate::{Address, Address16, Address32, Address64, PointerSize};
+use crate::{
+    file_format::{self, elf, pe},
+    hash::{HashAlgo, Hasher},
+    Address, Address16, Address32, Address64, BitField, FromEndian, PointerSize,
+};
 
 use super::{sys, Error, MemoryRange};
 
 pub use super::sys::ProcessId;
 
 /// A process that the auto splitter is attached to.
+///
+/// When developing an auto splitter, the `verbose-reads` feature can be
+/// enabled to print a message for every read that fails, including the reads
+/// done while following a pointer path. Reads that are expected to fail, such
+/// as the ones done while scanning memory for a signature, are not reported. The message contains the address that
+/// couldn't be read, relative to the main module of the process if possible.
+/// This helps with tracking down wrong offsets. Without the feature, nothing
+/// is printed and no overhead is added.
 #[repr(transparent)]
 pub struct Process(pub(super) sys::Process);
 
+/// An address that is described relative to the module that contains it, if
+/// any. This is returned by [`Process::describe_address`] and formats as
+/// `module+0x1234` when it's displayed.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct DescribedAddress<'name> {
+    address: Address,
+    module: Option<(&'name str, u64)>,
+}
+
+impl<'name> DescribedAddress<'name> {
+    /// Returns the absolute address.
+    pub const fn address(&self) -> Address {
+        self.address
+    }
+
+    /// Returns the name of the module that contains the address and the
+    /// offset of the address from the start of the module, if the address is
+    /// in one of the modules that were considered.
+    pub const fn module(&self) -> Option<(&'name str, u64)> {
+        self.module
+    }
+}
+
+impl fmt::Display for DescribedAddress<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.module {
+            Some((name, offset)) => write!(f, "{name}+{offset:#x}"),
+            None => write!(f, "{:#x}", self.address.value()),
+        }
+    }
+}
+
+/// A single step of a pointer path that is followed by
+/// [`Process::read_path`].
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum PathStep {
+    /// Adds the offset to the current address and then reads a pointer from
+    /// there, which becomes the new current address.
+    Deref(u64),
+    /// Adds the offset to the current address without reading anything. This
+    /// is useful for structs that are stored inline in another struct.
+    Add(u64),
+}
+
+/// An error returned by [`Process::read_pointer_path_traced`] that tells
+/// which offset of the pointer path could not be followed.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub struct PathError {
+    /// The index of the offset in the path at which the read failed. If it's
+    /// the index of the last offset, the pointers could all be followed, but
+    /// the value itself could not be read. An empty path reports a depth of
+    /// `0`.
+    pub depth: usize,
+}
+
+impl From<PathError> for Error {
+    fn from(_: PathError) -> Self {
+        Error {}
+    }
+}
+
 impl Drop for Process {
     #[inline]
     fn drop(&mut self) {
@@ -138,6 +213,32 @@ impl Process {
         unsafe { sys::process_is_open(self.0) }
     }
 
+    /// Checks whether the process stored in the slot provided has exited. If it
+    /// has, the process is detached by dropping it and [`true`] is returned.
+    /// As the slot is empty afterwards, this returns [`true`] only once per
+    /// process, so auto splitters that don't use the asynchronous
+    /// [`until_closes`](Self::until_closes) can use this to run their cleanup
+    /// code exactly once when the game closes.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use asr::Process;
+    /// # fn update(process: &mut Option<Process>) {
+    /// if Process::exit_detected(process) {
+    ///     asr::timer::reset();
+    /// }
+    /// # }
+    /// ```
+    pub fn exit_detected(process: &mut Option<Self>) -> bool {
+        if process.as_ref().is_some_and(|process| !process.is_open()) {
+            *process = None;
+            true
+        } else {
+            false
+        }
+    }
+
     /// Gets the path of the executable in the file system. The path is a path
     /// that is accessible through the WASI file system, so a Windows path of
     /// `C:\foo\bar.exe` would be returned as `/mnt/c/foo/bar.exe`.
@@ -161,6 +262,15 @@ impl Process {
         }
     }
 
+    /// Checks whether a module with the given name is currently loaded in the
+    /// process. This can be used to detect misspelled module names or modules
+    /// that are never going to be loaded, instead of retrying
+    /// [`get_module_address`](Self::get_module_address) forever.
+    #[inline]
+    pub fn has_module(&self, name: &str) -> bool {
+        self.get_module_address(name).is_ok()
+    }
+
     /// Gets the address of a module in the process.
     #[inline]
     pub fn get_module_address(&self, name: &str) -> Result<Address, Error> {
@@ -177,6 +287,46 @@ impl Process {
         }
     }
 
+    /// Converts a relative virtual address (RVA) of a module into an absolute
+    /// address in the process. An RVA is an offset from the start of the
+    /// module, which is how decompilers such as IDA or Ghidra report
+    /// addresses when the module is loaded at a base address of `0`. If they
+    /// use the preferred base address of the module instead, that needs to be
+    /// subtracted first.
+    ///
+    /// The runtime looks up the module every time this is called, which is
+    /// cheap, but not free. If the same module is accessed a lot, it's better
+    /// to look up its address once and use [`read_rva`](Self::read_rva).
+    pub fn rva_to_address(&self, module_name: &str, rva: u64) -> Result<Address, Error> {
+        Ok(self.get_module_address(module_name)? + rva)
+    }
+
+    /// Reads a value of the type specified from the process at the relative
+    /// virtual address (RVA) of a module. The base address of the module is
+    /// meant to be looked up once, for example when attaching to the process,
+    /// so that the module doesn't need to be looked up for every read. See
+    /// [`rva_to_address`](Self::rva_to_address) for more information about
+    /// RVAs.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use asr::Process;
+    /// # fn example(process: &Process) -> Result<(), asr::Error> {
+    /// let game = process.get_module_address("Game.exe")?;
+    /// // Reported by the decompiler as `Game.exe+0x1A2B3C`.
+    /// let level = process.read_rva::<u32>(game, 0x1A2B3C)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn read_rva<T: CheckedBitPattern>(
+        &self,
+        module_address: impl Into<Address>,
+        rva: u64,
+    ) -> Result<T, Error> {
+        self.read(module_address.into() + rva)
+    }
+
     /// Gets the size of a module in the process.
     #[inline]
     pub fn get_module_size(&self, name: &str) -> Result<u64, Error> {
@@ -235,6 +385,136 @@ impl Process {
         Ok((self.get_module_address(name)?, self.get_module_size(name)?))
     }
 
+    /// Gets the address and size of a module in the process like
+    /// [`get_module_range`](Self::get_module_range), but if the runtime can't
+    /// find the module, the memory ranges of the process are searched for the
+    /// main executable instead. Some games hide their modules from the usual
+    /// queries, for example because they are packed or protected by DRM, and
+    /// this may still allow attaching to them.
+    ///
+    /// The search is a heuristic and considerably slower than querying the
+    /// module, so it should only be used for games that are known to need it:
+    ///
+    /// - Only the start of each memory range is checked for a PE or ELF header.
+    ///   The first Portable Executable that is not a `dll` or the first ELF
+    ///   module is considered to be the main executable. This is usually
+    ///   the case, as executables tend to be mapped below the libraries they
+    ///   load, but it's not guaranteed.
+    /// - The name is only used for querying the runtime, the module found by
+    ///   the search may be a different one. So this can't be used to look up
+    ///   any module other than the main executable.
+    /// - The size is taken from the PE header or, for ELF modules, covers
+    ///   all the memory ranges that directly follow the header. Packers may
+    ///   rewrite or remap the headers, in which case the size may be off or
+    ///   no module is found at all.
+    pub fn get_module_range_or_scan(&self, name: &str) -> Result<(Address, u64), Error> {
+        if let Ok(range) = self.get_module_range(name) {
+            return Ok(range);
+        }
+
+        let mut ranges = self.memory_ranges().filter_map(|range| range.range().ok());
+        while let Some((address, size)) = ranges.next() {
+            if pe::is_dll(self, address) == Some(false) {
+                let size = pe::read_size_of_image(self, address).map_or(size, u64::from);
+                return Ok((address, size));
+            }
+
+            if elf::is_64_bit(self, address).is_some() {
+                let mut end = address + size;
+                for (next_address, next_size) in ranges.by_ref() {
+                    if next_address != end {
+                        break;
+                    }
+                    end = next_address + next_size;
+                }
+                return Ok((address, end.value() - address.value()));
+            }
+        }
+
+        Err(Error {})
+    }
+
+    /// Describes an address relative to the module that contains it, which is
+    /// useful for debugging and bug reports. The runtime doesn't provide a
+    /// list of all the modules of a process, so the names of the modules to
+    /// consider need to be provided. If none of them contain the address, the
+    /// absolute address is used.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use asr::{Address, Process};
+    /// # fn example(process: &Process, address: Address) {
+    /// let described = process.describe_address(address, &["Game.exe", "UnityPlayer.dll"]);
+    /// // Prints something like `Game.exe+0x1234`.
+    /// asr::print_limited::<128>(&described);
+    /// # }
+    /// ```
+    pub fn describe_address<'name>(
+        &self,
+        address: impl Into<Address>,
+        module_names: &[&'name str],
+    ) -> DescribedAddress<'name> {
+        let address = address.into();
+        let module = module_names.iter().find_map(|&name| {
+            let (base, size) = self.get_module_range(name).ok()?;
+            let offset = address.value().checked_sub(base.value())?;
+            (offset < size).then_some((name, offset))
+        });
+        DescribedAddress { address, module }
+    }
+
+    /// Detects the pointer size of a module in the process by parsing its
+    /// executable format. Both PE (Windows) and ELF (Linux) modules are
+    /// supported. The pointer size of the main module of the process is the
+    /// one to use for reading pointers of the process.
+    pub fn get_module_pointer_size(&self, name: &str) -> Result<PointerSize, Error> {
+        let address = self.get_module_address(name)?;
+
+        if let Some(machine_type) = pe::MachineType::read(self, address) {
+            return machine_type.pointer_size().ok_or(Error {});
+        }
+
+        match elf::is_64_bit(self, address) {
+            Some(true) => Ok(PointerSize::Bit64),
+            Some(false) => Ok(PointerSize::Bit32),
+            None => Err(Error {}),
+        }
+    }
+
+    /// Reads the binary format, pointer size, endianness and architecture of a
+    /// module in the process from its headers. This allows configuring all
+    /// the reads for a module with a single call, regardless of the platform.
+    /// See [`read_binary_info`](file_format::read_binary_info) for the
+    /// supported formats.
+    pub fn get_module_binary_info(&self, name: &str) -> Result<file_format::BinaryInfo, Error> {
+        file_format::read_binary_info(self, self.get_module_address(name)?).ok_or(Error {})
+    }
+
+    /// Gets the address and size of a module in the process, along with its
+    /// [binary information](file_format::BinaryInfo). This combines
+    /// [`get_module_range`](Self::get_module_range) and
+    /// [`get_module_binary_info`](Self::get_module_binary_info), which attach
+    /// code usually needs together.
+    pub fn get_module_info(
+        &self,
+        name: &str,
+    ) -> Result<(Address, u64, file_format::BinaryInfo), Error> {
+        let (address, size) = self.get_module_range(name)?;
+        let info = file_format::read_binary_info(self, address).ok_or(Error {})?;
+        Ok((address, size, info))
+    }
+
+    /// Detects the pointer size of the process based on its main module. See
+    /// [`get_module_pointer_size`](Self::get_module_pointer_size) for more
+    /// information.
+    #[cfg(feature = "alloc")]
+    pub fn get_pointer_size(&self) -> Result<PointerSize, Error> {
+        let path = self.get_path()?;
+        let name = path.rsplit(['/', '\\']).next().ok_or(Error {})?;
+        self.get_module_pointer_size(name)
+    }
+
     /// Iterates over all committed (not reserved, not free) memory ranges of the process.
     #[inline]
     pub fn memory_ranges(&self) -> impl DoubleEndedIterator<Item = MemoryRange<'_>> {
@@ -270,15 +550,271 @@ impl Process {
         }
     }
 
+    /// Reads a value from the process at the address given and wraps it in a
+    /// [`BitField`], which allows querying its individual bits. If the value
+    /// is stored in a different endianness, it needs to be converted with
+    /// [`FromEndian`] before querying the bits.
+    #[inline]
+    pub fn read_bitfield<T: CheckedBitPattern>(
+        &self,
+        address: impl Into<Address>,
+    ) -> Result<BitField<T>, Error> {
+        self.read(address).map(BitField::new)
+    }
+
+    /// Reads a tagged union, such as a C-style union that is discriminated by a
+    /// separate `kind` field, from the process at the address given. The tag
+    /// is read at the offset provided relative to the address first. The
+    /// closure then receives the tag and the address of the union, so it can
+    /// read the correct variant. If the tag itself is an enum deriving
+    /// [`CheckedBitPattern`], reading an unknown tag fails instead of handing
+    /// out garbage.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use asr::{Address, Error, Process};
+    /// # fn example(process: &Process, address: Address) -> Result<(), Error> {
+    /// enum Item {
+    ///     Coins(u32),
+    ///     Position([f32; 2]),
+    /// }
+    ///
+    /// // struct { union { uint32_t coins; float position[2]; }; uint8_t kind; }
+    /// let item = process.read_tagged(address, 0x8, |kind: u8, union| match kind {
+    ///     0 => process.read(union).map(|coins| Some(Item::Coins(coins))),
+    ///     1 => process.read(union).map(|pos| Some(Item::Position(pos))),
+    ///     _ => Ok(None),
+    /// })?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    pub fn read_tagged<Tag: CheckedBitPattern, T>(
+        &self,
+        address: impl Into<Address>,
+        tag_offset: u64,
+        f: impl FnOnce(Tag, Address) -> Result<T, Error>,
+    ) -> Result<T, Error> {
+        let address = address.into();
+        let tag = self.read(address + tag_offset)?;
+        f(tag, address)
+    }
+
+    /// Hashes the memory of the range given with the hash algorithm provided.
+    /// The memory is read in small chunks, so no allocation is needed,
+    /// regardless of the size of the range. Hashing a part of the executable,
+    /// such as its code section, is a robust way to tell different versions
+    /// of a game apart. The range can be the address and size of a module, as
+    /// returned by [`get_module_range`](Self::get_module_range). Returns an
+    /// error if any part of the range can't be read.
+    pub fn hash_range(
+        &self,
+        (address, len): (impl Into<Address>, u64),
+        algo: HashAlgo,
+    ) -> Result<u64, Error> {
+        let mut address = address.into();
+        let mut remaining = len;
+        let mut buf = [MaybeUninit::uninit(); 4 << 10];
+        let mut hasher = Hasher::new(algo);
+
+        while remaining != 0 {
+            let chunk_len = remaining.min(buf.len() as u64);
+            let chunk = self.read_into_uninit_buf(address, &mut buf[..chunk_len as usize])?;
+            hasher.update(chunk);
+            address = address + chunk_len;
+            remaining -= chunk_len;
+        }
+
+        Ok(hasher.finish())
+    }
+
+    /// Reads a value of the type specified from the process at the address
+    /// given and converts it from big endian. This also works for structs that
+    /// derive [`FromEndian`], in which case every field gets converted.
+    #[inline]
+    pub fn read_be<T: CheckedBitPattern + FromEndian>(
+        &self,
+        address: impl Into<Address>,
+    ) -> Result<T, Error> {
+        Ok(self.read::<T>(address)?.from_be())
+    }
+
+    /// Reads a value of the type specified from the process at the address
+    /// given and converts it from little endian. This also works for structs
+    /// that derive [`FromEndian`], in which case every field gets converted.
+    #[inline]
+    pub fn read_le<T: CheckedBitPattern + FromEndian>(
+        &self,
+        address: impl Into<Address>,
+    ) -> Result<T, Error> {
+        Ok(self.read::<T>(address)?.from_le())
+    }
+
+    /// Reads a value of the type specified from the process at the address
+    /// given. If the value can't be read, the default value of the type is
+    /// returned instead.
+    #[inline]
+    pub fn read_or_default<T: CheckedBitPattern + Default>(
+        &self,
+        address: impl Into<Address>,
+    ) -> T {
+        self.read(address).unwrap_or_default()
+    }
+
+    /// Reads a value of the type specified from the process at the address
+    /// given. If the value can't be read, the fallback value provided is
+    /// returned instead.
+    #[inline]
+    pub fn read_or<T: CheckedBitPattern>(&self, address: impl Into<Address>, fallback: T) -> T {
+        self.read(address).unwrap_or(fallback)
+    }
+
+    /// Reads an integer from the process at the address given and decodes it
+    /// as an enum. Both the decoded enum and the raw value are returned, so
+    /// values that don't correspond to any variant, such as states added in a
+    /// patch of the game, can still be logged or handled, instead of the read
+    /// failing as a whole like it would when reading the enum directly.
+    ///
+    /// The raw integer type `R` determines the width of the value in memory,
+    /// such as [`u8`] for an enum that is stored as a single byte. It is
+    /// widened to a [`u32`] before being decoded. Only the read itself can
+    /// fail.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use asr::{Address, Process};
+    /// enum State {
+    ///     Menu,
+    ///     Playing,
+    /// }
+    ///
+    /// impl TryFrom<u32> for State {
+    ///     type Error = ();
+    ///
+    ///     fn try_from(value: u32) -> Result<Self, ()> {
+    ///         match value {
+    ///             0 => Ok(Self::Menu),
+    ///             1 => Ok(Self::Playing),
+    ///             _ => Err(()),
+    ///         }
+    ///     }
+    /// }
+    ///
+    /// # fn example(process: &Process, address: Address) {
+    /// if let Ok((state, raw)) = process.read_enum_or::<State, u8>(address) {
+    ///     if state.is_none() {
+    ///         asr::print_limited::<64>(&format_args!("Unknown state: {raw}"));
+    ///     }
+    /// }
+    /// # }
+    /// ```
+    pub fn read_enum_or<E: TryFrom<u32>, R: CheckedBitPattern + Into<u32>>(
+        &self,
+        address: impl Into<Address>,
+    ) -> Result<(Option<E>, u32), Error> {
+        let raw = self.read::<R>(address)?.into();
+        Ok((E::try_from(raw).ok(), raw))
+    }
+
+    /// Reads a value of the type specified from the process at the address
+    /// given, retrying the read up to `attempts` times in total if it fails.
+    /// This smooths over reads that fail only transiently, such as while the
+    /// game is moving memory around. The last error is returned if none of the
+    /// attempts succeed. If `attempts` is `0`, the read is still attempted
+    /// once.
+    ///
+    /// This doesn't yield back to the runtime between the attempts, so all of
+    /// them happen within the same tick. It is meant for critical single
+    /// reads. Using it with large counts or in hot loops slows down the tick
+    /// considerably whenever the memory genuinely isn't readable.
+    pub fn read_retry<T: CheckedBitPattern>(
+        &self,
+        address: impl Into<Address>,
+        attempts: u32,
+    ) -> Result<T, Error> {
+        let address = address.into();
+        let mut result = self.read(address);
+        for _ in 1..attempts {
+            if result.is_ok() {
+                break;
+            }
+            result = self.read(address);
+        }
+        result
+    }
+
+    /// Reads a range of bytes from the process at the address given into the
+    /// buffer provided. The buffer is only filled up to the point where
+    /// memory stops being readable. The number of bytes that were read is
+    /// returned, which may be 0 if nothing at the address is readable.
+    ///
+    /// The runtime only supports reading memory all at once, so this narrows
+    /// down the readable prefix with a binary search, which needs a number of
+    /// reads that is logarithmic in the size of the buffer. This assumes that
+    /// the readable part of the memory is contiguous, starting at the address.
+    pub fn read_partial(&self, address: impl Into<Address>, buf: &mut [u8]) -> usize {
+        let address = address.into();
+        if self.read_into_buf_quietly(address, buf).is_ok() {
+            return buf.len();
+        }
+
+        // The prefix of length `readable` is known to be readable, the one of
+        // length `unreadable` is known not to be.
+        let (mut readable, mut unreadable) = (0, buf.len());
+        while unreadable - readable > 1 {
+            let mid = readable + (unreadable - readable) / 2;
+            if self.read_into_buf_quietly(address, &mut buf[..mid]).is_ok() {
+                readable = mid;
+            } else {
+                unreadable = mid;
+            }
+        }
+
+        // The failed reads may have left the buffer in an unspecified state,
+        // so the readable prefix needs to be read again.
+        if readable != 0
+            && self
+                .read_into_buf_quietly(address, &mut buf[..readable])
+                .is_err()
+        {
+            return 0;
+        }
+        readable
+    }
+
     /// Reads a range of bytes from the process at the address given into the
     /// buffer provided.
     #[inline]
     pub fn read_into_buf(&self, address: impl Into<Address>, buf: &mut [u8]) -> Result<(), Error> {
+        let address = address.into();
+        let result = self.read_into_buf_quietly(address, buf);
+        #[cfg(feature = "verbose-reads")]
+        if result.is_err() {
+            self.report_failed_read(address, buf.len());
+        }
+        result
+    }
+
+    /// Reads a range of bytes from the process at the address given into the
+    /// buffer provided, just like [`read_into_buf`](Self::read_into_buf), but
+    /// a failed read is never reported by the `verbose-reads` feature. This is
+    /// meant for reads that are expected to fail, such as while scanning
+    /// memory.
+    #[inline]
+    pub(crate) fn read_into_buf_quietly(
+        &self,
+        address: impl Into<Address>,
+        buf: &mut [u8],
+    ) -> Result<(), Error> {
         // SAFETY: The process handle is guaranteed to be valid. We provide a
         // valid pointer and length to the buffer. We also do proper error
         // handling afterwards.
         unsafe {
             let buf_len = buf.len();
+            #[cfg(feature = "stats")]
+            super::stats::record_read(buf_len);
             if sys::process_read(self.0, address.into(), buf.as_mut_ptr(), buf_len) {
                 Ok(())
             } else {
@@ -295,6 +831,28 @@ impl Process {
         &self,
         address: impl Into<Address>,
         buf: &'buf mut [MaybeUninit<u8>],
+    ) -> Result<&'buf mut [u8], Error> {
+        let address = address.into();
+        #[cfg(feature = "verbose-reads")]
+        let buf_len = buf.len();
+        let result = self.read_into_uninit_buf_quietly(address, buf);
+        #[cfg(feature = "verbose-reads")]
+        if result.is_err() {
+            self.report_failed_read(address, buf_len);
+        }
+        result
+    }
+
+    /// Reads a range of bytes from the process at the address given into the
+    /// buffer provided, just like
+    /// [`read_into_uninit_buf`](Self::read_into_uninit_buf), but a failed read
+    /// is never reported by the `verbose-reads` feature. This is meant for
+    /// reads that are expected to fail, such as while scanning memory.
+    #[inline]
+    pub(crate) fn read_into_uninit_buf_quietly<'buf>(
+        &self,
+        address: impl Into<Address>,
+        buf: &'buf mut [MaybeUninit<u8>],
     ) -> Result<&'buf mut [u8], Error> {
         // SAFETY: The process handle is guaranteed to be valid. We provide a
         // valid pointer and length to the buffer. We also do proper error
@@ -302,6 +860,8 @@ impl Process {
         // afterwards, so we can safely return an u8 slice of it.
         unsafe {
             let buf_len = buf.len();
+            #[cfg(feature = "stats")]
+            super::stats::record_read(buf_len);
             if sys::process_read(self.0, address.into(), buf.as_mut_ptr().cast(), buf_len) {
                 Ok(slice::from_raw_parts_mut(buf.as_mut_ptr().cast(), buf_len))
             } else {
@@ -310,6 +870,30 @@ impl Process {
         }
     }
 
+    /// Prints a message about a read that failed, describing the address
+    /// relative to the main module of the process if it's part of it. Other
+    /// modules can't be considered, as there is no way to list them.
+    #[cfg(feature = "verbose-reads")]
+    #[cold]
+    #[inline(never)]
+    fn report_failed_read(&self, address: Address, len: usize) {
+        let mut path_buf = [0; 260];
+        let mut path_len = path_buf.len();
+        // SAFETY: The process handle is guaranteed to be valid. We provide a
+        // valid pointer and length to the buffer. We only use as much of the
+        // buffer as the runtime reports to have filled, and only if the call
+        // succeeded.
+        let success =
+            unsafe { sys::process_get_path(self.0, path_buf.as_mut_ptr(), &mut path_len) };
+        let path = success
+            .then(|| core::str::from_utf8(&path_buf[..path_len]).ok())
+            .flatten()
+            .unwrap_or_default();
+        let main_module = path.rsplit(['/', '\\']).next().unwrap_or_default();
+        let described = self.describe_address(address, &[main_module]);
+        super::print_limited::<128>(&format_args!("Failed to read {len} bytes at {described}"));
+    }
+
     /// Reads a range of bytes from the process at the address given into the
     /// buffer provided. This is a convenience method for reading into a slice
     /// of a specific type.
@@ -334,16 +918,63 @@ impl Process {
         }
     }
 
+    /// Reads `count` elements of an array from the process at the address
+    /// given into the first `count` elements of the slice provided, where
+    /// each element is `stride` bytes apart. This allows reading arrays of
+    /// structs that are larger in the game than the type that models them,
+    /// for example because only the first few fields are of interest. Each
+    /// element is read at `address + index * stride`.
+    ///
+    /// Returns an error if the slice is shorter than `count`, if the stride
+    /// is smaller than the size of `T`, if the array would extend past the
+    /// end of the address space or if any of the elements can't be read.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use asr::{Address, Process};
+    /// # fn example(process: &Process, entities: Address) {
+    /// // Each entity is 0x90 bytes large, but only its position is needed,
+    /// // which is stored at the start of it.
+    /// let mut positions = [[0f32; 3]; 32];
+    /// let _ = process.read_array_strided(entities, positions.len(), 0x90, &mut positions);
+    /// # }
+    /// ```
+    pub fn read_array_strided<T: CheckedBitPattern>(
+        &self,
+        address: impl Into<Address>,
+        count: usize,
+        stride: u64,
+        out: &mut [T],
+    ) -> Result<(), Error> {
+        let address = address.into();
+        let out = out.get_mut(..count).ok_or(Error {})?;
+        if stride < mem::size_of::<T>() as u64 {
+            return Err(Error {});
+        }
+        if let Some(last) = count.checked_sub(1) {
+            (last as u64)
+                .checked_mul(stride)
+                .and_then(|offset| offset.checked_add(mem::size_of::<T>() as u64))
+                .and_then(|span| address.value().checked_add(span))
+                .ok_or(Error {})?;
+        }
+        for (index, element) in out.iter_mut().enumerate() {
+            *element = self.read(address + index as u64 * stride)?;
+        }
+        Ok(())
+    }
+
     /// Reads a range of bytes from the process at the address given into the
     /// buffer provided. This is a convenience method for reading into a slice
     /// of a specific type. The buffer does not need to be initialized. After
     /// the slice successfully got filled, the initialized slice is returned.
     #[inline]
-    pub fn read_into_uninit_slice<T: CheckedBitPattern>(
+    pub fn read_into_uninit_slice<'slice, T: CheckedBitPattern>(
         &self,
         address: impl Into<Address>,
-        slice: &mut [MaybeUninit<T>],
-    ) -> Result<&mut [T], Error> {
+        slice: &'slice mut [MaybeUninit<T>],
+    ) -> Result<&'slice mut [T], Error> {
         // SAFETY: The process handle is guaranteed to be valid. We provide a
         // valid pointer and length to the buffer. We also do proper error
         // handling afterwards. The buffer is guaranteed to be initialized
@@ -415,6 +1046,12 @@ impl Process {
     }
 
     /// Reads a pointer address from the process at the address given.
+    ///
+    /// The pointer size needs to match the bitness of the process, not the one
+    /// of the auto splitter's runtime. Reading a pointer of a 32-bit process as
+    /// a 64-bit pointer doesn't fail, but results in a corrupted address. Use
+    /// [`get_module_pointer_size`](Self::get_module_pointer_size) to detect
+    /// the correct pointer size.
     pub fn read_pointer(
         &self,
         address: impl Into<Address>,
@@ -427,6 +1064,95 @@ impl Process {
         })
     }
 
+    /// Reads a pointer from the process at the address given and then reads a
+    /// value of the type specified at the offset provided from where the
+    /// pointer points to. This is the same as calling
+    /// [`read_pointer`](Self::read_pointer) followed by [`read`](Self::read),
+    /// except that a null pointer results in an error instead of reading from
+    /// the offset as an absolute address.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use asr::{Address, PointerSize, Process};
+    /// # fn example(process: &Process, player_pointer: Address) {
+    /// // Reads the health stored at offset 0x40 of the player object.
+    /// let health = process.read_through::<f32>(player_pointer, PointerSize::Bit64, 0x40);
+    /// # }
+    /// ```
+    pub fn read_through<T: CheckedBitPattern>(
+        &self,
+        pointer_address: impl Into<Address>,
+        pointer_size: PointerSize,
+        offset: u64,
+    ) -> Result<T, Error> {
+        let pointer = self.read_pointer(pointer_address, pointer_size)?;
+        if pointer.is_null() {
+            return Err(Error {});
+        }
+        self.read(pointer + offset)
+    }
+
+    /// Reads an array of pointers from the process at the address given into
+    /// the slice provided. The number of pointers read is the length of the
+    /// slice. The pointers are read with the pointer size provided and widened
+    /// to an [`Address`], so this works the same for 16-bit, 32-bit and 64-bit
+    /// processes. The pointers are read in batches, which is a lot faster than
+    /// reading each of them on its own.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use asr::{Address, PointerSize, Process};
+    /// # fn example(process: &Process, entity_list: Address) {
+    /// let mut entities = [Address::NULL; 16];
+    /// if process
+    ///     .read_pointers(entity_list, PointerSize::Bit32, &mut entities)
+    ///     .is_ok()
+    /// {
+    ///     for entity in entities.iter().filter(|entity| !entity.is_null()) {
+    ///         // Use the entity.
+    ///     }
+    /// }
+    /// # }
+    /// ```
+    pub fn read_pointers(
+        &self,
+        address: impl Into<Address>,
+        pointer_size: PointerSize,
+        out: &mut [Address],
+    ) -> Result<(), Error> {
+        let mut address = address.into();
+        let width = pointer_size as usize;
+        let mut buf = [0; 256];
+        for chunk in out.chunks_mut(buf.len() / 8) {
+            let packed = &mut buf[..chunk.len() * width];
+            self.read_into_buf(address, packed)?;
+            widen_pointers(packed, width, chunk);
+            address = address + packed.len() as u64;
+        }
+
+        Ok(())
+    }
+
+    /// Reads a pointer address from the process at the address given, just
+    /// like [`read_pointer`](Self::read_pointer), but additionally rejects
+    /// 64-bit pointers that are not in canonical form, as they can't possibly
+    /// point to valid memory on x86-64. This catches broken pointer paths
+    /// early, instead of attempting to read from garbage addresses. As some
+    /// emulated address spaces may use such values, this check is opt-in.
+    pub fn read_pointer_canonical(
+        &self,
+        address: impl Into<Address>,
+        pointer_size: PointerSize,
+    ) -> Result<Address, Error> {
+        let pointer = self.read_pointer(address, pointer_size)?;
+        if pointer_size == PointerSize::Bit64 && !pointer.is_canonical() {
+            return Err(Error {});
+        }
+        Ok(pointer)
+    }
+
     /// Follows a path of pointers from the address given and reads a value of
     /// the type specified from the process at the end of the pointer path.
     pub fn read_pointer_path<T: CheckedBitPattern>(
@@ -435,11 +1161,201 @@ impl Process {
         pointer_size: PointerSize,
         path: &[u64],
     ) -> Result<T, Error> {
+        Ok(self.read_pointer_path_traced(address, pointer_size, path)?)
+    }
+
+    /// Follows a path of pointers from the address given and reads a value of
+    /// the type specified from the process at the end of the pointer path.
+    /// This works just like [`read_pointer_path`](Self::read_pointer_path),
+    /// but if the path can't be followed, the [error](PathError) reports the
+    /// index of the offset at which it broke. This is useful while developing
+    /// an auto splitter, as it tells which of the offsets is wrong.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use asr::{Address, PathError, PointerSize, Process};
+    /// # fn example(process: &Process, base: Address) {
+    /// if let Err(PathError { depth, .. }) =
+    ///     process.read_pointer_path_traced::<u32>(base, PointerSize::Bit64, &[0x10, 0x28, 0x8])
+    /// {
+    ///     asr::print_limited::<64>(&format_args!("The path broke at offset {depth}"));
+    /// }
+    /// # }
+    /// ```
+    pub fn read_pointer_path_traced<T: CheckedBitPattern>(
+        &self,
+        address: impl Into<Address>,
+        pointer_size: PointerSize,
+        path: &[u64],
+    ) -> Result<T, PathError> {
+        #[cfg(feature = "stats")]
+        super::stats::record_pointer_path();
+        let mut address = address.into();
+        let (&last, path) = path.split_last().ok_or(PathError { depth: 0 })?;
+        for (depth, &offset) in path.iter().enumerate() {
+            address = self
+                .read_pointer(address + offset, pointer_size)
+                .map_err(|_| PathError { depth })?;
+        }
+        self.read(address + last)
+            .map_err(|_| PathError { depth: path.len() })
+    }
+
+    /// Follows a path of steps from the address given and reads a value of the
+    /// type specified from the process at the end of the path. Unlike
+    /// [`read_pointer_path`](Self::read_pointer_path), where every offset but
+    /// the last one is dereferenced, each [step](PathStep) decides whether a
+    /// pointer is read or the offset is only added. The value is read at the
+    /// address reached after the last step.
+    ///
+    /// # Example
+    ///
+    /// Reads a field at offset `0x8` of a struct that is stored inline at
+    /// offset `0x40` of an object, which is reached through two pointers.
+    ///
+    /// ```no_run
+    /// # use asr::{Address, PathStep, PointerSize, Process};
+    /// # fn example(process: &Process, base: Address) {
+    /// let value = process.read_path::<u32>(
+    ///     base,
+    ///     PointerSize::Bit64,
+    ///     &[
+    ///         PathStep::Deref(0x10),
+    ///         PathStep::Deref(0x28),
+    ///         PathStep::Add(0x40),
+    ///         PathStep::Add(0x8),
+    ///     ],
+    /// );
+    /// # }
+    /// ```
+    pub fn read_path<T: CheckedBitPattern>(
+        &self,
+        address: impl Into<Address>,
+        pointer_size: PointerSize,
+        path: &[PathStep],
+    ) -> Result<T, Error> {
+        #[cfg(feature = "stats")]
+        super::stats::record_pointer_path();
+        let mut address = address.into();
+        for &step in path {
+            address = match step {
+                PathStep::Deref(offset) => self.read_pointer(address + offset, pointer_size)?,
+                PathStep::Add(offset) => address + offset,
+            };
+        }
+        self.read(address)
+    }
+
+    /// Follows a path of pointers from the address given and reads a value of
+    /// the type specified from the process at the end of the pointer path.
+    /// This works just like [`read_pointer_path`](Self::read_pointer_path),
+    /// but the offsets are signed, which is useful when a pointer points into
+    /// the middle of a struct and the field of interest comes before it.
+    pub fn read_pointer_path_signed<T: CheckedBitPattern>(
+        &self,
+        address: impl Into<Address>,
+        pointer_size: PointerSize,
+        path: &[i64],
+    ) -> Result<T, Error> {
+        #[cfg(feature = "stats")]
+        super::stats::record_pointer_path();
         let mut address = address.into();
         let (&last, path) = path.split_last().ok_or(Error {})?;
         for &offset in path {
+            address = self.read_pointer(address.add_signed(offset), pointer_size)?;
+        }
+        self.read(address.add_signed(last))
+    }
+
+    /// Follows a path of pointers described by a string from the address
+    /// given and reads a value of the type specified from the process at the
+    /// end of the pointer path. This works just like
+    /// [`read_pointer_path`](Self::read_pointer_path), but allows the path to
+    /// be configured at runtime, such as through the settings.
+    ///
+    /// The path consists of one or more offsets separated by `->`. Each offset
+    /// is either a hexadecimal number prefixed with `0x` or a decimal number.
+    /// Whitespace around the offsets is ignored. If the path is malformed, an
+    /// error is returned without reading any memory.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use asr::{Address, PointerSize, Process};
+    /// # fn example(process: &Process, base: Address) {
+    /// let value = process.read_path_str::<u32>(base, PointerSize::Bit64, "0x10 -> 0x20 -> 8");
+    /// # }
+    /// ```
+    pub fn read_path_str<T: CheckedBitPattern>(
+        &self,
+        address: impl Into<Address>,
+        pointer_size: PointerSize,
+        path: &str,
+    ) -> Result<T, Error> {
+        let offsets = || path.split("->").map(parse_offset);
+        offsets().try_for_each(|offset| offset.map(drop))?;
+
+        #[cfg(feature = "stats")]
+        super::stats::record_pointer_path();
+        let mut address = address.into();
+        let mut offsets = offsets().peekable();
+        while let Some(offset) = offsets.next() {
+            let offset = offset?;
+            if offsets.peek().is_none() {
+                return self.read(address + offset);
+            }
             address = self.read_pointer(address + offset, pointer_size)?;
         }
-        self.read(address + last)
+        Err(Error {})
+    }
+}
+
+/// Parses a single offset of a pointer path described by a string.
+fn parse_offset(offset: &str) -> Result<u64, Error> {
+    let offset = offset.trim();
+    match offset.strip_prefix("0x") {
+        Some(hex) => u64::from_str_radix(hex, 16),
+        None => offset.parse(),
+    }
+    .map_err(|_| Error {})
+}
+
+/// Converts the pointers of the width provided that are packed into the bytes
+/// provided into addresses. Narrower pointers are zero-extended.
+fn widen_pointers(packed: &[u8], width: usize, out: &mut [Address]) {
+    for (pointer, bytes) in out.iter_mut().zip(packed.chunks_exact(width)) {
+        *pointer = Address::new(match *bytes {
+            [a, b] => u16::from_ne_bytes([a, b]) as u64,
+            [a, b, c, d] => u32::from_ne_bytes([a, b, c, d]) as u64,
+            [a, b, c, d, e, f, g, h] => u64::from_ne_bytes([a, b, c, d, e, f, g, h]),
+            _ => unreachable!(),
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn widen_32_bit_pointers() {
+        let mut packed = [0; 12];
+        for (bytes, value) in packed
+            .chunks_exact_mut(4)
+            .zip([0x1234_5678u32, 0xFFFF_FFFF, 0])
+        {
+            bytes.copy_from_slice(&value.to_ne_bytes());
+        }
+        let mut out = [Address::new(u64::MAX); 3];
+        widen_pointers(&packed, PointerSize::Bit32 as usize, &mut out);
+        assert_eq!(
+            out,
+            [
+                Address::new(0x1234_5678),
+                Address::new(0xFFFF_FFFF),
+                Address::NULL,
+            ],
+        );
     }
 }
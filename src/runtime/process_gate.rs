@@ -0,0 +1,69 @@
+/// An event reported by a [`ProcessGate`] for a single tick.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum ProcessEvent {
+    /// The process got attached during this tick. It wasn't attached during
+    /// the previous one, so this is the time to run any one-time setup.
+    Attached,
+    /// The process got detached during this tick, such as because the game
+    /// closed. It was attached during the previous one, so this is the time
+    /// to run any teardown, such as resetting the state of the auto splitter.
+    Detached,
+    /// Whether the process is attached hasn't changed since the previous
+    /// tick.
+    Stable,
+}
+
+/// Detects when a process gets attached or detached by comparing whether it
+/// is attached in the current tick with the previous one. This works the same
+/// for auto splitters that get polled through an `update` function and for
+/// ones that use [`async_main`](crate::async_main), because it only needs to
+/// be told whether the process is attached once per tick.
+///
+/// # Example
+///
+/// ```
+/// # use asr::{ProcessEvent, ProcessGate};
+/// let mut gate = ProcessGate::new();
+///
+/// // Usually this would be `process.is_some()` for an `Option<Process>`
+/// // that is attached on demand, or `process.is_open()`.
+/// assert_eq!(gate.update(false), ProcessEvent::Stable);
+/// assert_eq!(gate.update(true), ProcessEvent::Attached);
+/// assert_eq!(gate.update(true), ProcessEvent::Stable);
+/// assert!(gate.is_attached());
+///
+/// assert_eq!(gate.update(false), ProcessEvent::Detached);
+/// assert_eq!(gate.update(false), ProcessEvent::Stable);
+/// assert!(!gate.is_attached());
+///
+/// assert_eq!(gate.update(true), ProcessEvent::Attached);
+/// assert_eq!(gate.update(true), ProcessEvent::Stable);
+/// ```
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct ProcessGate {
+    attached: bool,
+}
+
+impl ProcessGate {
+    /// Creates a new gate that considers the process to not be attached yet.
+    pub const fn new() -> Self {
+        Self { attached: false }
+    }
+
+    /// Reports whether the process is attached during the current tick and
+    /// returns whether that changed since the previous one.
+    pub const fn update(&mut self, is_attached: bool) -> ProcessEvent {
+        let was_attached = self.attached;
+        self.attached = is_attached;
+        match (was_attached, is_attached) {
+            (false, true) => ProcessEvent::Attached,
+            (true, false) => ProcessEvent::Detached,
+            _ => ProcessEvent::Stable,
+        }
+    }
+
+    /// Returns whether the process was attached during the most recent tick.
+    pub const fn is_attached(&self) -> bool {
+        self.attached
+    }
+}
@@ -0,0 +1,172 @@
+//! The binary format used by [`Map::serialize`] and [`Map::deserialize`],
+//! which documents the layout.
+
+use alloc::vec::Vec;
+
+use super::{List, Map, Value, ValueType};
+
+const MAGIC: [u8; 4] = *b"ASRS";
+const VERSION: u8 = 1;
+
+/// Maps and lists nested deeper than this are rejected, so a malicious blob
+/// can't overflow the stack.
+const MAX_DEPTH: usize = 64;
+
+const TAG_MAP: u8 = 1;
+const TAG_LIST: u8 = 2;
+const TAG_BOOL: u8 = 3;
+const TAG_I64: u8 = 4;
+const TAG_F64: u8 = 5;
+const TAG_STRING: u8 = 6;
+
+pub(super) fn serialize(map: &Map) -> Vec<u8> {
+    let mut buf = Vec::new();
+    buf.extend_from_slice(&MAGIC);
+    buf.push(VERSION);
+    write_map(&mut buf, map);
+    buf
+}
+
+pub(super) fn deserialize(blob: &[u8]) -> Option<Map> {
+    let mut reader = Reader(blob);
+    if reader.bytes(MAGIC.len())? != MAGIC || reader.u8()? != VERSION {
+        return None;
+    }
+    let map = read_map(&mut reader, 0)?;
+    reader.0.is_empty().then_some(map)
+}
+
+fn write_len(buf: &mut Vec<u8>, len: usize) {
+    buf.extend_from_slice(&(len as u32).to_le_bytes());
+}
+
+fn write_str(buf: &mut Vec<u8>, text: &str) {
+    write_len(buf, text.len());
+    buf.extend_from_slice(text.as_bytes());
+}
+
+/// Writes the number of encoded items in place of the placeholder at `start`.
+fn patch_len(buf: &mut [u8], start: usize, len: u32) {
+    buf[start..start + 4].copy_from_slice(&len.to_le_bytes());
+}
+
+fn write_map(buf: &mut Vec<u8>, map: &Map) {
+    let start = buf.len();
+    write_len(buf, 0);
+    let mut len = 0;
+    for (key, value) in map.iter() {
+        let key_start = buf.len();
+        write_str(buf, &key);
+        if write_value(buf, &value) {
+            len += 1;
+        } else {
+            buf.truncate(key_start);
+        }
+    }
+    patch_len(buf, start, len);
+}
+
+fn write_list(buf: &mut Vec<u8>, list: &List) {
+    let start = buf.len();
+    write_len(buf, 0);
+    let mut len = 0;
+    for value in list.iter() {
+        len += write_value(buf, &value) as u32;
+    }
+    patch_len(buf, start, len);
+}
+
+/// Returns [`false`] if the type of the value is not supported by the format,
+/// in which case nothing is written.
+fn write_value(buf: &mut Vec<u8>, value: &Value) -> bool {
+    match value.get_type() {
+        ValueType::Map => {
+            buf.push(TAG_MAP);
+            write_map(buf, &value.get_map().unwrap_or_default());
+        }
+        ValueType::List => {
+            buf.push(TAG_LIST);
+            write_list(buf, &value.get_list().unwrap_or_default());
+        }
+        ValueType::Bool => {
+            buf.push(TAG_BOOL);
+            buf.push(value.get_bool().unwrap_or_default() as u8);
+        }
+        ValueType::I64 => {
+            buf.push(TAG_I64);
+            buf.extend_from_slice(&value.get_i64().unwrap_or_default().to_le_bytes());
+        }
+        ValueType::F64 => {
+            buf.push(TAG_F64);
+            buf.extend_from_slice(&value.get_f64().unwrap_or_default().to_le_bytes());
+        }
+        ValueType::String => {
+            buf.push(TAG_STRING);
+            write_str(buf, &value.get_string().unwrap_or_default());
+        }
+        ValueType::Unknown => return false,
+    }
+    true
+}
+
+struct Reader<'a>(&'a [u8]);
+
+impl<'a> Reader<'a> {
+    const fn bytes(&mut self, len: usize) -> Option<&'a [u8]> {
+        if len > self.0.len() {
+            return None;
+        }
+        let (bytes, rest) = self.0.split_at(len);
+        self.0 = rest;
+        Some(bytes)
+    }
+
+    fn array<const N: usize>(&mut self) -> Option<[u8; N]> {
+        self.bytes(N)?.try_into().ok()
+    }
+
+    fn u8(&mut self) -> Option<u8> {
+        Some(self.array::<1>()?[0])
+    }
+
+    fn len(&mut self) -> Option<usize> {
+        Some(u32::from_le_bytes(self.array()?) as usize)
+    }
+
+    fn str(&mut self) -> Option<&'a str> {
+        let len = self.len()?;
+        core::str::from_utf8(self.bytes(len)?).ok()
+    }
+}
+
+fn read_map(reader: &mut Reader<'_>, depth: usize) -> Option<Map> {
+    let map = Map::new();
+    for _ in 0..reader.len()? {
+        let key = reader.str()?;
+        map.insert(key, &read_value(reader, depth)?);
+    }
+    Some(map)
+}
+
+fn read_value(reader: &mut Reader<'_>, depth: usize) -> Option<Value> {
+    Some(match reader.u8()? {
+        TAG_MAP if depth < MAX_DEPTH => read_map(reader, depth + 1)?.into(),
+        TAG_LIST if depth < MAX_DEPTH => {
+            let list = List::new();
+            for _ in 0..reader.len()? {
+                list.push(&read_value(reader, depth + 1)?);
+            }
+            list.into()
+        }
+        TAG_BOOL => match reader.u8()? {
+            0 => false,
+            1 => true,
+            _ => return None,
+        }
+        .into(),
+        TAG_I64 => i64::from_le_bytes(reader.array()?).into(),
+        TAG_F64 => f64::from_le_bytes(reader.array()?).into(),
+        TAG_STRING => reader.str()?.into(),
+        _ => return None,
+    })
+}
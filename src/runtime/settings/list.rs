@@ -39,6 +39,13 @@ impl Default for List {
     }
 }
 
+#[cfg(feature = "alloc")]
+impl PartialEq for List {
+    fn eq(&self, other: &Self) -> bool {
+        self.len() == other.len() && self.iter().eq(other.iter())
+    }
+}
+
 impl List {
     /// Creates a new empty settings list.
     #[inline]
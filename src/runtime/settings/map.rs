@@ -52,6 +52,16 @@ impl Default for Map {
     }
 }
 
+#[cfg(feature = "alloc")]
+impl PartialEq for Map {
+    fn eq(&self, other: &Self) -> bool {
+        self.len() == other.len()
+            && self
+                .iter()
+                .all(|(key, value)| other.get(&key) == Some(value))
+    }
+}
+
 impl Map {
     /// Creates a new empty settings map.
     #[inline]
@@ -262,4 +272,140 @@ impl Map {
     pub fn values(&self) -> impl DoubleEndedIterator<Item = Value> + '_ {
         (0..self.len()).flat_map(|i| self.get_value_by_index(i))
     }
+
+    /// Serializes the map, including all the maps and lists nested inside of
+    /// it, into a blob of bytes. This allows exporting the settings as a
+    /// preset that can be shared with other users or bundled with the auto
+    /// splitter as its defaults, and then be loaded with
+    /// [`deserialize`](Self::deserialize). The format is versioned and stable
+    /// across versions of this crate, see the documentation of
+    /// [`deserialize`](Self::deserialize) for its layout. Values of a type that
+    /// the format doesn't support are left out.
+    #[cfg(feature = "alloc")]
+    pub fn serialize(&self) -> alloc::vec::Vec<u8> {
+        super::blob::serialize(self)
+    }
+
+    /// Deserializes a map from a blob of bytes that was created with
+    /// [`serialize`](Self::serialize). Returns [`None`] if the blob is
+    /// malformed or was created by a newer version of the format.
+    ///
+    /// The blob starts with the magic bytes `ASRS`, followed by a single byte
+    /// holding the version of the format, which currently is `1`. The rest
+    /// of the blob is the encoded map. All integers are stored in little
+    /// endian.
+    ///
+    /// - A map is a `u32` holding the number of entries, followed by each
+    ///   entry as a string key and a value.
+    /// - A value is a single byte holding its type, followed by the encoded
+    ///   value. The types are `1` for a map, `2` for a list, `3` for a bool,
+    ///   `4` for an `i64`, `5` for an `f64` and `6` for a string.
+    /// - A list is a `u32` holding the number of values, followed by each
+    ///   value.
+    /// - A bool is a single byte that is either `0` or `1`.
+    /// - An `i64` and an `f64` are stored as their 8 bytes.
+    /// - A string is a `u32` holding its length in bytes, followed by its
+    ///   UTF-8 encoded bytes.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use asr::settings::Map;
+    /// let preset = Map::load().serialize();
+    /// // ... Share the preset ...
+    /// if let Some(map) = Map::deserialize(&preset) {
+    ///     map.store();
+    /// }
+    /// ```
+    #[cfg(feature = "alloc")]
+    pub fn deserialize(blob: &[u8]) -> Option<Self> {
+        super::blob::deserialize(blob)
+    }
+
+    /// Returns an iterator over the keys whose values differ between this map
+    /// and an older version of it. This includes keys that got added, keys
+    /// that got removed and keys whose value changed. This can be used to only
+    /// react to the settings that the user actually changed, by keeping the
+    /// previously loaded map around and comparing it to the newly loaded one.
+    ///
+    /// The values are compared with [`Value`]'s [`PartialEq`] implementation.
+    /// So values of an unknown type, as well as `f64` values that are NaN,
+    /// never compare equal, which means that their keys are returned every
+    /// time.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use asr::settings::Map;
+    /// let mut previous = Map::load();
+    /// loop {
+    ///     let current = Map::load();
+    ///     for key in current.changed_keys_since(&previous) {
+    ///         asr::print_message(&key);
+    ///     }
+    ///     previous = current;
+    ///     # break;
+    /// }
+    /// ```
+    #[cfg(feature = "alloc")]
+    pub fn changed_keys_since<'a>(
+        &'a self,
+        other: &'a Map,
+    ) -> impl Iterator<Item = alloc::string::String> + 'a {
+        changed_keys(
+            self.iter(),
+            other.keys(),
+            |key| other.get(key),
+            |key| self.get(key),
+        )
+    }
+}
+
+/// Returns the keys of the current entries whose values differ from the
+/// previous ones, followed by the previous keys that are no longer present.
+#[cfg(feature = "alloc")]
+fn changed_keys<'a, K: 'a, V: PartialEq + 'a>(
+    current: impl Iterator<Item = (K, V)> + 'a,
+    previous_keys: impl Iterator<Item = K> + 'a,
+    get_previous: impl Fn(&K) -> Option<V> + 'a,
+    get_current: impl Fn(&K) -> Option<V> + 'a,
+) -> impl Iterator<Item = K> + 'a {
+    let changed = current
+        .filter(move |(key, value)| get_previous(key).as_ref() != Some(value))
+        .map(|(key, _)| key);
+    let removed = previous_keys.filter(move |key| get_current(key).is_none());
+    changed.chain(removed)
+}
+
+#[cfg(all(test, feature = "alloc"))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn changed_keys_of_two_maps() {
+        let previous = [
+            ("same", 1.0),
+            ("changed", 2.0),
+            ("removed", 3.0),
+            ("nan", f64::NAN),
+        ];
+        let current = [
+            ("same", 1.0),
+            ("changed", 4.0),
+            ("added", 5.0),
+            ("nan", f64::NAN),
+        ];
+        fn get<'a>(entries: &'a [(&str, f64)]) -> impl Fn(&&str) -> Option<f64> + 'a {
+            move |key| entries.iter().find(|(k, _)| k == key).map(|&(_, v)| v)
+        }
+
+        let changed: alloc::vec::Vec<_> = changed_keys(
+            current.iter().copied(),
+            previous.iter().map(|&(key, _)| key),
+            get(&previous),
+            get(&current),
+        )
+        .collect();
+        assert_eq!(changed, ["changed", "added", "nan", "removed"]);
+    }
 }
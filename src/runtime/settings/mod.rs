@@ -54,6 +54,8 @@
 //!
 //! Check the [`Map`](struct@Map) struct for more information.
 
+#[cfg(feature = "alloc")]
+mod blob;
 pub mod gui;
 mod list;
 mod map;
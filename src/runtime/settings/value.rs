@@ -280,6 +280,23 @@ impl Value {
     }
 }
 
+#[cfg(feature = "alloc")]
+impl PartialEq for Value {
+    /// Compares the contents of two setting values. Maps and lists are compared
+    /// recursively. Values of an unknown type are never considered equal.
+    fn eq(&self, other: &Self) -> bool {
+        match (self.get_type(), other.get_type()) {
+            (ValueType::Map, ValueType::Map) => self.get_map() == other.get_map(),
+            (ValueType::List, ValueType::List) => self.get_list() == other.get_list(),
+            (ValueType::Bool, ValueType::Bool) => self.get_bool() == other.get_bool(),
+            (ValueType::I64, ValueType::I64) => self.get_i64() == other.get_i64(),
+            (ValueType::F64, ValueType::F64) => self.get_f64() == other.get_f64(),
+            (ValueType::String, ValueType::String) => self.get_string() == other.get_string(),
+            _ => false,
+        }
+    }
+}
+
 impl Drop for Value {
     #[inline]
     fn drop(&mut self) {
@@ -0,0 +1,70 @@
+//! Statistics about the memory reads issued by the auto splitter. This is
+//! useful for profiling slow auto splitters, as it allows finding the hot
+//! paths that issue the most reads and that may be worth batching.
+//!
+//! # Example
+//!
+//! ```no_run
+//! # use asr::stats;
+//! # fn example() {
+//! // At the start of the tick:
+//! stats::reset();
+//!
+//! // ... Run the logic of the auto splitter ...
+//!
+//! // At the end of the tick:
+//! let stats = stats::snapshot();
+//! asr::print_limited::<128>(&format_args!(
+//!     "{} reads, {} bytes, {} pointer paths",
+//!     stats.reads, stats.bytes, stats.pointer_paths,
+//! ));
+//! # }
+//! ```
+
+use core::sync::atomic::{AtomicU64, Ordering};
+
+static READS: AtomicU64 = AtomicU64::new(0);
+static BYTES: AtomicU64 = AtomicU64::new(0);
+static POINTER_PATHS: AtomicU64 = AtomicU64::new(0);
+
+/// A snapshot of the read statistics since the last [`reset`].
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct Stats {
+    /// The amount of reads issued to the runtime. Every method on
+    /// [`Process`](crate::Process) that reads memory results in one or more
+    /// of these.
+    pub reads: u64,
+    /// The total amount of bytes requested by all the reads.
+    pub bytes: u64,
+    /// The amount of times
+    /// [`read_pointer_path`](crate::Process::read_pointer_path) got called.
+    pub pointer_paths: u64,
+}
+
+/// Returns the read statistics accumulated since the last [`reset`].
+pub fn snapshot() -> Stats {
+    Stats {
+        reads: READS.load(Ordering::Relaxed),
+        bytes: BYTES.load(Ordering::Relaxed),
+        pointer_paths: POINTER_PATHS.load(Ordering::Relaxed),
+    }
+}
+
+/// Resets all the read statistics to zero.
+pub fn reset() {
+    READS.store(0, Ordering::Relaxed);
+    BYTES.store(0, Ordering::Relaxed);
+    POINTER_PATHS.store(0, Ordering::Relaxed);
+}
+
+#[inline]
+pub(crate) fn record_read(bytes: usize) {
+    READS.fetch_add(1, Ordering::Relaxed);
+    BYTES.fetch_add(bytes as u64, Ordering::Relaxed);
+}
+
+#[inline]
+pub(crate) fn record_pointer_path() {
+    POINTER_PATHS.fetch_add(1, Ordering::Relaxed);
+}
@@ -175,7 +175,7 @@ extern "C" {
     /// `buf_len_ptr` got set to 0, the path does not exist or failed to get
     /// read. The path is guaranteed to be valid UTF-8 and is not
     /// nul-terminated.
-    #[cfg(feature = "alloc")]
+    #[cfg(any(feature = "alloc", feature = "verbose-reads"))]
     pub fn process_get_path(process: Process, buf_ptr: *mut u8, buf_len_ptr: *mut usize) -> bool;
     /// Gets the number of memory ranges in a given process.
     pub fn process_get_memory_range_count(process: Process) -> Option<NonZeroU64>;
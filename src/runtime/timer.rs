@@ -118,3 +118,139 @@ pub fn set_game_time(time: time::Duration) {
     // SAFETY: It is always safe to call this function.
     unsafe { sys::timer_set_game_time(time.whole_seconds(), time.subsec_nanoseconds()) }
 }
+
+/// How the game time needs to change when the loading state changes.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+enum GameTimeTransition {
+    Pause,
+    Resume,
+}
+
+/// Removes loading times from the game time. Every tick, the auto splitter
+/// tells the load remover whether the game is currently loading. The game time
+/// is only paused and resumed when that changes, so the runtime is never asked
+/// to pause the game time twice, and a loading flag that flickers for a single
+/// tick simply results in a short pause. Combine this with a
+/// [`Watcher`](crate::watcher::Watcher) if the loading state needs to be
+/// derived from a value that changes.
+///
+/// # Example
+///
+/// ```no_run
+/// # use asr::{timer::LoadRemover, Address, Process};
+/// # fn example(process: &Process, address: Address) {
+/// let mut load_remover = LoadRemover::new();
+/// // Every tick:
+/// load_remover.update(process.read::<u8>(address).is_ok_and(|loading| loading != 0));
+/// # }
+/// ```
+#[derive(Debug, Default)]
+pub struct LoadRemover {
+    is_loading: bool,
+    #[cfg(target_os = "wasi")]
+    loading_since: Option<crate::time_util::Instant>,
+    #[cfg(target_os = "wasi")]
+    removed: core::time::Duration,
+}
+
+impl LoadRemover {
+    /// Creates a new load remover that assumes the game is not loading.
+    pub const fn new() -> Self {
+        Self {
+            is_loading: false,
+            #[cfg(target_os = "wasi")]
+            loading_since: None,
+            #[cfg(target_os = "wasi")]
+            removed: core::time::Duration::ZERO,
+        }
+    }
+
+    /// Updates whether the game is currently loading, pausing the game time
+    /// when a load starts and resuming it when the load ends.
+    pub fn update(&mut self, is_loading: bool) {
+        match self.transition(is_loading) {
+            Some(GameTimeTransition::Pause) => {
+                pause_game_time();
+                #[cfg(target_os = "wasi")]
+                {
+                    self.loading_since = Some(crate::time_util::Instant::now());
+                }
+            }
+            Some(GameTimeTransition::Resume) => {
+                resume_game_time();
+                #[cfg(target_os = "wasi")]
+                if let Some(loading_since) = self.loading_since.take() {
+                    self.removed += loading_since.elapsed();
+                }
+            }
+            None => {}
+        }
+    }
+
+    /// Updates whether the game is currently loading and returns how the game
+    /// time needs to change, if at all.
+    const fn transition(&mut self, is_loading: bool) -> Option<GameTimeTransition> {
+        if is_loading == self.is_loading {
+            return None;
+        }
+        self.is_loading = is_loading;
+        Some(if is_loading {
+            GameTimeTransition::Pause
+        } else {
+            GameTimeTransition::Resume
+        })
+    }
+
+    /// Returns [`true`] if the game is currently considered to be loading.
+    pub const fn is_loading(&self) -> bool {
+        self.is_loading
+    }
+
+    /// Returns the total amount of time that has been removed so far,
+    /// including the load that is currently in progress.
+    #[cfg(target_os = "wasi")]
+    pub fn removed_time(&self) -> core::time::Duration {
+        self.removed
+            + self
+                .loading_since
+                .map_or(core::time::Duration::ZERO, |since| since.elapsed())
+    }
+
+    /// Resets the load remover, for example when the timer gets reset. If a
+    /// load is in progress, the game time is resumed.
+    pub fn reset(&mut self) {
+        self.update(false);
+        #[cfg(target_os = "wasi")]
+        {
+            self.removed = core::time::Duration::ZERO;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn flickering_loading_flag_pauses_and_resumes_once_per_edge() {
+        use GameTimeTransition::{Pause, Resume};
+
+        let mut load_remover = LoadRemover::new();
+        let transitions = [false, true, false, true, true, true, false, false]
+            .map(|is_loading| load_remover.transition(is_loading));
+        assert_eq!(
+            transitions,
+            [
+                None,
+                Some(Pause),
+                Some(Resume),
+                Some(Pause),
+                None,
+                None,
+                Some(Resume),
+                None
+            ],
+        );
+        assert!(!load_remover.is_loading());
+    }
+}
@@ -2,9 +2,10 @@
 
 use core::mem::{self, MaybeUninit};
 
+use arrayvec::ArrayVec;
 use bytemuck::AnyBitPattern;
 
-use crate::{Address, Process};
+use crate::{Address, Error, Process};
 
 type Offset = u8;
 
@@ -164,7 +165,21 @@ impl<const N: usize> Signature<N> {
         }
     }
 
-    fn scan(&self, haystack: &[u8]) -> Option<usize> {
+    /// Scans a buffer for the signature. If the signature is found, the offset
+    /// of the start of the signature within the buffer is returned. This is
+    /// useful for scanning memory that has already been read from the process,
+    /// or for testing signatures without a process.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use asr::signature::Signature;
+    /// const SIG: Signature<4> = Signature::new("8B ?? 24 ??");
+    /// let haystack = [0x90, 0x90, 0x8B, 0x44, 0x24, 0x08, 0xC3];
+    /// assert_eq!(SIG.scan_buf(&haystack), Some(2));
+    /// assert_eq!(SIG.scan_buf(&haystack[3..]), None);
+    /// ```
+    pub fn scan_buf(&self, haystack: &[u8]) -> Option<usize> {
         match self {
             Signature::Simple(needle) => memchr::memmem::find(haystack, needle),
             Signature::Complex {
@@ -194,30 +209,463 @@ impl<const N: usize> Signature<N> {
         process: &Process,
         (addr, len): (impl Into<Address>, u64),
     ) -> Option<Address> {
-        let mut addr: Address = Into::into(addr);
-        // TODO: Handle the case where a signature may be cut in half by a page
-        // boundary.
-        let overall_end = addr.value() + len;
+        self.scan_process_range_chunked::<{ 4 << 10 }>(process, (addr, len))
+    }
+
+    /// Checks whether the bytes at the address given match the signature.
+    /// Unlike scanning, this only looks at a single address, which makes it
+    /// cheap enough to verify an address that got resolved in some other way,
+    /// such as from a previous scan or a known offset, before trusting the
+    /// values derived from it. If the memory can't be read, this returns
+    /// `false`.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use asr::{signature::Signature, Address, Process};
+    /// # fn example(process: &Process, address: Address) {
+    /// // mov rax, [rip + disp32]
+    /// const SIG: Signature<7> = Signature::new("48 8B 05 ?? ?? ?? ??");
+    /// if SIG.matches_at(process, address) {
+    ///     // The displacement can be read from `address + 3`.
+    /// }
+    /// # }
+    /// ```
+    pub fn matches_at(&self, process: &Process, address: impl Into<Address>) -> bool {
+        let mut buf = [MaybeUninit::uninit(); N];
+        let Ok(bytes) = process.read_into_uninit_buf(address, &mut buf) else {
+            return false;
+        };
+        let Ok(bytes) = <&[u8; N]>::try_from(&*bytes) else {
+            return false;
+        };
+        match self {
+            Signature::Simple(needle) => bytes == needle,
+            Signature::Complex { needle, mask, .. } => matches(bytes, needle, mask),
+        }
+    }
+
+    /// Scans a process for the signature, reading `CHUNK` bytes of memory at
+    /// a time. This works just like
+    /// [`scan_process_range`](Self::scan_process_range), which reads a single
+    /// 4 KiB page at a time. Reading larger chunks reduces the overhead of
+    /// reading from the process, which can speed up scanning large modules
+    /// such as `GameAssembly.dll` considerably. The chunk is stored on the
+    /// stack, so it shouldn't be too large. If a chunk can't be read as a
+    /// whole, because some of its pages are not mapped, it is scanned page by
+    /// page instead. `CHUNK` needs to be a non-zero multiple of 4 KiB.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use asr::{signature::Signature, Address, Process};
+    /// # fn example(process: &Process, module: (Address, u64)) {
+    /// const SIG: Signature<4> = Signature::new("48 8B 05 ??");
+    /// // Read 64 KiB at a time.
+    /// let address = SIG.scan_process_range_chunked::<{ 64 << 10 }>(process, module);
+    /// # }
+    /// ```
+    pub fn scan_process_range_chunked<const CHUNK: usize>(
+        &self,
+        process: &Process,
+        (addr, len): (impl Into<Address>, u64),
+    ) -> Option<Address> {
+        // Chunks are read along page boundaries, so this is checked at compile
+        // time.
+        const { assert!(CHUNK != 0 && CHUNK.is_multiple_of(4 << 10)) };
+
+        let mut window = [[0; N]; 2];
+        scan_chunks::<CHUNK>(
+            &mut |addr, buf| process.read_into_uninit_buf_quietly(addr, buf),
+            (addr.into(), len),
+            &mut window.as_flattened_mut()[..2 * N.saturating_sub(1)],
+            &mut 0,
+            &|buf| self.scan_buf(buf),
+        )
+    }
+
+    /// Scans a process for the signature, starting from the end of the address
+    /// range given and searching backwards. If the signature is found, the
+    /// address of the start of the last match is returned. This is useful if
+    /// the signature is known to be located close to the end of a large range.
+    pub fn scan_process_range_reverse(
+        &self,
+        process: &Process,
+        (addr, len): (impl Into<Address>, u64),
+    ) -> Option<Address> {
+        let start = Into::<Address>::into(addr).value();
+        let mut end = start + len;
         let mut buf = [MaybeUninit::uninit(); 4 << 10];
-        while addr.value() < overall_end {
-            // We round up to the 4 KiB address boundary as that's a single
-            // page, which is safe to read either fully or not at all. We do
-            // this to do a single read rather than many small ones as the
-            // syscall overhead is a quite high.
-            let end = (addr.value() & !((4 << 10) - 1)) + (4 << 10).min(overall_end);
-            let len = end - addr.value();
-            let current_read_buf = &mut buf[..len as usize];
-            if let Ok(current_read_buf) = process.read_into_uninit_buf(addr, current_read_buf) {
-                if let Some(pos) = self.scan(current_read_buf) {
-                    return Some(addr.add(pos as u64));
+        // Matches that are cut in half by a page boundary are found by
+        // scanning the end of each page together with the start of the page
+        // after it, which is kept around from the previous iteration.
+        let overlap = N.saturating_sub(1);
+        let mut window = [[0; N]; 2];
+        let window = window.as_flattened_mut();
+        let mut head_len = 0;
+        while end > start {
+            // Same as when scanning forwards, we read a single page at a time,
+            // but starting with the last one.
+            let page_start = ((end - 1) & !((4 << 10) - 1)).max(start);
+            let current_read_buf = &mut buf[..(end - page_start) as usize];
+            match process.read_into_uninit_buf_quietly(page_start, current_read_buf) {
+                Ok(page) => {
+                    // A match that starts in this page and ends in the next
+                    // one comes after every match that is fully inside of
+                    // this page, so it is checked first.
+                    let tail_len = page.len().min(overlap);
+                    let tail_start = page.len() - tail_len;
+                    if head_len != 0 {
+                        window.copy_within(..head_len, tail_len);
+                        window[..tail_len].copy_from_slice(&page[tail_start..]);
+                        if let Some(pos) = self.rscan(&window[..tail_len + head_len]) {
+                            return Some(Address::new(page_start + (tail_start + pos) as u64));
+                        }
+                    }
+                    if let Some(pos) = self.rscan(page) {
+                        return Some(Address::new(page_start + pos as u64));
+                    }
+                    head_len = page.len().min(overlap);
+                    window[..head_len].copy_from_slice(&page[..head_len]);
                 }
-            };
-            addr = Address::new(end);
+                Err(_) => head_len = 0,
+            }
+            end = page_start;
         }
         None
     }
+
+    fn rscan(&self, haystack: &[u8]) -> Option<usize> {
+        let mut last = None;
+        let mut current = 0;
+        while let Some(pos) = self.scan_buf(&haystack[current..]) {
+            last = Some(current + pos);
+            current += pos + 1;
+        }
+        last
+    }
+
+    /// Scans a process for the signature and resolves the x86-64 RIP-relative
+    /// address referenced by an instruction in it. The 32-bit displacement is
+    /// read at `sig_offset` bytes from the start of the signature. As the
+    /// displacement is relative to the end of the instruction, `instr_len` is
+    /// the offset of the end of the instruction from the start of the
+    /// signature. If the signature starts with the instruction, this is simply
+    /// its length.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use asr::{signature::Signature, Address, Process};
+    /// # fn example(process: &Process, range: (Address, u64)) {
+    /// // lea rcx, [rip + disp32]
+    /// const SIG: Signature<7> = Signature::new("48 8D 0D ?? ?? ?? ??");
+    /// let address = SIG.scan_rip_relative(process, range, 3, 7);
+    /// # }
+    /// ```
+    pub fn scan_rip_relative(
+        &self,
+        process: &Process,
+        range: (impl Into<Address>, u64),
+        sig_offset: u8,
+        instr_len: u8,
+    ) -> Option<Address> {
+        let addr = self.scan_process_range(process, range)?;
+        let displacement = process.read::<i32>(addr + sig_offset as u64).ok()?;
+        Some(resolve_rip_relative(
+            addr,
+            i32::from_le(displacement),
+            instr_len,
+        ))
+    }
+
+    /// Scans a buffer for the signature and resolves the x86-64 RIP-relative
+    /// address referenced by an instruction in it, just like
+    /// [`scan_rip_relative`](Self::scan_rip_relative) does for a process.
+    /// The buffer is assumed to be located at the address provided.
+    pub fn scan_rip_relative_buf(
+        &self,
+        haystack: &[u8],
+        address: impl Into<Address>,
+        sig_offset: u8,
+        instr_len: u8,
+    ) -> Option<Address> {
+        let pos = self.scan_buf(haystack)?;
+        let start = pos + sig_offset as usize;
+        let displacement = haystack.get(start..start + 4)?;
+        let displacement = i32::from_le_bytes(displacement.try_into().ok()?);
+        Some(resolve_rip_relative(
+            Into::<Address>::into(address) + pos as u64,
+            displacement,
+            instr_len,
+        ))
+    }
+}
+
+/// Resolves the address referenced by a RIP-relative displacement of an
+/// instruction found at the address provided.
+fn resolve_rip_relative(addr: Address, displacement: i32, instr_len: u8) -> Address {
+    (addr + instr_len as u64).add_signed(displacement as i64)
+}
+
+/// A cache for the results of signature scans. Scanning for signatures can
+/// take a while, so if an auto splitter attaches to the same game over and
+/// over again, such as when the game crashes a lot, it may be worth keeping
+/// the results around. Each result is stored based on a name identifying the
+/// signature and the address and size of the module that got scanned. If the
+/// module is loaded at a different address or has a different size, the
+/// signature is scanned for again. The cache can hold up to `N` results.
+/// Once it's full, further results are not cached anymore.
+///
+/// # Example
+///
+/// ```no_run
+/// # use asr::{signature::{Signature, SignatureCache}, Process};
+/// # fn example(process: &Process, cache: &mut SignatureCache<4>) {
+/// const SIG: Signature<4> = Signature::new("48 8B 05 ??");
+///
+/// let module = process.get_module_range("Game.exe").unwrap();
+/// let address = cache.scan(process, "static data", &SIG, module);
+/// # }
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct SignatureCache<const N: usize> {
+    entries: ArrayVec<CacheEntry, N>,
+}
+
+#[derive(Copy, Clone, Debug)]
+struct CacheEntry {
+    name: &'static str,
+    module: (Address, u64),
+    result: Address,
 }
 
+impl<const N: usize> SignatureCache<N> {
+    /// Creates a new empty cache.
+    pub const fn new() -> Self {
+        Self {
+            entries: ArrayVec::new_const(),
+        }
+    }
+
+    /// Scans the module given for the signature, unless the result for the
+    /// signature with the same name has already been cached for that module.
+    /// Only signatures that are found get cached.
+    pub fn scan<const M: usize>(
+        &mut self,
+        process: &Process,
+        name: &'static str,
+        signature: &Signature<M>,
+        (addr, len): (impl Into<Address>, u64),
+    ) -> Option<Address> {
+        let module = (addr.into(), len);
+
+        let index = self.entries.iter().position(|entry| entry.name == name);
+        if let Some(index) = index {
+            let entry = self.entries[index];
+            if entry.module == module {
+                return Some(entry.result);
+            }
+            self.entries.remove(index);
+        }
+
+        let result = signature.scan_process_range(process, module)?;
+        let _ = self.entries.try_push(CacheEntry {
+            name,
+            module,
+            result,
+        });
+        Some(result)
+    }
+
+    /// Removes all the cached results.
+    pub fn clear(&mut self) {
+        self.entries.clear();
+    }
+}
+
+/// A signature that is parsed at runtime, such as from a string provided
+/// through the settings. Unlike [`Signature`], the length of the signature is
+/// not part of the type, only the maximum length `CAP` is. This makes it
+/// possible to build signatures in a data-driven way, at the cost of scanning
+/// being slower than with a [`Signature`] parsed at compile time, which should
+/// be preferred whenever the signature is known in advance.
+///
+/// # Example
+///
+/// ```
+/// # use asr::signature::DynSignature;
+/// let sig = DynSignature::<32>::new("8B ?? 24 ??").unwrap();
+/// assert_eq!(sig.len(), 4);
+/// assert_eq!(sig.scan_buf(&[0x90, 0x8B, 0x44, 0x24, 0x08]), Some(1));
+///
+/// // Invalid signatures are rejected instead of panicking.
+/// assert!(DynSignature::<32>::new("8B 4").is_none());
+/// assert!(DynSignature::<32>::new("XY").is_none());
+/// ```
+#[derive(Clone, Debug)]
+pub struct DynSignature<const CAP: usize> {
+    needle: ArrayVec<u8, CAP>,
+    mask: ArrayVec<u8, CAP>,
+}
+
+impl<const CAP: usize> DynSignature<CAP> {
+    /// Parses a signature from a string. The string uses the same format as
+    /// [`Signature::new`], a hexadecimal string with `?` as wildcard. Returns
+    /// [`None`] if the signature is empty, invalid or longer than `CAP`
+    /// bytes.
+    pub fn new(signature: &str) -> Option<Self> {
+        let mut nibbles = signature
+            .bytes()
+            .filter(|b| !matches!(b, b' ' | b'\r' | b'\n' | b'\t'))
+            .map(|b| match b {
+                b'0'..=b'9' => Some(b - b'0'),
+                b'a'..=b'f' => Some(b - b'a' + 0xA),
+                b'A'..=b'F' => Some(b - b'A' + 0xA),
+                b'?' => Some(0x10),
+                _ => None,
+            });
+
+        let mut needle = ArrayVec::new();
+        let mut mask = ArrayVec::new();
+        while let Some(a) = nibbles.next() {
+            let (a, b) = (a?, nibbles.next()??);
+            let sig_byte = (a << 4) | (b & 0x0F);
+            let mask_byte = ((a != 0x10) as u8 * 0xF0) | ((b != 0x10) as u8 * 0x0F);
+            needle.try_push(sig_byte & mask_byte).ok()?;
+            mask.try_push(mask_byte).ok()?;
+        }
+
+        if needle.is_empty() {
+            return None;
+        }
+
+        Some(Self { needle, mask })
+    }
+
+    /// Returns the length of the signature in bytes.
+    #[allow(clippy::len_without_is_empty)]
+    pub const fn len(&self) -> usize {
+        self.needle.len()
+    }
+
+    /// Scans a buffer for the signature. If the signature is found, the offset
+    /// of the start of the signature within the buffer is returned.
+    pub fn scan_buf(&self, haystack: &[u8]) -> Option<usize> {
+        if self.mask.iter().all(|&mask| mask == 0xFF) {
+            return memchr::memmem::find(haystack, &self.needle);
+        }
+        haystack.windows(self.len()).position(|window| {
+            window
+                .iter()
+                .zip(&self.needle)
+                .zip(&self.mask)
+                .all(|((&byte, &needle), &mask)| byte & mask == needle)
+        })
+    }
+
+    /// Scans a process for the signature. This will scan the address range of
+    /// the process given. If the signature is found, the address of the start
+    /// of the signature is returned.
+    pub fn scan_process_range(
+        &self,
+        process: &Process,
+        (addr, len): (impl Into<Address>, u64),
+    ) -> Option<Address> {
+        let mut window = [[0; CAP]; 2];
+        scan_chunks::<{ 4 << 10 }>(
+            &mut |addr, buf| process.read_into_uninit_buf_quietly(addr, buf),
+            (addr.into(), len),
+            &mut window.as_flattened_mut()[..2 * self.len().saturating_sub(1)],
+            &mut 0,
+            &|buf| self.scan_buf(buf),
+        )
+    }
+
+    /// Checks whether the bytes at the address given match the signature. See
+    /// [`Signature::matches_at`] for more information.
+    pub fn matches_at(&self, process: &Process, address: impl Into<Address>) -> bool {
+        let mut buf = [MaybeUninit::uninit(); CAP];
+        process
+            .read_into_uninit_buf(address, &mut buf[..self.len()])
+            .is_ok_and(|bytes| {
+                bytes
+                    .iter()
+                    .zip(&self.needle)
+                    .zip(&self.mask)
+                    .all(|((&byte, &needle), &mask)| byte & mask == needle)
+            })
+    }
+}
+
+/// Scans the address range of the process given `CHUNK` bytes at a time,
+/// calling the function provided for each chunk that could be read. Matches
+/// that are cut in half by a chunk boundary are found by also scanning the end
+/// of each chunk together with the start of the next one. Both of them are
+/// copied into the `window` provided, which needs to be twice as long as the
+/// longest possible match minus one byte. The first `tail_len` bytes of the
+/// window are the end of the memory right before the range, which is empty
+/// for the first range, but not for the chunks that are scanned page by page.
+fn scan_chunks<const CHUNK: usize>(
+    read: &mut ReadChunk<'_>,
+    (mut addr, len): (Address, u64),
+    window: &mut [u8],
+    tail_len: &mut usize,
+    scan: &dyn Fn(&[u8]) -> Option<usize>,
+) -> Option<Address> {
+    let overall_end = addr.value() + len;
+    let overlap = window.len() / 2;
+    let mut buf = [MaybeUninit::uninit(); CHUNK];
+    while addr.value() < overall_end {
+        // We round down to the 4 KiB address boundary as that's a single
+        // page, which is safe to read either fully or not at all. This way
+        // only the first and the last chunk are shorter than the chunk size.
+        // We do this to do a few large reads rather than many small ones as
+        // the syscall overhead is a quite high.
+        let end = ((addr.value() & !((4 << 10) - 1)) + CHUNK as u64).min(overall_end);
+        let len = end - addr.value();
+        let current_read_buf = &mut buf[..len as usize];
+        match read(addr, current_read_buf) {
+            Ok(chunk) => {
+                // Any match in the window needs to start in the previous
+                // chunk, as the window is too short to fit one otherwise.
+                if *tail_len != 0 {
+                    let head_len = chunk.len().min(overlap);
+                    window[*tail_len..*tail_len + head_len].copy_from_slice(&chunk[..head_len]);
+                    if let Some(pos) = scan(&window[..*tail_len + head_len]) {
+                        return Some(Address::new(addr.value() - (*tail_len - pos) as u64));
+                    }
+                }
+                if let Some(pos) = scan(chunk) {
+                    return Some(addr.add(pos as u64));
+                }
+                *tail_len = chunk.len().min(overlap);
+                window[..*tail_len].copy_from_slice(&chunk[chunk.len() - *tail_len..]);
+            }
+            // If some of the pages of the chunk are not mapped, we fall back
+            // to scanning it page by page. This continues with the end of the
+            // previous chunk and leaves the end of the last readable page in
+            // the window, so matches across the pages that are mapped are
+            // still found.
+            Err(_) if CHUNK > 4 << 10 => {
+                if let Some(address) =
+                    scan_chunks::<{ 4 << 10 }>(read, (addr, len), window, tail_len, scan)
+                {
+                    return Some(address);
+                }
+            }
+            // Nothing can match across memory that is not mapped.
+            Err(_) => *tail_len = 0,
+        }
+        addr = Address::new(end);
+    }
+    None
+}
+
+/// Reads the bytes at the address given into the buffer provided.
+type ReadChunk<'a> =
+    dyn for<'buf> FnMut(Address, &'buf mut [MaybeUninit<u8>]) -> Result<&'buf mut [u8], Error> + 'a;
+
 fn matches<const N: usize>(scan: &[u8; N], needle: &[u8; N], mask: &[u8; N]) -> bool {
     // SAFETY: Before reading individual chunks from the arrays, we check that
     // we can still read values of that size. We also read them unaligned as the
@@ -297,3 +745,101 @@ fn strip_pod<'a, T: AnyBitPattern>(cursor: &mut &'a [u8]) -> Option<&'a T> {
     *cursor = after;
     Some(bytemuck::from_bytes(before))
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn rscan_finds_the_last_match() {
+        const SIG: Signature<3> = Signature::new("AA ?? CC");
+        let haystack = [0xAA, 0x00, 0xCC, 0xAA, 0x01, 0xCC, 0xAA, 0x02];
+        assert_eq!(SIG.scan_buf(&haystack), Some(0));
+        assert_eq!(SIG.rscan(&haystack), Some(3));
+        assert_eq!(SIG.rscan(&haystack[4..]), None);
+    }
+
+    #[test]
+    fn rscan_finds_overlapping_matches() {
+        const SIG: Signature<2> = Signature::new("AA AA");
+        assert_eq!(SIG.rscan(&[0xAA; 5]), Some(3));
+    }
+
+    #[test]
+    fn scan_rip_relative_buf_resolves_lea_and_mov() {
+        const LEA: Signature<7> = Signature::new("48 8D 0D ?? ?? ?? ??");
+        const MOV: Signature<10> = Signature::new("C3 48 8B 05 ?? ?? ?? ?? 48 85");
+
+        let mut haystack = [0xCC; 0x40];
+        // lea rcx, [rip + 0x100]
+        haystack[0x10..0x17].copy_from_slice(&[0x48, 0x8D, 0x0D, 0x00, 0x01, 0x00, 0x00]);
+        // ret; mov rax, [rip - 0x20]; test rax, rax
+        haystack[0x20..0x2A]
+            .copy_from_slice(&[0xC3, 0x48, 0x8B, 0x05, 0xE0, 0xFF, 0xFF, 0xFF, 0x48, 0x85]);
+
+        let base = Address::new(0x1000);
+        assert_eq!(
+            LEA.scan_rip_relative_buf(&haystack, base, 3, 7),
+            Some(Address::new(0x1000 + 0x17 + 0x100)),
+        );
+        assert_eq!(
+            MOV.scan_rip_relative_buf(&haystack, base, 4, 8),
+            Some(Address::new(0x1000 + 0x28 - 0x20)),
+        );
+        assert_eq!(
+            LEA.scan_rip_relative_buf(&haystack[..0x16], base, 3, 7),
+            None
+        );
+    }
+
+    /// Scans 24 KiB of memory 8 KiB at a time, with the page given not being
+    /// mapped.
+    fn scan_with_unmapped_page(memory: &[u8; 24 << 10], unmapped: usize) -> Option<Address> {
+        const SIG: Signature<4> = Signature::new("DE AD BE EF");
+        let read: &mut ReadChunk<'_> = &mut |addr, buf| {
+            let start = addr.value() as usize;
+            let end = start + buf.len();
+            if (start..end).any(|byte| byte >> 12 == unmapped) {
+                return Err(Error {});
+            }
+            for (dst, &src) in buf.iter_mut().zip(&memory[start..end]) {
+                dst.write(src);
+            }
+            // SAFETY: Every byte of the buffer got initialized above.
+            Ok(unsafe { &mut *(buf as *mut [MaybeUninit<u8>] as *mut [u8]) })
+        };
+        let mut window = [0; 6];
+        scan_chunks::<{ 8 << 10 }>(
+            read,
+            (Address::NULL, memory.len() as u64),
+            &mut window,
+            &mut 0,
+            &|buf| SIG.scan_buf(buf),
+        )
+    }
+
+    #[test]
+    fn chunked_scan_finds_matches_next_to_unmapped_pages() {
+        let mut memory = [0; 24 << 10];
+
+        // The match starts in the first chunk and ends in the second one,
+        // which is scanned page by page, as its last page is not mapped.
+        memory[0x1FFE..0x2002].copy_from_slice(&[0xDE, 0xAD, 0xBE, 0xEF]);
+        assert_eq!(
+            scan_with_unmapped_page(&memory, 3),
+            Some(Address::new(0x1FFE))
+        );
+
+        // The match starts on the last page of the second chunk, whose first
+        // page is not mapped, and ends in the third chunk.
+        memory[0x1FFE..0x2002].fill(0);
+        memory[0x3FFE..0x4002].copy_from_slice(&[0xDE, 0xAD, 0xBE, 0xEF]);
+        assert_eq!(
+            scan_with_unmapped_page(&memory, 2),
+            Some(Address::new(0x3FFE))
+        );
+
+        // The start of the match is on a page that is not mapped.
+        assert_eq!(scan_with_unmapped_page(&memory, 3), None);
+    }
+}
@@ -41,6 +41,45 @@ impl<const N: usize> ArrayCString<N> {
             && self.0.get(..bytes.len()).is_some_and(|s| s == bytes)
     }
 
+    /// Checks whether the string matches the given text, ignoring the case of
+    /// ASCII letters. This is useful for names that may differ in case, such
+    /// as file names on Windows.
+    pub fn matches_ignore_ascii_case(&self, text: impl AsRef<[u8]>) -> bool {
+        let bytes = text.as_ref();
+        self.0.get(bytes.len()).is_none_or(|&b| b == 0)
+            && self
+                .0
+                .get(..bytes.len())
+                .is_some_and(|s| s.eq_ignore_ascii_case(bytes))
+    }
+
+    /// Returns the index of the first of the candidates that the string
+    /// [matches](Self::matches), if any.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use asr::string::ArrayCString;
+    /// let name: ArrayCString<8> = bytemuck::cast(*b"pcsx2\0\0\0");
+    /// assert_eq!(name.matches_any(&["dolphin", "pcsx2"]), Some(1));
+    /// assert_eq!(name.matches_any(&["PCSX2"]), None);
+    /// assert_eq!(name.matches_any_ignore_ascii_case(&["PCSX2"]), Some(0));
+    /// ```
+    pub fn matches_any(&self, candidates: &[impl AsRef<[u8]>]) -> Option<usize> {
+        candidates
+            .iter()
+            .position(|candidate| self.matches(candidate))
+    }
+
+    /// Returns the index of the first of the candidates that the string
+    /// [matches](Self::matches_ignore_ascii_case) when ignoring the case of
+    /// ASCII letters, if any.
+    pub fn matches_any_ignore_ascii_case(&self, candidates: &[impl AsRef<[u8]>]) -> Option<usize> {
+        candidates
+            .iter()
+            .position(|candidate| self.matches_ignore_ascii_case(candidate))
+    }
+
     /// Reduces the size of the string contained inside the ArrayString
     /// to the value provided by `len`. If a value higher than the size of the ArrayString
     /// is provided, no action is performed.
@@ -94,6 +133,24 @@ impl<const N: usize> FromEndian for ArrayCString<N> {
 
 /// A nul-terminated wide string (16-bit characters) that is stored in an array
 /// of a fixed size of `N` characters. This can be read from a process's memory.
+///
+/// The characters are stored in the byte order of the target. On big endian
+/// systems, such as the GameCube or the Wii, the string can be read with
+/// [`Process::read_be`](crate::Process::read_be) or the emulator's `read`
+/// method, which take care of converting every character. A string that was
+/// read as is can be converted through [`FromEndian`] as well.
+///
+/// # Example
+///
+/// ```
+/// use asr::{string::ArrayWString, FromEndian};
+///
+/// // "Hi" as it is stored on a big endian system.
+/// let bytes = [0x00, b'H', 0x00, b'i', 0x00, 0x00];
+/// let string: ArrayWString<3> = bytemuck::cast(bytes);
+///
+/// assert!(string.from_be().matches_str("Hi"));
+/// ```
 #[derive(Copy, Clone)]
 #[repr(transparent)]
 pub struct ArrayWString<const N: usize>([u16; N]);
@@ -2,7 +2,9 @@
 
 use core::{
     cell::{RefCell, RefMut, UnsafeCell},
+    fmt,
     marker::PhantomData,
+    mem,
     ops::{Deref, DerefMut},
 };
 
@@ -154,3 +156,115 @@ impl<T> RacyCell<T> {
         self.0.get()
     }
 }
+
+/// A value that is initialized on its first access. This is specifically for
+/// single-threaded WebAssembly and allows declaring globals that can't be
+/// constructed in a `const` context, without having to resort to `static mut`
+/// or a [`RacyCell`].
+///
+/// The value can only be accessed immutably. If it needs to be modified, it
+/// can be wrapped in a [`Mutex`], for example.
+///
+/// # Panics
+///
+/// The initialization must not access the same `Lazy` again, as there would
+/// not be any value to return yet. Doing so panics.
+///
+/// # Example
+///
+/// ```no_run
+/// use asr::{sync::{Lazy, Mutex}, watcher::Watcher};
+///
+/// static WATCHER: Lazy<Mutex<Watcher<u32>>> = Lazy::new(|| Mutex::new(Watcher::new()));
+///
+/// #[no_mangle]
+/// pub extern "C" fn update() {
+///     let mut watcher = WATCHER.lock();
+///     // ...
+/// }
+/// ```
+pub struct Lazy<T, F = fn() -> T> {
+    state: UnsafeCell<LazyState<T, F>>,
+}
+
+enum LazyState<T, F> {
+    Uninit(F),
+    Initializing,
+    Init(T),
+}
+
+impl<T, F> Lazy<T, F> {
+    /// Creates a new lazy value that is initialized with the function
+    /// provided on its first access.
+    #[inline]
+    pub const fn new(f: F) -> Self {
+        Self {
+            state: UnsafeCell::new(LazyState::Uninit(f)),
+        }
+    }
+
+    /// Returns the value if it has already been initialized.
+    #[inline]
+    pub fn get(&self) -> Option<&T> {
+        // SAFETY: The state is only ever modified while it's not initialized
+        // yet, so there are no references to the value at that point. Once
+        // the value is initialized, it never gets modified again.
+        match unsafe { &*self.state.get() } {
+            LazyState::Init(value) => Some(value),
+            _ => None,
+        }
+    }
+}
+
+impl<T, F: FnOnce() -> T> Lazy<T, F> {
+    /// Forces the initialization of the value if it's not initialized yet and
+    /// returns a reference to it.
+    #[track_caller]
+    pub fn force(&self) -> &T {
+        if let Some(value) = self.get() {
+            return value;
+        }
+
+        // SAFETY: The value is not initialized yet, so no references into the
+        // state exist. The state is marked as initializing while the function
+        // runs, so reentrant accesses panic instead of observing it.
+        let f = match mem::replace(unsafe { &mut *self.state.get() }, LazyState::Initializing) {
+            LazyState::Uninit(f) => f,
+            _ => panic!("Lazy instance has been accessed during its initialization"),
+        };
+
+        let value = f();
+
+        // SAFETY: The state is still marked as initializing, so nothing else
+        // can have a reference into it.
+        unsafe { *self.state.get() = LazyState::Init(value) };
+
+        match self.get() {
+            Some(value) => value,
+            None => unreachable!(),
+        }
+    }
+}
+
+impl<T, F: FnOnce() -> T> Deref for Lazy<T, F> {
+    type Target = T;
+
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        self.force()
+    }
+}
+
+impl<T: fmt::Debug, F> fmt::Debug for Lazy<T, F> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.get() {
+            Some(value) => f.debug_tuple("Lazy").field(value).finish(),
+            None => f.write_str("Lazy(<uninit>)"),
+        }
+    }
+}
+
+#[cfg(not(target_feature = "atomics"))]
+// SAFETY: This can only be safe in single-threaded WASM, as the initialization
+// is not synchronized in any way.
+unsafe impl<T: Send + Sync, F: Send> Sync for Lazy<T, F> {}
@@ -13,7 +13,7 @@ mod instant {
 
     use wasi::Timestamp;
 
-    fn current_time() -> Timestamp {
+    fn try_current_time() -> Option<Timestamp> {
         // SAFETY: This is copied from std, so it should be fine.
         // https://github.com/rust-lang/rust/blob/dd5d7c729d4e8a59708df64002e09dbcbc4005ba/library/std/src/sys/wasi/time.rs#L15
         unsafe {
@@ -23,11 +23,17 @@ mod instant {
                 1, // precision... seems ignored though?
                 rp0.as_mut_ptr() as _,
             );
-            assert_eq!(ret, wasi::ERRNO_SUCCESS.raw() as _);
-            rp0.assume_init()
+            if ret != wasi::ERRNO_SUCCESS.raw() as _ {
+                return None;
+            }
+            Some(rp0.assume_init())
         }
     }
 
+    fn current_time() -> Timestamp {
+        try_current_time().expect("The monotonic clock is not available.")
+    }
+
     /// A version of the standard library's `Instant` using WASI that doesn't
     /// need the standard library.
     #[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord)]
@@ -68,6 +74,70 @@ mod instant {
             Self(self.0 + rhs.as_nanos() as u64)
         }
     }
+
+    /// A stopwatch that measures the real time that elapsed since it got
+    /// started or reset, regardless of the tick rate. This is useful for
+    /// throttling work to a certain amount of real time, or for measuring how
+    /// long something takes. The time is measured with the monotonic clock of
+    /// the runtime. If the clock is not available, no time ever elapses, so
+    /// [`elapsed`](Self::elapsed) always returns zero.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use asr::time_util::Stopwatch;
+    /// # fn scan() {}
+    /// # fn example(stopwatch: &mut Stopwatch) {
+    /// // Only scan once per second.
+    /// if stopwatch.elapsed() >= asr::time::Duration::SECOND {
+    ///     stopwatch.reset();
+    ///     scan();
+    /// }
+    /// # }
+    /// ```
+    #[derive(Copy, Clone, Debug)]
+    pub struct Stopwatch {
+        start: Option<Timestamp>,
+    }
+
+    impl Default for Stopwatch {
+        fn default() -> Self {
+            Self::new()
+        }
+    }
+
+    impl Stopwatch {
+        /// Creates a new stopwatch that starts measuring right away.
+        pub fn new() -> Self {
+            Self {
+                start: try_current_time(),
+            }
+        }
+
+        /// Returns the real time that elapsed since the stopwatch got created
+        /// or last reset.
+        pub fn elapsed(&self) -> time::Duration {
+            let nanos = match (self.start, try_current_time()) {
+                (Some(start), Some(now)) => now.saturating_sub(start),
+                _ => 0,
+            };
+            time::Duration::new((nanos / 1_000_000_000) as _, (nanos % 1_000_000_000) as _)
+        }
+
+        /// Restarts measuring the elapsed time from now on.
+        pub fn reset(&mut self) {
+            self.start = try_current_time();
+        }
+
+        /// Returns the real time that elapsed since the stopwatch got created
+        /// or last reset, and then resets it. This is useful for measuring the
+        /// time between two ticks.
+        pub fn lap(&mut self) -> time::Duration {
+            let elapsed = self.elapsed();
+            self.reset();
+            elapsed
+        }
+    }
 }
 #[cfg(target_os = "wasi")]
-pub use self::instant::Instant;
+pub use self::instant::{Instant, Stopwatch};
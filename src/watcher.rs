@@ -1,8 +1,11 @@
 //! Support for watching values and tracking changes between them.
 
-use core::{mem, ops};
+use core::{cmp::Ordering, iter, mem, ops, slice};
 
-use bytemuck::{bytes_of, NoUninit};
+use arrayvec::ArrayVec;
+use bytemuck::{bytes_of, CheckedBitPattern, NoUninit};
+
+use crate::{Address, Error, Process};
 
 /// A watcher keeps a pair of values and allows you to track changes between
 /// them.
@@ -95,6 +98,44 @@ impl<T> Pair<T> {
             current: f(self.current),
         }
     }
+
+    /// Borrows both values of the pair. Combined with [`map`](Self::map),
+    /// this allows tracking a single field of a struct that is watched as a
+    /// whole.
+    pub const fn as_ref(&self) -> Pair<&T> {
+        Pair {
+            old: &self.old,
+            current: &self.current,
+        }
+    }
+
+    /// Checks if the field of a struct that is selected by the function
+    /// provided changed. This allows watching a whole struct, such as one
+    /// that got read with a binding generated by the `Class` derive, and then
+    /// reacting to the individual fields that changed. The field only needs
+    /// to be [`PartialEq`], so floating point numbers are supported as well.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use asr::watcher::Watcher;
+    /// #[derive(Copy, Clone)]
+    /// struct Player {
+    ///     level: u32,
+    ///     x: f32,
+    /// }
+    ///
+    /// let mut watcher = Watcher::new();
+    /// watcher.update_infallible(Player { level: 1, x: 0.0 });
+    /// let pair = watcher.update_infallible(Player { level: 2, x: 0.0 });
+    ///
+    /// assert!(pair.field_changed(|p| &p.level));
+    /// assert!(!pair.field_changed(|p| &p.x));
+    /// assert!(pair.as_ref().map(|p| p.level).changed_from_to(&1, &2));
+    /// ```
+    pub fn field_changed<U: PartialEq + ?Sized>(&self, mut f: impl FnMut(&T) -> &U) -> bool {
+        f(&self.old) != f(&self.current)
+    }
 }
 
 impl<T: Eq> Pair<T> {
@@ -165,3 +206,574 @@ impl<T: PartialOrd> Pair<T> {
         self.old > self.current
     }
 }
+
+/// A history of the last `N` values of something that is sampled regularly,
+/// such as once per tick. Once the history is full, pushing a new value
+/// replaces the oldest one. This is mostly useful for diagnosing values that
+/// are flaky, by inspecting how they changed over the last couple of ticks.
+///
+/// # Example
+///
+/// ```no_run
+/// # use asr::{watcher::History, Address, Process};
+/// # fn example(process: &Process, address: Address, history: &mut History<u32, 60>) {
+/// history.push(process.read(address).unwrap_or_default());
+///
+/// if history.changes() > 10 {
+///     asr::print_message("The value is changing a lot.");
+/// }
+/// # }
+/// ```
+#[derive(Clone)]
+pub struct History<T, const N: usize> {
+    values: ArrayVec<T, N>,
+    start: usize,
+}
+
+impl<T, const N: usize> Default for History<T, N> {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T, const N: usize> History<T, N> {
+    /// Creates a new empty history.
+    #[inline]
+    pub const fn new() -> Self {
+        Self {
+            values: ArrayVec::new_const(),
+            start: 0,
+        }
+    }
+
+    /// Pushes a new value into the history. If the history is full, the oldest
+    /// value is removed.
+    pub fn push(&mut self, value: T) {
+        if let Err(err) = self.values.try_push(value) {
+            if N == 0 {
+                return;
+            }
+            self.values[self.start] = err.element();
+            self.start = (self.start + 1) % N;
+        }
+    }
+
+    /// Removes all the values from the history.
+    #[inline]
+    pub fn clear(&mut self) {
+        self.values.clear();
+        self.start = 0;
+    }
+
+    /// Returns the number of values in the history.
+    #[inline]
+    pub const fn len(&self) -> usize {
+        self.values.len()
+    }
+
+    /// Returns [`true`] if the history doesn't contain any values.
+    #[inline]
+    pub const fn is_empty(&self) -> bool {
+        self.values.is_empty()
+    }
+
+    /// Returns the most recent value.
+    pub fn latest(&self) -> Option<&T> {
+        self.iter().next_back()
+    }
+
+    /// Returns the two most recent values as a [`Pair`].
+    pub fn pair(&self) -> Option<Pair<&T>> {
+        let mut iter = self.iter();
+        let current = iter.next_back()?;
+        let old = iter.next_back()?;
+        Some(Pair { old, current })
+    }
+
+    /// Iterates over the values from the oldest to the most recent one.
+    pub fn iter(&self) -> iter::Chain<slice::Iter<'_, T>, slice::Iter<'_, T>> {
+        let (newer, older) = self.values.split_at(self.start);
+        older.iter().chain(newer)
+    }
+
+    /// Iterates over the last `n` values, from the oldest to the most recent
+    /// one.
+    pub fn last_n(&self, n: usize) -> impl Iterator<Item = &T> + '_ {
+        self.iter().skip(self.len().saturating_sub(n))
+    }
+
+    /// Iterates over all the pairs of consecutive values, from the oldest to
+    /// the most recent one.
+    pub fn pairs(&self) -> impl Iterator<Item = Pair<&T>> + '_ {
+        self.iter()
+            .zip(self.iter().skip(1))
+            .map(|(old, current)| Pair { old, current })
+    }
+}
+
+impl<T: PartialOrd, const N: usize> History<T, N> {
+    /// Returns the smallest value in the history. Values that can't be
+    /// compared, such as NaN, are skipped.
+    pub fn min(&self) -> Option<&T> {
+        self.iter().reduce(|a, b| match b.partial_cmp(a) {
+            Some(Ordering::Less) => b,
+            _ => a,
+        })
+    }
+
+    /// Returns the largest value in the history. Values that can't be
+    /// compared, such as NaN, are skipped.
+    pub fn max(&self) -> Option<&T> {
+        self.iter().reduce(|a, b| match b.partial_cmp(a) {
+            Some(Ordering::Greater) => b,
+            _ => a,
+        })
+    }
+}
+
+impl<T: PartialEq, const N: usize> History<T, N> {
+    /// Returns the number of times the value changed within the history.
+    pub fn changes(&self) -> usize {
+        self.pairs().filter(|pair| pair.old != pair.current).count()
+    }
+
+    /// Checks if the value changed at any point within the history.
+    pub fn changed(&self) -> bool {
+        self.pairs().any(|pair| pair.old != pair.current)
+    }
+}
+
+/// A threshold detector with hysteresis, also known as a Schmitt trigger. It
+/// fires once when the value rises above the high threshold and doesn't fire
+/// again until the value has dropped below the low threshold first. This
+/// prevents values that jitter around a threshold, such as a float position,
+/// from triggering over and over again.
+///
+/// # Example
+///
+/// ```
+/// # use asr::watcher::Schmitt;
+/// let mut trigger = Schmitt::new(10.0, 20.0);
+///
+/// assert!(!trigger.update(15.0));
+/// // Rising above the high threshold fires once.
+/// assert!(trigger.update(21.0));
+/// // Jittering around the high threshold doesn't fire again.
+/// assert!(!trigger.update(19.5));
+/// assert!(!trigger.update(20.5));
+/// // Dropping below the low threshold resets the trigger.
+/// assert!(!trigger.update(9.0));
+/// assert!(!trigger.is_active());
+/// assert!(trigger.update(25.0));
+/// ```
+#[derive(Copy, Clone, Debug)]
+pub struct Schmitt<T> {
+    low: T,
+    high: T,
+    active: bool,
+}
+
+impl<T> Schmitt<T> {
+    /// Creates a new threshold detector with the low and high thresholds
+    /// provided. The detector starts out inactive. The low threshold should
+    /// not be larger than the high threshold.
+    pub const fn new(low: T, high: T) -> Self {
+        Self {
+            low,
+            high,
+            active: false,
+        }
+    }
+
+    /// Returns [`true`] if the value rose above the high threshold and hasn't
+    /// dropped below the low threshold since.
+    pub const fn is_active(&self) -> bool {
+        self.active
+    }
+
+    /// Resets the detector to be inactive, so that it fires the next time the
+    /// value rises above the high threshold, regardless of whether it dropped
+    /// below the low threshold.
+    pub const fn reset(&mut self) {
+        self.active = false;
+    }
+}
+
+impl<T: PartialOrd> Schmitt<T> {
+    /// Updates the detector with a new value. Returns [`true`] only if the
+    /// detector was inactive and the value rose above the high threshold. Once
+    /// active, the detector becomes inactive again when the value drops below
+    /// the low threshold.
+    pub fn update(&mut self, value: T) -> bool {
+        if self.active {
+            if value < self.low {
+                self.active = false;
+            }
+            false
+        } else if value > self.high {
+            self.active = true;
+            true
+        } else {
+            false
+        }
+    }
+}
+
+/// The largest type that can be watched by a [`WatcherList`], in bytes.
+pub const MAX_WATCHED_SIZE: usize = 16;
+
+/// A value in a process that is watched by a [`WatcherList`].
+#[derive(Copy, Clone)]
+struct Entry {
+    name: &'static str,
+    address: Address,
+    len: usize,
+    watcher: Watcher<[u8; MAX_WATCHED_SIZE]>,
+}
+
+impl Entry {
+    const fn new<T: CheckedBitPattern>(
+        name: &'static str,
+        address: Address,
+    ) -> Result<Self, Error> {
+        let len = mem::size_of::<T>();
+        if len > MAX_WATCHED_SIZE {
+            return Err(Error {});
+        }
+        Ok(Self {
+            name,
+            address,
+            len,
+            watcher: Watcher::new(),
+        })
+    }
+
+    fn update(&mut self, bytes: Option<&[u8]>) {
+        let value = bytes.map(|bytes| {
+            let mut buf = [0; MAX_WATCHED_SIZE];
+            buf[..self.len].copy_from_slice(bytes);
+            buf
+        });
+        self.watcher.update(value);
+    }
+
+    const fn end(&self) -> u64 {
+        self.address.value() + self.len as u64
+    }
+
+    fn get<T: CheckedBitPattern>(&self) -> Option<Pair<T>> {
+        if mem::size_of::<T>() != self.len {
+            return None;
+        }
+        let pair = self.watcher.pair.as_ref()?;
+        let decode = |bytes: &[u8; MAX_WATCHED_SIZE]| {
+            bytemuck::checked::try_pod_read_unaligned(&bytes[..self.len]).ok()
+        };
+        Some(Pair {
+            old: decode(&pair.old)?,
+            current: decode(&pair.current)?,
+        })
+    }
+}
+
+fn find<'a>(entries: &'a [Entry], name: &str) -> Option<&'a Entry> {
+    entries.iter().find(|entry| entry.name == name)
+}
+
+/// Returns the index at which an entry needs to be inserted to keep the
+/// entries sorted by their address.
+fn insertion_index(entries: &[Entry], entry: &Entry) -> usize {
+    entries.partition_point(|other| other.address <= entry.address)
+}
+
+/// Values that are closer together than this are read all at once.
+const MAX_BATCH_SIZE: usize = 256;
+
+/// Updates the entries, which need to be sorted by their address. Entries
+/// whose values overlap or are right next to each other are read with a
+/// single call of the function provided, which reads the bytes at the
+/// address given. If such a batch can't be read, each of its values is read
+/// on its own, so a value that can't be read doesn't affect the others.
+fn update_entries(
+    entries: &mut [Entry],
+    mut read_quietly: impl FnMut(Address, &mut [u8]) -> Result<(), Error>,
+    mut read: impl FnMut(Address, &mut [u8]) -> Result<(), Error>,
+) {
+    let mut buf = [0; MAX_BATCH_SIZE];
+    let mut rest = entries;
+    while let Some(first) = rest.first() {
+        let start = first.address;
+        let mut end = first.end();
+        let len = rest
+            .iter()
+            .position(|entry| {
+                let fits = entry.address.value() <= end
+                    && entry.end().max(end) - start.value() <= MAX_BATCH_SIZE as u64;
+                if fits {
+                    end = end.max(entry.end());
+                }
+                !fits
+            })
+            .unwrap_or(rest.len());
+        let (batch, next) = mem::take(&mut rest).split_at_mut(len);
+        rest = next;
+
+        let buf = &mut buf[..(end - start.value()) as usize];
+        if batch.len() > 1 && read_quietly(start, buf).is_ok() {
+            for entry in batch {
+                let offset = (entry.address.value() - start.value()) as usize;
+                entry.update(Some(&buf[offset..][..entry.len]));
+            }
+        } else {
+            for entry in batch {
+                let buf = &mut buf[..entry.len];
+                let value = read(entry.address, buf).ok().map(|_| &*buf);
+                entry.update(value);
+            }
+        }
+    }
+}
+
+/// A list of named values in a process that are all watched at once, with
+/// room for up to `N` values. This is useful for keeping track of a lot of
+/// values, such as for showing them in a debug overlay, without needing a
+/// separate [`Watcher`] for each of them. Each value is registered with its
+/// name, its address and its type, which may be at most
+/// [`MAX_WATCHED_SIZE`] bytes large. Calling [`update`](Self::update) reads
+/// all of them, after which the old and current value of each of them can be
+/// retrieved by its name. Values that overlap or are right next to each other
+/// in memory are read all at once. A [`DynWatcherList`] can hold any number of values
+/// if the `alloc` feature is enabled.
+///
+/// # Example
+///
+/// ```no_run
+/// # use asr::{watcher::WatcherList, Address, Process};
+/// # fn example(process: &Process, level: Address, timer: Address) {
+/// let mut list = WatcherList::<8>::new();
+/// list.register::<u8>("Level", level).ok();
+/// list.register::<f32>("Timer", timer).ok();
+///
+/// // On every tick:
+/// list.update(process);
+/// if list.get::<u8>("Level").is_some_and(|level| level.changed()) {
+///     // The level changed.
+/// }
+/// # }
+/// ```
+#[derive(Clone, Default)]
+pub struct WatcherList<const N: usize> {
+    entries: ArrayVec<Entry, N>,
+}
+
+impl<const N: usize> WatcherList<N> {
+    /// Creates a new empty list.
+    #[inline]
+    pub const fn new() -> Self {
+        Self {
+            entries: ArrayVec::new_const(),
+        }
+    }
+
+    /// Registers a value of the type specified at the address given, which
+    /// can be retrieved by its name afterwards. Returns an error if the list
+    /// is full or the type is larger than [`MAX_WATCHED_SIZE`] bytes. The
+    /// value is read for the first time on the next
+    /// [`update`](Self::update).
+    pub fn register<T: CheckedBitPattern>(
+        &mut self,
+        name: &'static str,
+        address: impl Into<Address>,
+    ) -> Result<(), Error> {
+        let entry = Entry::new::<T>(name, address.into())?;
+        let index = insertion_index(&self.entries, &entry);
+        self.entries.try_insert(index, entry).map_err(|_| Error {})
+    }
+
+    /// Reads all the values from the process. A value that can't be read is
+    /// treated the same way [`Watcher::update`] treats [`None`], so no pair is
+    /// available for it until it can be read again.
+    pub fn update(&mut self, process: &Process) {
+        update_entries(
+            &mut self.entries,
+            |address, buf| process.read_into_buf_quietly(address, buf),
+            |address, buf| process.read_into_buf(address, buf),
+        );
+    }
+
+    /// Returns the old and current value with the name provided. Returns
+    /// [`None`] if there is no such value, if it couldn't be read, if the type
+    /// is not of the same size as the one it was registered with or if the
+    /// bytes are not valid for the type.
+    pub fn get<T: CheckedBitPattern>(&self, name: &str) -> Option<Pair<T>> {
+        find(&self.entries, name)?.get()
+    }
+
+    /// Iterates over the names and addresses of all the values, ordered by
+    /// their addresses.
+    pub fn iter(&self) -> impl Iterator<Item = (&'static str, Address)> + '_ {
+        self.entries.iter().map(|entry| (entry.name, entry.address))
+    }
+
+    /// Removes all the values from the list.
+    #[inline]
+    pub fn clear(&mut self) {
+        self.entries.clear();
+    }
+}
+
+/// A list of named values in a process that are all watched at once. This
+/// works just like a [`WatcherList`], but can hold any number of values.
+#[cfg(feature = "alloc")]
+#[derive(Clone, Default)]
+pub struct DynWatcherList {
+    entries: alloc::vec::Vec<Entry>,
+}
+
+#[cfg(feature = "alloc")]
+impl DynWatcherList {
+    /// Creates a new empty list.
+    #[inline]
+    pub const fn new() -> Self {
+        Self {
+            entries: alloc::vec::Vec::new(),
+        }
+    }
+
+    /// Registers a value of the type specified at the address given. See
+    /// [`WatcherList::register`] for more information. Returns an error if
+    /// the type is larger than [`MAX_WATCHED_SIZE`] bytes.
+    pub fn register<T: CheckedBitPattern>(
+        &mut self,
+        name: &'static str,
+        address: impl Into<Address>,
+    ) -> Result<(), Error> {
+        let entry = Entry::new::<T>(name, address.into())?;
+        let index = insertion_index(&self.entries, &entry);
+        self.entries.insert(index, entry);
+        Ok(())
+    }
+
+    /// Removes the value with the name provided. Returns [`true`] if there
+    /// was such a value.
+    pub fn unregister(&mut self, name: &str) -> bool {
+        let len = self.entries.len();
+        self.entries.retain(|entry| entry.name != name);
+        self.entries.len() != len
+    }
+
+    /// Reads all the values from the process. See [`WatcherList::update`]
+    /// for more information.
+    pub fn update(&mut self, process: &Process) {
+        update_entries(
+            &mut self.entries,
+            |address, buf| process.read_into_buf_quietly(address, buf),
+            |address, buf| process.read_into_buf(address, buf),
+        );
+    }
+
+    /// Returns the old and current value with the name provided. See
+    /// [`WatcherList::get`] for more information.
+    pub fn get<T: CheckedBitPattern>(&self, name: &str) -> Option<Pair<T>> {
+        find(&self.entries, name)?.get()
+    }
+
+    /// Iterates over the names and addresses of all the values, ordered by
+    /// their addresses.
+    pub fn iter(&self) -> impl Iterator<Item = (&'static str, Address)> + '_ {
+        self.entries.iter().map(|entry| (entry.name, entry.address))
+    }
+
+    /// Removes all the values from the list.
+    #[inline]
+    pub fn clear(&mut self) {
+        self.entries.clear();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn read_from<'a>(
+        memory: &'a [(u64, &[u8])],
+        reads: &'a mut u32,
+    ) -> impl FnMut(Address, &mut [u8]) -> Result<(), Error> + 'a {
+        move |address, buf| {
+            *reads += 1;
+            let (start, bytes) = memory
+                .iter()
+                .find(|(start, bytes)| {
+                    (*start..*start + bytes.len() as u64).contains(&address.value())
+                })
+                .ok_or(Error {})?;
+            let bytes = bytes
+                .get((address.value() - start) as usize..)
+                .and_then(|bytes| bytes.get(..buf.len()))
+                .ok_or(Error {})?;
+            buf.copy_from_slice(bytes);
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn neighboring_values_are_read_at_once() {
+        let mut list = WatcherList::<4>::new();
+        list.register::<u32>("a", 0x100u64).unwrap();
+        list.register::<u8>("unreadable", 0x200u64).unwrap();
+        list.register::<u16>("b", 0x104u64).unwrap();
+        list.register::<u32>("overlapping", 0x102u64).unwrap();
+
+        let memory: &[(u64, &[u8])] = &[(0x100, &[1, 0, 0, 0, 2, 0])];
+        let (mut batched, mut single) = (0, 0);
+        update_entries(
+            &mut list.entries,
+            read_from(memory, &mut batched),
+            read_from(memory, &mut single),
+        );
+        assert_eq!((batched, single), (1, 1));
+        assert_eq!(list.get::<u32>("a").map(|pair| pair.current), Some(1));
+        assert_eq!(list.get::<u16>("b").map(|pair| pair.current), Some(2));
+        assert_eq!(
+            list.get::<u32>("overlapping").map(|pair| pair.current),
+            Some(0x20000)
+        );
+        assert!(list.get::<u8>("unreadable").is_none());
+
+        let memory: &[(u64, &[u8])] = &[(0x100, &[1, 0, 0, 0, 3, 0]), (0x200, &[7])];
+        update_entries(
+            &mut list.entries,
+            read_from(memory, &mut batched),
+            read_from(memory, &mut single),
+        );
+        let a = list.get::<u32>("a").unwrap();
+        assert!(!a.changed());
+        let b = list.get::<u16>("b").unwrap();
+        assert!(b.changed_from_to(&2, &3));
+        // The first read of a value has the same old and current value.
+        let unreadable = list.get::<u8>("unreadable").unwrap();
+        assert!(!unreadable.changed() && unreadable.current == 7);
+    }
+
+    #[test]
+    fn values_of_a_batch_are_read_on_their_own_if_the_batch_fails() {
+        let mut list = WatcherList::<2>::new();
+        list.register::<u16>("readable", 0x1FEu64).unwrap();
+        list.register::<u8>("unreadable", 0x200u64).unwrap();
+
+        let memory: &[(u64, &[u8])] = &[(0x1F0, &[0; 14]), (0x1FE, &[5, 0])];
+        let (mut batched, mut single) = (0, 0);
+        update_entries(
+            &mut list.entries,
+            read_from(memory, &mut batched),
+            read_from(memory, &mut single),
+        );
+        assert_eq!((batched, single), (1, 2));
+        assert_eq!(
+            list.get::<u16>("readable").map(|pair| pair.current),
+            Some(5)
+        );
+        assert!(list.get::<u8>("unreadable").is_none());
+    }
+}
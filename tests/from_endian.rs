@@ -0,0 +1,49 @@
+//! `Process::read_be` and `Process::read_le` read the raw value and convert
+//! it with `FromEndian`, so the conversion of derived structs is what decides
+//! whether every field ends up in the host's byte order.
+#![cfg(feature = "derive")]
+
+use asr::FromEndian;
+use bytemuck::{Pod, Zeroable};
+
+#[derive(Copy, Clone, Debug, PartialEq, Pod, Zeroable, FromEndian)]
+#[repr(C)]
+struct Header {
+    magic: u32,
+    version: u16,
+    #[endian(skip)]
+    flags: [u8; 2],
+    offset: u64,
+}
+
+const RAW: [u8; 16] = [
+    0x12, 0x34, 0x56, 0x78, 0x00, 0x02, 0xAB, 0xCD, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08,
+];
+
+#[test]
+fn from_be_swaps_every_field() {
+    let header: Header = bytemuck::cast(RAW);
+    assert_eq!(
+        header.from_be(),
+        Header {
+            magic: 0x12345678,
+            version: 0x0002,
+            flags: [0xAB, 0xCD],
+            offset: 0x0102030405060708,
+        },
+    );
+}
+
+#[test]
+fn from_le_swaps_every_field() {
+    let header: Header = bytemuck::cast(RAW);
+    assert_eq!(
+        header.from_le(),
+        Header {
+            magic: 0x78563412,
+            version: 0x0200,
+            flags: [0xAB, 0xCD],
+            offset: 0x0807060504030201,
+        },
+    );
+}